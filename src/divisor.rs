@@ -0,0 +1,395 @@
+use super::*;
+
+/// An integer divisor with its "magic numbers" worked out ahead of time.
+///
+/// There's no SIMD integer divide, but dividing by a known value can be done
+/// with a multiply-high, an add, and a couple of shifts per lane (see
+/// Granlund & Montgomery, "Division by Invariant Integers using
+/// Multiplication"). Making a `Divisor` costs a scalar divide, so when you
+/// divide by the same value in a loop make it once outside the loop.
+///
+/// Signed division rounds toward zero like the scalar `/`, and
+/// `MIN / -1` wraps around to `MIN`.
+///
+/// ```
+/// # use wide::*;
+/// let by_7 = Divisor::<i32x4>::new(7);
+/// let x = i32x4::from([20, -20, 6, i32::MIN]);
+/// assert_eq!(x / by_7, i32x4::from([2, -2, 0, i32::MIN / 7]));
+/// assert_eq!(x % by_7, i32x4::from([6, -6, 6, i32::MIN % 7]));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Divisor<T> {
+  divisor: T,
+  magic: T,
+  /// all 1s when a signed divisor is negative
+  sign: T,
+  /// unsigned division shifts by both of these, signed only by `shift2`
+  shift1: u32,
+  shift2: u32,
+}
+
+/// The width of `T` in bits.
+#[inline]
+fn bits<T>() -> u32 {
+  (core::mem::size_of::<T>() * 8) as u32
+}
+
+/// The high half of the double width product of each lane.
+trait MulHigh {
+  fn mul_high(self, rhs: Self) -> Self;
+}
+
+macro_rules! mul_high_lanes {
+  ($a:expr, $b:expr, $e:ty, $w:ty, $n:literal) => {{
+    let mut a: [$e; $n] = cast($a);
+    let b: [$e; $n] = cast($b);
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+      *x = ((*x as $w * *y as $w) >> bits::<$e>()) as $e;
+    }
+    cast(a)
+  }};
+}
+
+#[cfg(target_feature = "sse2")]
+#[inline]
+#[must_use]
+fn mul_high_u32_m128i(a: m128i, b: m128i) -> m128i {
+  // full products of lanes 0 and 2, then of lanes 1 and 3
+  let even = mul_widen_u32_odd_m128i(a, b);
+  let odd = mul_widen_u32_odd_m128i(
+    shr_imm_u64_m128i!(a, 32),
+    shr_imm_u64_m128i!(b, 32),
+  );
+  let high_mask = m128i::from([0xFFFF_FFFF_0000_0000_u64; 2]);
+  bitor_m128i(shr_imm_u64_m128i!(even, 32), bitand_m128i(odd, high_mask))
+}
+
+#[cfg(target_feature = "sse4.1")]
+#[inline]
+#[must_use]
+fn mul_high_i32_m128i(a: m128i, b: m128i) -> m128i {
+  let even = mul_widen_i32_odd_m128i(a, b);
+  let odd = mul_widen_i32_odd_m128i(
+    shr_imm_u64_m128i!(a, 32),
+    shr_imm_u64_m128i!(b, 32),
+  );
+  let high_mask = m128i::from([0xFFFF_FFFF_0000_0000_u64; 2]);
+  bitor_m128i(shr_imm_u64_m128i!(even, 32), bitand_m128i(odd, high_mask))
+}
+
+#[cfg(target_feature = "avx2")]
+#[inline]
+#[must_use]
+fn mul_high_u32_m256i(a: m256i, b: m256i) -> m256i {
+  let even = mul_u64_low_bits_m256i(a, b);
+  let odd = mul_u64_low_bits_m256i(
+    shr_imm_u64_m256i!(a, 32),
+    shr_imm_u64_m256i!(b, 32),
+  );
+  let high_mask = m256i::from([0xFFFF_FFFF_0000_0000_u64; 4]);
+  bitor_m256i(shr_imm_u64_m256i!(even, 32), bitand_m256i(odd, high_mask))
+}
+
+#[cfg(target_feature = "avx2")]
+#[inline]
+#[must_use]
+fn mul_high_i32_m256i(a: m256i, b: m256i) -> m256i {
+  let even = mul_i64_low_bits_m256i(a, b);
+  let odd = mul_i64_low_bits_m256i(
+    shr_imm_u64_m256i!(a, 32),
+    shr_imm_u64_m256i!(b, 32),
+  );
+  let high_mask = m256i::from([0xFFFF_FFFF_0000_0000_u64; 4]);
+  bitor_m256i(shr_imm_u64_m256i!(even, 32), bitand_m256i(odd, high_mask))
+}
+
+impl MulHigh for i16x8 {
+  #[inline]
+  fn mul_high(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        cast(mul_i16_keep_high_m128i(cast(self), cast(rhs)))
+      } else {
+        mul_high_lanes!(self, rhs, i16, i32, 8)
+      }
+    }
+  }
+}
+
+impl MulHigh for u16x8 {
+  #[inline]
+  fn mul_high(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        cast(mul_u16_keep_high_m128i(cast(self), cast(rhs)))
+      } else {
+        mul_high_lanes!(self, rhs, u16, u32, 8)
+      }
+    }
+  }
+}
+
+impl MulHigh for i32x4 {
+  #[inline]
+  fn mul_high(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        cast(mul_high_i32_m128i(cast(self), cast(rhs)))
+      } else if #[cfg(target_feature="sse2")] {
+        // the signed high half is the unsigned one, less `b` where `a` is
+        // negative and less `a` where `b` is negative
+        let high: Self = cast(mul_high_u32_m128i(cast(self), cast(rhs)));
+        let zero = Self::default();
+        high - (zero.cmp_gt(self) & rhs) - (zero.cmp_gt(rhs) & self)
+      } else {
+        mul_high_lanes!(self, rhs, i32, i64, 4)
+      }
+    }
+  }
+}
+
+impl MulHigh for u32x4 {
+  #[inline]
+  fn mul_high(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        cast(mul_high_u32_m128i(cast(self), cast(rhs)))
+      } else {
+        mul_high_lanes!(self, rhs, u32, u64, 4)
+      }
+    }
+  }
+}
+
+impl MulHigh for i32x8 {
+  #[inline]
+  fn mul_high(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        cast(mul_high_i32_m256i(cast(self), cast(rhs)))
+      } else {
+        let a: [i32x4; 2] = cast(self);
+        let b: [i32x4; 2] = cast(rhs);
+        cast([a[0].mul_high(b[0]), a[1].mul_high(b[1])])
+      }
+    }
+  }
+}
+
+impl MulHigh for u32x8 {
+  #[inline]
+  fn mul_high(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        cast(mul_high_u32_m256i(cast(self), cast(rhs)))
+      } else {
+        let a: [u32x4; 2] = cast(self);
+        let b: [u32x4; 2] = cast(rhs);
+        cast([a[0].mul_high(b[0]), a[1].mul_high(b[1])])
+      }
+    }
+  }
+}
+
+// there's no 64-bit multiply-high at all, but the scalar one is still much
+// faster than a scalar divide.
+
+impl MulHigh for i64x2 {
+  #[inline]
+  fn mul_high(self, rhs: Self) -> Self {
+    mul_high_lanes!(self, rhs, i64, i128, 2)
+  }
+}
+
+impl MulHigh for u64x2 {
+  #[inline]
+  fn mul_high(self, rhs: Self) -> Self {
+    mul_high_lanes!(self, rhs, u64, u128, 2)
+  }
+}
+
+impl MulHigh for i64x4 {
+  #[inline]
+  fn mul_high(self, rhs: Self) -> Self {
+    mul_high_lanes!(self, rhs, i64, i128, 4)
+  }
+}
+
+impl MulHigh for u64x4 {
+  #[inline]
+  fn mul_high(self, rhs: Self) -> Self {
+    mul_high_lanes!(self, rhs, u64, u128, 4)
+  }
+}
+
+/// A right shift that fills with the sign bit.
+///
+/// `>>` on `i32x8`, `i64x2` and `i64x4` fills with zeros on the SIMD paths, so
+/// signed division can't use it.
+trait ShrSigned {
+  fn shr_signed(self, n: u32) -> Self;
+}
+
+impl ShrSigned for i16x8 {
+  #[inline]
+  fn shr_signed(self, n: u32) -> Self {
+    self >> n
+  }
+}
+
+impl ShrSigned for i32x4 {
+  #[inline]
+  fn shr_signed(self, n: u32) -> Self {
+    self >> n
+  }
+}
+
+impl ShrSigned for i32x8 {
+  #[inline]
+  fn shr_signed(self, n: u32) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        cast(shr_all_i32_m256i(cast(self), cast([u64::from(n), 0])))
+      } else {
+        let a: [i32x4; 2] = cast(self);
+        cast([a[0] >> n, a[1] >> n])
+      }
+    }
+  }
+}
+
+// there's no 64-bit arithmetic shift, so shift logically and then sign extend
+// from wherever the sign bit ended up
+
+impl ShrSigned for i64x2 {
+  #[inline]
+  fn shr_signed(self, n: u32) -> Self {
+    let x = cast::<_, u64x2>(self) >> n;
+    let m = u64x2::splat(1 << 63) >> n;
+    cast((x ^ m) - m)
+  }
+}
+
+impl ShrSigned for i64x4 {
+  #[inline]
+  fn shr_signed(self, n: u32) -> Self {
+    let x = cast::<_, u64x4>(self) >> n;
+    let m = u64x4::splat(1 << 63) >> n;
+    cast((x ^ m) - m)
+  }
+}
+
+macro_rules! impl_unsigned_divisor {
+  ($($t:ty => $e:ty, $w:ty);+ $(;)?) => {
+    $(
+      impl Divisor<$t> {
+        /// Works out the magic numbers for dividing by `d`.
+        ///
+        /// Panics if `d` is 0.
+        #[inline]
+        #[must_use]
+        pub fn new(d: $e) -> Self {
+          assert!(d != 0, "attempt to divide by zero");
+          // l = ceil(log2(d)), m = floor(2^N * (2^l - d) / d) + 1
+          let l = bits::<$e>() - (d - 1).leading_zeros();
+          let m = ((1 as $w) << bits::<$e>()) * (((1 as $w) << l) - d as $w)
+            / d as $w
+            + 1;
+          Self {
+            divisor: <$t>::splat(d),
+            magic: <$t>::splat(m as $e),
+            sign: <$t>::default(),
+            shift1: l.min(1),
+            shift2: l.saturating_sub(1),
+          }
+        }
+      }
+
+      impl Div<Divisor<$t>> for $t {
+        type Output = Self;
+        #[inline]
+        #[must_use]
+        fn div(self, rhs: Divisor<$t>) -> Self::Output {
+          let t = self.mul_high(rhs.magic);
+          (t + ((self - t) >> rhs.shift1)) >> rhs.shift2
+        }
+      }
+
+      impl Rem<Divisor<$t>> for $t {
+        type Output = Self;
+        #[inline]
+        #[must_use]
+        fn rem(self, rhs: Divisor<$t>) -> Self::Output {
+          self - (self / rhs) * rhs.divisor
+        }
+      }
+    )+
+  };
+}
+
+impl_unsigned_divisor! {
+  u16x8 => u16, u32;
+  u32x4 => u32, u64;
+  u32x8 => u32, u64;
+  u64x2 => u64, u128;
+  u64x4 => u64, u128;
+}
+
+macro_rules! impl_signed_divisor {
+  ($($t:ty => $e:ty, $u:ty, $w:ty);+ $(;)?) => {
+    $(
+      impl Divisor<$t> {
+        /// Works out the magic numbers for dividing by `d`.
+        ///
+        /// Panics if `d` is 0.
+        #[inline]
+        #[must_use]
+        pub fn new(d: $e) -> Self {
+          assert!(d != 0, "attempt to divide by zero");
+          // l = max(ceil(log2(|d|)), 1), m = floor(2^(N + l - 1) / |d|) + 1,
+          // and only the low N bits of m are kept
+          let abs = d.wrapping_abs() as $u;
+          let l = (bits::<$e>() - (abs - 1).leading_zeros()).max(1);
+          let m = ((1 as $w) << (bits::<$e>() + l - 1)) / abs as $w + 1;
+          Self {
+            divisor: <$t>::splat(d),
+            magic: <$t>::splat(m as $e),
+            sign: <$t>::splat(if d < 0 { -1 } else { 0 }),
+            shift1: 0,
+            shift2: l - 1,
+          }
+        }
+      }
+
+      impl Div<Divisor<$t>> for $t {
+        type Output = Self;
+        #[inline]
+        #[must_use]
+        fn div(self, rhs: Divisor<$t>) -> Self::Output {
+          let q = self + self.mul_high(rhs.magic);
+          // rounding toward zero means adding 1 to negative lanes
+          let q = q.shr_signed(rhs.shift2) - self.shr_signed(bits::<$e>() - 1);
+          (q ^ rhs.sign) - rhs.sign
+        }
+      }
+
+      impl Rem<Divisor<$t>> for $t {
+        type Output = Self;
+        #[inline]
+        #[must_use]
+        fn rem(self, rhs: Divisor<$t>) -> Self::Output {
+          self - (self / rhs) * rhs.divisor
+        }
+      }
+    )+
+  };
+}
+
+impl_signed_divisor! {
+  i16x8 => i16, u16, u32;
+  i32x4 => i32, u32, u64;
+  i32x8 => i32, u32, u64;
+  i64x2 => i64, u64, u128;
+  i64x4 => i64, u64, u128;
+}
@@ -156,6 +156,51 @@ impl Div for f32x8 {
   }
 }
 
+impl Rem for f32x8 {
+  type Output = Self;
+  /// The remainder of dividing lane by lane, with the sign of `self`, like C's
+  /// `fmod`. The result is exact.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn rem(self, rhs: Self) -> Self::Output {
+    // each step takes a quotient of at most 12 bits off, which keeps all of
+    // the products below exact
+    const_f32_as_f32x8!(STEP, 11.0);
+    const_f32_as_f32x8!(SCALE_UP, 16777216.0);
+    const_f32_as_f32x8!(SCALE_BITS, 24.0);
+    // lanes past this work at a quarter of the size, so that `q * hi` can't
+    // overflow
+    const_f32_as_f32x8!(BIG, 4.253529586511731e37);
+    let y = rhs.abs();
+    let special = !self.is_finite() | rhs.is_nan() | y.cmp_eq(Self::ZERO);
+    let mut r = special.blend(Self::ZERO, self.abs());
+    // `mul_pow2n` can't get all the way up from a subnormal, so those start
+    // out scaled up by 2^24
+    let tiny = y.cmp_lt(Self::from(f32::MIN_POSITIVE));
+    let y_scaled = tiny.blend(y * SCALE_UP, y);
+    let shift = tiny & SCALE_BITS;
+    let ey = y.exponent_sub();
+    while r.cmp_ge(y).any() {
+      let k = (r.exponent_sub() - ey - STEP).max(Self::ZERO);
+      let big = r.cmp_ge(BIG);
+      let d = y_scaled.mul_pow2n(k - shift - (big & Self::from(2.0)));
+      let rq = big.blend(r * Self::from(0.25), r);
+      let q = (rq / d).trunc();
+      // `hi` is `d` rounded up to 12 bits, so `q * hi` is close enough to
+      // `r` that `r - q * hi` is exact, and `lo` is short enough that
+      // `q * lo` is exact
+      let low = u32x8::from(0xFFF);
+      let hi = cast::<_, Self>((cast::<_, u32x8>(d) + low) & !low);
+      let lo = d - hi;
+      let r1 = (rq - q * hi) - q * lo;
+      let r1 = r1.cmp_lt(Self::ZERO).blend(r1 + d, r1);
+      r = big.blend(r1 * Self::from(4.0), r1);
+    }
+    special.blend(Self::from(f32::NAN), r.copysign(self))
+  }
+}
+
 impl Add<f32> for f32x8 {
   type Output = Self;
   #[inline]
@@ -192,6 +237,15 @@ impl Div<f32> for f32x8 {
   }
 }
 
+impl Rem<f32> for f32x8 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: f32) -> Self::Output {
+    self.rem(Self::splat(rhs))
+  }
+}
+
 impl Add<f32x8> for f32 {
   type Output = f32x8;
   #[inline]
@@ -228,6 +282,15 @@ impl Div<f32x8> for f32 {
   }
 }
 
+impl Rem<f32x8> for f32 {
+  type Output = f32x8;
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: f32x8) -> Self::Output {
+    f32x8::splat(self).rem(rhs)
+  }
+}
+
 impl BitAnd for f32x8 {
   type Output = Self;
   #[inline]
@@ -476,12 +539,74 @@ impl f32x8 {
       }
     }
   }
+
+  /// Transposes an 8x8 matrix held as eight rows, so lane `j` of row `i` in
+  /// the output is lane `i` of row `j` in the input.
+  #[inline]
+  #[must_use]
+  pub fn transpose8(data: [Self; 8]) -> [Self; 8] {
+    pick! {
+      if #[cfg(target_feature="avx")] {
+        // 4x4 transposes within each 128-bit half, then swap the halves over
+        let [r0, r1, r2, r3, r4, r5, r6, r7] = data;
+        let t0 = unpack_lo_m256(r0.avx, r1.avx);
+        let t1 = unpack_hi_m256(r0.avx, r1.avx);
+        let t2 = unpack_lo_m256(r2.avx, r3.avx);
+        let t3 = unpack_hi_m256(r2.avx, r3.avx);
+        let t4 = unpack_lo_m256(r4.avx, r5.avx);
+        let t5 = unpack_hi_m256(r4.avx, r5.avx);
+        let t6 = unpack_lo_m256(r6.avx, r7.avx);
+        let t7 = unpack_hi_m256(r6.avx, r7.avx);
+        let s0 = shuffle_abi_f32_half_m256!(t0, t2, [a:0, a:1, b:0, b:1]);
+        let s1 = shuffle_abi_f32_half_m256!(t0, t2, [a:2, a:3, b:2, b:3]);
+        let s2 = shuffle_abi_f32_half_m256!(t1, t3, [a:0, a:1, b:0, b:1]);
+        let s3 = shuffle_abi_f32_half_m256!(t1, t3, [a:2, a:3, b:2, b:3]);
+        let s4 = shuffle_abi_f32_half_m256!(t4, t6, [a:0, a:1, b:0, b:1]);
+        let s5 = shuffle_abi_f32_half_m256!(t4, t6, [a:2, a:3, b:2, b:3]);
+        let s6 = shuffle_abi_f32_half_m256!(t5, t7, [a:0, a:1, b:0, b:1]);
+        let s7 = shuffle_abi_f32_half_m256!(t5, t7, [a:2, a:3, b:2, b:3]);
+        [
+          Self { avx: shuffle_abi_f128z_all_m256!(s0, s4, [A_Low, B_Low]) },
+          Self { avx: shuffle_abi_f128z_all_m256!(s1, s5, [A_Low, B_Low]) },
+          Self { avx: shuffle_abi_f128z_all_m256!(s2, s6, [A_Low, B_Low]) },
+          Self { avx: shuffle_abi_f128z_all_m256!(s3, s7, [A_Low, B_Low]) },
+          Self { avx: shuffle_abi_f128z_all_m256!(s0, s4, [A_High, B_High]) },
+          Self { avx: shuffle_abi_f128z_all_m256!(s1, s5, [A_High, B_High]) },
+          Self { avx: shuffle_abi_f128z_all_m256!(s2, s6, [A_High, B_High]) },
+          Self { avx: shuffle_abi_f128z_all_m256!(s3, s7, [A_High, B_High]) },
+        ]
+      } else {
+        // transpose the four 4x4 blocks and swap the off diagonal ones
+        let rows: [[f32x4; 2]; 8] = cast(data);
+        let mut out = [[f32x4::default(); 2]; 8];
+        for (half, block_rows) in rows.chunks_exact(4).enumerate() {
+          for col in 0..2 {
+            let block = f32x4::transpose4([
+              block_rows[0][col],
+              block_rows[1][col],
+              block_rows[2][col],
+              block_rows[3][col],
+            ]);
+            let out_rows = &mut out[col * 4..col * 4 + 4];
+            for (row, &quad) in out_rows.iter_mut().zip(block.iter()) {
+              row[half] = quad;
+            }
+          }
+        }
+        cast(out)
+      }
+    }
+  }
   #[inline]
   #[must_use]
   pub fn abs(self) -> Self {
     let non_sign_bits = f32x8::from(f32::from_bits(i32::MAX as u32));
     self & non_sign_bits
   }
+  /// Lanewise maximum. What comes out for NaN depends on the backend and the
+  /// order of the operands, so use [`max_num`](Self::max_num),
+  /// [`maximum`](Self::maximum) or [`fast_max`](Self::fast_max) when it
+  /// matters.
   #[inline]
   #[must_use]
   pub fn max(self, rhs: Self) -> Self {
@@ -504,6 +629,10 @@ impl f32x8 {
       }
     }
   }
+  /// Lanewise minimum. What comes out for NaN depends on the backend and the
+  /// order of the operands, so use [`min_num`](Self::min_num),
+  /// [`minimum`](Self::minimum) or [`fast_min`](Self::fast_min) when it
+  /// matters.
   #[inline]
   #[must_use]
   pub fn min(self, rhs: Self) -> Self {
@@ -526,6 +655,168 @@ impl f32x8 {
       }
     }
   }
+
+  /// Lanewise maximum, the way `maxps` does it: `self` if it's greater,
+  /// otherwise `rhs`. That makes a NaN in either lane, or a pair of zeros,
+  /// give `rhs`.
+  #[inline]
+  #[must_use]
+  pub fn fast_max(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx")] {
+        // older compilers swap the operands of `vmaxps`, which changes which
+        // lane comes out for NaNs and zeros, so compare and blend instead
+        self.cmp_gt(rhs).blend(self, rhs)
+      } else if #[cfg(target_feature="sse2")] {
+        Self { sse0: max_m128(self.sse0, rhs.sse0), sse1: max_m128(self.sse1, rhs.sse1) }
+      } else {
+        Self { arr: [
+          if self.arr[0] > rhs.arr[0] { self.arr[0] } else { rhs.arr[0] },
+          if self.arr[1] > rhs.arr[1] { self.arr[1] } else { rhs.arr[1] },
+          if self.arr[2] > rhs.arr[2] { self.arr[2] } else { rhs.arr[2] },
+          if self.arr[3] > rhs.arr[3] { self.arr[3] } else { rhs.arr[3] },
+          if self.arr[4] > rhs.arr[4] { self.arr[4] } else { rhs.arr[4] },
+          if self.arr[5] > rhs.arr[5] { self.arr[5] } else { rhs.arr[5] },
+          if self.arr[6] > rhs.arr[6] { self.arr[6] } else { rhs.arr[6] },
+          if self.arr[7] > rhs.arr[7] { self.arr[7] } else { rhs.arr[7] },
+        ]}
+      }
+    }
+  }
+
+  /// Lanewise minimum, the way `minps` does it: `self` if it's less,
+  /// otherwise `rhs`. That makes a NaN in either lane, or a pair of zeros,
+  /// give `rhs`.
+  #[inline]
+  #[must_use]
+  pub fn fast_min(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx")] {
+        // older compilers swap the operands of `vminps`, which changes which
+        // lane comes out for NaNs and zeros, so compare and blend instead
+        self.cmp_lt(rhs).blend(self, rhs)
+      } else if #[cfg(target_feature="sse2")] {
+        Self { sse0: min_m128(self.sse0, rhs.sse0), sse1: min_m128(self.sse1, rhs.sse1) }
+      } else {
+        Self { arr: [
+          if self.arr[0] < rhs.arr[0] { self.arr[0] } else { rhs.arr[0] },
+          if self.arr[1] < rhs.arr[1] { self.arr[1] } else { rhs.arr[1] },
+          if self.arr[2] < rhs.arr[2] { self.arr[2] } else { rhs.arr[2] },
+          if self.arr[3] < rhs.arr[3] { self.arr[3] } else { rhs.arr[3] },
+          if self.arr[4] < rhs.arr[4] { self.arr[4] } else { rhs.arr[4] },
+          if self.arr[5] < rhs.arr[5] { self.arr[5] } else { rhs.arr[5] },
+          if self.arr[6] < rhs.arr[6] { self.arr[6] } else { rhs.arr[6] },
+          if self.arr[7] < rhs.arr[7] { self.arr[7] } else { rhs.arr[7] },
+        ]}
+      }
+    }
+  }
+
+  /// Lanewise maximum that ignores NaN like [`f32::max`], so only two NaN
+  /// lanes give NaN. `-0.0` counts as less than `0.0`.
+  #[inline]
+  #[must_use]
+  pub fn max_num(self, rhs: Self) -> Self {
+    let m = rhs.fast_max(self);
+    let m = self.cmp_eq(rhs).blend(self & rhs, m);
+    self.is_nan().blend(rhs, m)
+  }
+
+  /// Lanewise minimum that ignores NaN like [`f32::min`], so only two NaN
+  /// lanes give NaN. `-0.0` counts as less than `0.0`.
+  #[inline]
+  #[must_use]
+  pub fn min_num(self, rhs: Self) -> Self {
+    let m = rhs.fast_min(self);
+    let m = self.cmp_eq(rhs).blend(self | rhs, m);
+    self.is_nan().blend(rhs, m)
+  }
+
+  /// Lanewise IEEE 754-2019 `maximum`: a NaN in either lane gives NaN, and
+  /// `-0.0` counts as less than `0.0`.
+  #[inline]
+  #[must_use]
+  pub fn maximum(self, rhs: Self) -> Self {
+    let m = self.fast_max(rhs);
+    let m = self.cmp_eq(rhs).blend(self & rhs, m);
+    (self.is_nan() | rhs.is_nan()).blend(self + rhs, m)
+  }
+
+  /// Lanewise IEEE 754-2019 `minimum`: a NaN in either lane gives NaN, and
+  /// `-0.0` counts as less than `0.0`.
+  #[inline]
+  #[must_use]
+  pub fn minimum(self, rhs: Self) -> Self {
+    let m = self.fast_min(rhs);
+    let m = self.cmp_eq(rhs).blend(self | rhs, m);
+    (self.is_nan() | rhs.is_nan()).blend(self + rhs, m)
+  }
+
+  /// Clamps each lane to `[min, max]`. NaN lanes stay NaN, and a NaN bound
+  /// is ignored.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    // the fast ops give their second operand whenever there's a NaN
+    max.fast_min(min.fast_max(self))
+  }
+
+  /// Clamps each lane to `[0, 1]`, keeping NaN.
+  #[inline]
+  #[must_use]
+  pub fn saturate(self) -> Self {
+    self.clamp(Self::ZERO, Self::ONE)
+  }
+
+  /// Linear interpolation from `self` at `t = 0` to `b` at `t = 1`. Both
+  /// ends come out exactly, and `t` outside `[0, 1]` extrapolates.
+  #[inline]
+  #[must_use]
+  pub fn lerp(self, b: Self, t: Self) -> Self {
+    t.mul_add(b, t.mul_neg_add(self, self))
+  }
+
+  /// The `t` at which `a.lerp(b, t)` gives `v`. Equal `a` and `b` give NaN
+  /// or infinity.
+  #[inline]
+  #[must_use]
+  pub fn inverse_lerp(a: Self, b: Self, v: Self) -> Self {
+    (v - a) / (b - a)
+  }
+
+  /// Maps each lane linearly from `[in_start, in_end]` onto
+  /// `[out_start, out_end]`, extrapolating outside of them.
+  #[inline]
+  #[must_use]
+  pub fn remap(
+    self,
+    in_start: Self,
+    in_end: Self,
+    out_start: Self,
+    out_end: Self,
+  ) -> Self {
+    out_start.lerp(out_end, Self::inverse_lerp(in_start, in_end, self))
+  }
+
+  /// 0 up to `edge0`, 1 from `edge1` on, and the cubic `3t^2 - 2t^3` in
+  /// between, like GLSL's `smoothstep`. NaN lanes stay NaN.
+  #[inline]
+  #[must_use]
+  pub fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+    let t = Self::inverse_lerp(edge0, edge1, self).saturate();
+    t * t * t.mul_neg_add(Self::from(2.0), Self::from(3.0))
+  }
+
+  /// Like [`smoothstep`](Self::smoothstep), but with the quintic
+  /// `6t^5 - 15t^4 + 10t^3`, which also has flat second derivatives at the
+  /// edges.
+  #[inline]
+  #[must_use]
+  pub fn smootherstep(self, edge0: Self, edge1: Self) -> Self {
+    let t = Self::inverse_lerp(edge0, edge1, self).saturate();
+    let p = t.mul_add(Self::from(6.0), Self::from(-15.0));
+    t * t * t * t.mul_add(p, Self::from(10.0))
+  }
   #[inline]
   #[must_use]
   pub fn is_nan(self) -> Self {
@@ -606,6 +897,70 @@ impl f32x8 {
       }
     }
   }
+  /// Rounds each lane toward zero.
+  #[inline]
+  #[must_use]
+  pub fn trunc(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx")] {
+        Self { avx: round_m256!(self.avx, Zero) }
+      } else {
+        let halves: [f32x4; 2] = cast(self);
+        cast([halves[0].trunc(), halves[1].trunc()])
+      }
+    }
+  }
+  /// Rounds each lane toward negative infinity.
+  #[inline]
+  #[must_use]
+  pub fn floor(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx")] {
+        Self { avx: round_m256!(self.avx, NegInf) }
+      } else {
+        let halves: [f32x4; 2] = cast(self);
+        cast([halves[0].floor(), halves[1].floor()])
+      }
+    }
+  }
+  /// Rounds each lane toward positive infinity.
+  #[inline]
+  #[must_use]
+  pub fn ceil(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx")] {
+        Self { avx: round_m256!(self.avx, PosInf) }
+      } else {
+        let halves: [f32x4; 2] = cast(self);
+        cast([halves[0].ceil(), halves[1].ceil()])
+      }
+    }
+  }
+  /// The fractional part of each lane, `self - self.trunc()`.
+  #[inline]
+  #[must_use]
+  pub fn fract(self) -> Self {
+    self - self.trunc()
+  }
+
+  /// The least nonnegative remainder of `self` divided by `rhs`, like
+  /// `f32::rem_euclid`.
+  #[inline]
+  #[must_use]
+  pub fn rem_euclid(self, rhs: Self) -> Self {
+    let r = self % rhs;
+    r.cmp_lt(Self::ZERO).blend(r + rhs.abs(), r)
+  }
+
+  /// The quotient of Euclidean division, like `f32::div_euclid`. It's
+  /// rounded so that `self` is close to `rhs * q + self.rem_euclid(rhs)`.
+  #[inline]
+  #[must_use]
+  pub fn div_euclid(self, rhs: Self) -> Self {
+    let q = (self / rhs).trunc();
+    let down = rhs.cmp_gt(Self::ZERO).blend(q - Self::ONE, q + Self::ONE);
+    (self % rhs).cmp_lt(Self::ZERO).blend(down, q)
+  }
   #[inline]
   #[must_use]
   pub fn round_int(self) -> i32x8 {
@@ -946,13 +1301,8 @@ impl f32x8 {
     const_f32_as_f32x8!(DP2F, 2.4187564849853515625E-4_f32 * 2.0);
     const_f32_as_f32x8!(DP3F, 3.77489497744594108E-8_f32 * 2.0);
 
-    const_f32_as_f32x8!(P0sinf, -1.6666654611E-1);
-    const_f32_as_f32x8!(P1sinf, 8.3321608736E-3);
-    const_f32_as_f32x8!(P2sinf, -1.9515295891E-4);
-
-    const_f32_as_f32x8!(P0cosf, 4.166664568298827E-2);
-    const_f32_as_f32x8!(P1cosf, -1.388731625493765E-3);
-    const_f32_as_f32x8!(P2cosf, 2.443315711809948E-5);
+    // Cody-Waite loses too many bits past this
+    const_f32_as_f32x8!(REDUCE_MAX, 8192.0);
 
     const_f32_as_f32x8!(TWO_OVER_PI, 2.0 / core::f32::consts::PI);
 
@@ -960,26 +1310,55 @@ impl f32x8 {
 
     // Find quadrant
     let y = (xa * TWO_OVER_PI).round();
-    let q: i32x8 = y.round_int();
+    let mut q: i32x8 = y.round_int();
+
+    let mut x =
+      y.mul_neg_add(DP3F, y.mul_neg_add(DP2F, y.mul_neg_add(DP1F, xa)));
+
+    // lanes past that are reduced one at a time with Payne-Hanek instead
+    let mut big = (xa.cmp_gt(REDUCE_MAX) & xa.is_finite()).move_mask();
+    if big != 0 {
+      let a: [f32; 8] = cast(xa);
+      let mut r: [f32; 8] = cast(x);
+      let mut k: [i32; 8] = cast(q);
+      while big != 0 {
+        let i = big.trailing_zeros() as usize;
+        let (ri, ki) = rem_pio2(f64::from(a[i]));
+        r[i] = ri as f32;
+        k[i] = ki as i32;
+        big &= big - 1;
+      }
+      x = cast(r);
+      q = cast(k);
+    }
 
-    let x = y.mul_neg_add(DP3F, y.mul_neg_add(DP2F, y.mul_neg_add(DP1F, xa)));
+    let (sin1, cos1) = Self::sin_cos_quadrant(x, q);
+    (sin1.flip_signs(self), cos1)
+  }
+
+  /// `sin` and `cos` of `x + q * pi/2`, for a reduced `|x| <= pi/4`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn sin_cos_quadrant(x: Self, q: i32x8) -> (Self, Self) {
+    const_f32_as_f32x8!(P0sinf, -1.6666654611E-1);
+    const_f32_as_f32x8!(P1sinf, 8.3321608736E-3);
+    const_f32_as_f32x8!(P2sinf, -1.9515295891E-4);
+
+    const_f32_as_f32x8!(P0cosf, 4.166664568298827E-2);
+    const_f32_as_f32x8!(P1cosf, -1.388731625493765E-3);
+    const_f32_as_f32x8!(P2cosf, 2.443315711809948E-5);
 
     let x2 = x * x;
-    let mut s = polynomial_2!(x2, P0sinf, P1sinf, P2sinf) * (x * x2) + x;
-    let mut c = polynomial_2!(x2, P0cosf, P1cosf, P2cosf) * (x2 * x2)
+    let s = polynomial_2!(x2, P0sinf, P1sinf, P2sinf) * (x * x2) + x;
+    let c = polynomial_2!(x2, P0cosf, P1cosf, P2cosf) * (x2 * x2)
       + f32x8::from(0.5).mul_neg_add(x2, f32x8::from(1.0));
 
     let swap = !(q & i32x8::from(1)).cmp_eq(i32x8::from(0));
 
-    let mut overflow: f32x8 = cast(q.cmp_gt(i32x8::from(0x2000000)));
-    overflow &= xa.is_finite();
-    s = overflow.blend(f32x8::from(0.0), s);
-    c = overflow.blend(f32x8::from(1.0), c);
-
     // calc sin
     let mut sin1 = cast::<_, f32x8>(swap).blend(c, s);
-    let sign_sin: i32x8 = (q << 30) ^ cast::<_, i32x8>(self);
-    sin1 = sin1.flip_signs(cast(sign_sin));
+    sin1 = sin1.flip_signs(cast(q << 30));
 
     // calc cos
     let mut cos1 = cast::<_, f32x8>(swap).blend(s, c);
@@ -1006,6 +1385,48 @@ impl f32x8 {
     let (s, c) = self.sin_cos();
     s / c
   }
+
+  /// `sin(pi * x)` and `cos(pi * x)`. The period is a whole number, so the
+  /// reduction is exact however big `x` is, and the integers and
+  /// half-integers give exactly 0 or ±1.
+  #[inline]
+  #[must_use]
+  pub fn sin_cos_pi(self) -> (Self, Self) {
+    // both steps are exact: t is in [-1, 1] and r in [-1/4, 1/4]
+    let t = (self * Self::HALF).round().mul_neg_add(Self::from(2.0), self);
+    let n = (t + t).round();
+    let r = n.mul_neg_add(Self::HALF, t);
+    let (s, c) = Self::sin_cos_quadrant(r * Self::PI, n.round_int());
+    // zeros get the signs IEEE 754 asks for: sin's follows x, cos's is +
+    let s = s.cmp_eq(Self::ZERO).blend(Self::ZERO.copysign(self), s);
+    let c = c.cmp_eq(Self::ZERO).blend(Self::ZERO, c);
+    (s, c)
+  }
+
+  /// `sin(pi * x)`, exactly 0 at the integers.
+  #[inline]
+  #[must_use]
+  pub fn sin_pi(self) -> Self {
+    let (s, _) = self.sin_cos_pi();
+    s
+  }
+
+  /// `cos(pi * x)`, exactly 0 at the half-integers.
+  #[inline]
+  #[must_use]
+  pub fn cos_pi(self) -> Self {
+    let (_, c) = self.sin_cos_pi();
+    c
+  }
+
+  /// `tan(pi * x)`, exactly 0 at the integers and infinite at the
+  /// half-integers.
+  #[inline]
+  #[must_use]
+  pub fn tan_pi(self) -> Self {
+    let (s, c) = self.sin_cos_pi();
+    s / c
+  }
   #[inline]
   #[must_use]
   pub fn to_degrees(self) -> Self {
@@ -1106,6 +1527,63 @@ impl f32x8 {
       }
     }
   }
+
+  /// Cube root. Negative lanes give negative roots.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn cbrt(self) -> Self {
+    const_f32_as_f32x8!(C0, 0.49660828);
+    const_f32_as_f32x8!(C1, 0.68823454);
+    const_f32_as_f32x8!(C2, -0.18528691);
+    const_f32_as_f32x8!(CBRT_2, 1.2599210498948732);
+    const_f32_as_f32x8!(CBRT_4, 1.5874010519681994);
+    const_f32_as_f32x8!(THIRD, 1.0 / 3.0);
+    const_f32_as_f32x8!(THREE, 3.0);
+    // subnormals are scaled up by 2^24 first, which the root undoes as 2^8
+    const_f32_as_f32x8!(SCALE_UP, 16777216.0);
+    const_f32_as_f32x8!(SCALE_DOWN, 1.0 / 256.0);
+    let a = self.abs();
+    let tiny = a.cmp_lt(Self::from(f32::MIN_POSITIVE));
+    let a = tiny.blend(a * SCALE_UP, a);
+    // a = m * 2^(3q + r) with m in [0.5, 1), so the root is cbrt(m * 2^r) * 2^q
+    let m = Self::fraction_2(a);
+    let e = Self::exponent(a) + Self::ONE;
+    let q = (e / THREE).floor();
+    let r = e - q * THREE;
+    let y = polynomial_2!(m, C0, C1, C2);
+    let y = r.cmp_eq(Self::from(2.0)).blend(y * CBRT_4, y);
+    let y = r.cmp_eq(Self::ONE).blend(y * CBRT_2, y);
+    let m = m * Self::vm_pow2n(r);
+    // the first guess is good to about 10 bits, and each Newton step doubles
+    // that
+    let y = y + (m / (y * y) - y) * THIRD;
+    let y = y + (m / (y * y) - y) * THIRD;
+    let y = y * Self::vm_pow2n(q);
+    let y = tiny.blend(y * SCALE_DOWN, y).copysign(self);
+    // zeros, infinities and NaNs are their own roots
+    (self.cmp_eq(Self::ZERO) | self.is_inf() | self.is_nan()).blend(self, y)
+  }
+
+  /// `sqrt(self^2 + other^2)`, without overflowing or underflowing along the
+  /// way.
+  #[inline]
+  #[must_use]
+  pub fn hypot(self, other: Self) -> Self {
+    let a = self.abs();
+    let b = other.abs();
+    let big = a.max(b);
+    let small = a.min(b);
+    // scale so that `big` is in [1, 2), then scale the result back
+    let e = Self::exponent(big);
+    let big = big.mul_pow2n(-e);
+    let small = small.mul_pow2n(-e);
+    let y = big.mul_add(big, small * small).sqrt().mul_pow2n(e);
+    let y = (a.is_nan() | b.is_nan()).blend(Self::from(f32::NAN), y);
+    let inf = Self::infinity();
+    (a.cmp_eq(inf) | b.cmp_eq(inf)).blend(inf, y)
+  }
+
   #[inline]
   #[must_use]
   pub fn move_mask(self) -> i32 {
@@ -1154,6 +1632,16 @@ impl f32x8 {
     cast::<_, f32x8>(c)
   }
 
+  /// `self * 2^n` for integer valued `n`, done in two steps so that `n` can
+  /// go past the normal exponent range (the result overflows or goes
+  /// subnormal as it should).
+  #[inline]
+  #[must_use]
+  fn mul_pow2n(self, n: Self) -> Self {
+    let half = (n * Self::HALF).floor();
+    self * Self::vm_pow2n(half) * Self::vm_pow2n(n - half)
+  }
+
   /// Calculate the exponent of a packed f32x8
   #[inline]
   #[must_use]
@@ -1182,6 +1670,79 @@ impl f32x8 {
     in_range.blend(z, Self::ZERO)
   }
 
+  /// `2^x`. Integer lanes give exact powers of two.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn exp2(self) -> Self {
+    const_f32_as_f32x8!(P0, 1.0 / 2.0);
+    const_f32_as_f32x8!(P1, 1.0 / 6.0);
+    const_f32_as_f32x8!(P2, 1. / 24.);
+    const_f32_as_f32x8!(P3, 1. / 120.);
+    const_f32_as_f32x8!(P4, 1. / 720.);
+    const_f32_as_f32x8!(P5, 1. / 5040.);
+    const_f32_as_f32x8!(LIMIT, 250.0);
+    let x = self.max(-LIMIT).min(LIMIT);
+    let r = x.round();
+    let x = (x - r) * Self::LN_2;
+    let z = polynomial_5!(x, P0, P1, P2, P3, P4, P5).mul_add(x * x, x);
+    let y = (z + Self::ONE).mul_pow2n(r);
+    self.is_nan().blend(self, y)
+  }
+
+  /// `10^x`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn exp10(self) -> Self {
+    const_f32_as_f32x8!(P0, 1.0 / 2.0);
+    const_f32_as_f32x8!(P1, 1.0 / 6.0);
+    const_f32_as_f32x8!(P2, 1. / 24.);
+    const_f32_as_f32x8!(P3, 1. / 120.);
+    const_f32_as_f32x8!(P4, 1. / 720.);
+    const_f32_as_f32x8!(P5, 1. / 5040.);
+    const_f32_as_f32x8!(LOG10_2_HI, 0.301025390625);
+    const_f32_as_f32x8!(LOG10_2_LO, 4.605038981195214e-6);
+    const_f32_as_f32x8!(LIMIT, 75.0);
+    let x = self.max(-LIMIT).min(LIMIT);
+    let r = (x * Self::LOG2_10).round();
+    let x = r.mul_neg_add(LOG10_2_HI, x);
+    let x = r.mul_neg_add(LOG10_2_LO, x) * Self::LN_10;
+    let z = polynomial_5!(x, P0, P1, P2, P3, P4, P5).mul_add(x * x, x);
+    let y = (z + Self::ONE).mul_pow2n(r);
+    self.is_nan().blend(self, y)
+  }
+
+  /// `e^x - 1`, accurate even when `x` is close to zero.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn expm1(self) -> Self {
+    const_f32_as_f32x8!(P0, 1.0 / 2.0);
+    const_f32_as_f32x8!(P1, 1.0 / 6.0);
+    const_f32_as_f32x8!(P2, 1. / 24.);
+    const_f32_as_f32x8!(P3, 1. / 120.);
+    const_f32_as_f32x8!(P4, 1. / 720.);
+    const_f32_as_f32x8!(P5, 1. / 5040.);
+    const_f32_as_f32x8!(LN2D_HI, 0.693359375);
+    const_f32_as_f32x8!(LN2D_LO, -2.12194440e-4);
+    const_f32_as_f32x8!(MIN_X, -30.0);
+    const_f32_as_f32x8!(MAX_X, 100.0);
+    const_f32_as_f32x8!(BIG, 60.0);
+    let x = self.max(MIN_X).min(MAX_X);
+    let r = (x * Self::LOG2_E).round();
+    let x = r.mul_neg_add(LN2D_HI, x);
+    let x = r.mul_neg_add(LN2D_LO, x);
+    // z = e^x - 1 for the reduced x, so the result is z * 2^r + (2^r - 1)
+    let z = polynomial_5!(x, P0, P1, P2, P3, P4, P5).mul_add(x * x, x);
+    let n2 = Self::vm_pow2n(r.min(BIG));
+    let small = z.mul_add(n2, n2 - Self::ONE);
+    // past `BIG` the - 1 is lost, and 2^r might not fit in one step
+    let big = (z + Self::ONE).mul_pow2n(r) - Self::ONE;
+    let y = r.cmp_lt(BIG).blend(small, big);
+    (self.cmp_eq(Self::ZERO) | self.is_nan()).blend(self, y)
+  }
+
   #[inline]
   #[allow(non_upper_case_globals)]
   fn exponent(self) -> f32x8 {
@@ -1195,6 +1756,160 @@ impl f32x8 {
     e
   }
 
+  /// Like `exponent`, but also right for subnormals.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn exponent_sub(self) -> Self {
+    const_f32_as_f32x8!(SCALE_UP, 16777216.0);
+    const_f32_as_f32x8!(SCALE_BITS, 24.0);
+    let sub = self.abs().cmp_lt(Self::from(f32::MIN_POSITIVE));
+    sub.blend((self * SCALE_UP).exponent() - SCALE_BITS, self.exponent())
+  }
+
+  /// Splits each lane into a mantissa and an exponent, so that
+  /// `self = mantissa * 2^exponent` with `0.5 <= |mantissa| < 1`. Zero,
+  /// infinity and NaN come back unchanged with an exponent of 0.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn frexp(self) -> (Self, i32x8) {
+    const_f32_as_f32x8!(SCALE_UP, 16777216.0);
+    const_f32_as_f32x8!(SCALE_BITS, 24.0);
+    let sub = self.abs().cmp_lt(Self::from(f32::MIN_POSITIVE));
+    let x = sub.blend(self * SCALE_UP, self).abs();
+    let m = x.fraction_2().copysign(self);
+    let exp = x.exponent() + Self::ONE - (sub & SCALE_BITS);
+    let keep = self.cmp_eq(Self::ZERO) | !self.is_finite();
+    (keep.blend(self, m), keep.blend(Self::ZERO, exp).round_int())
+  }
+
+  /// Multiplies each lane by 2 to the power of the matching lane of `exp`.
+  /// There's only one rounding, so subnormal results are correctly rounded
+  /// too.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn ldexp(self, exp: i32x8) -> Self {
+    // past this every finite nonzero lane overflows or underflows
+    const_f32_as_f32x8!(LIMIT, 300.0);
+    const_f32_as_f32x8!(UP_BITS, 127.0);
+    const_f32_as_f32x8!(UP, 1.7014118346046923e38);
+    const_f32_as_f32x8!(MIN_EXP, -126.0);
+    // stepping down by 2^-126 * 2^24 rather than 2^-126 keeps
+    // the intermediate normal whenever the result isn't 0
+    const_f32_as_f32x8!(DOWN_BITS, 102.0);
+    const_f32_as_f32x8!(DOWN, 1.9721522630525295e-31);
+    let mut n = exp.round_float().max(-LIMIT).min(LIMIT);
+    let mut y = self;
+    for _ in 0..2 {
+      let up = n.cmp_gt(UP_BITS);
+      y = up.blend(y * UP, y);
+      n = up.blend(n - UP_BITS, n);
+      let down = n.cmp_lt(MIN_EXP);
+      y = down.blend(y * DOWN, y);
+      n = down.blend(n + DOWN_BITS, n);
+    }
+    y * n.vm_pow2n()
+  }
+
+  /// Multiplies every lane by `2^n`, rounding only once like
+  /// [`ldexp`](Self::ldexp).
+  #[inline]
+  #[must_use]
+  pub fn scalbn(self, n: i32) -> Self {
+    self.ldexp(i32x8::from(n))
+  }
+
+  /// The exponent of each lane, `floor(log2(|x|))`, as an integer. Zero and
+  /// NaN give `i32::MIN`, and infinity gives `i32::MAX`.
+  #[inline]
+  #[must_use]
+  pub fn ilogb(self) -> i32x8 {
+    let exp = self.abs().exponent_sub().round_int();
+    let exp = cast::<_, i32x8>(self.cmp_eq(Self::ZERO) | self.is_nan())
+      .blend(i32x8::from(i32::MIN), exp);
+    cast::<_, i32x8>(self.is_inf()).blend(i32x8::from(i32::MAX), exp)
+  }
+
+  /// The next representable value above each lane. Zero of either sign
+  /// steps to the smallest subnormal, and `+inf` and NaN are unchanged.
+  #[inline]
+  #[must_use]
+  pub fn next_up(self) -> Self {
+    let bits = cast::<_, i32x8>(self);
+    // stepping the bits away from zero for negatives and towards it otherwise
+    let step = i32x8::from(0).cmp_gt(bits) | i32x8::from(1);
+    let up = cast::<_, Self>(bits + step);
+    let up = self.cmp_eq(Self::ZERO).blend(Self::from(f32::from_bits(1)), up);
+    (self.is_nan() | self.cmp_eq(Self::from(f32::INFINITY))).blend(self, up)
+  }
+
+  /// The next representable value below each lane. Zero of either sign
+  /// steps to the smallest negative subnormal, and `-inf` and NaN are
+  /// unchanged.
+  #[inline]
+  #[must_use]
+  pub fn next_down(self) -> Self {
+    -(-self).next_up()
+  }
+
+  /// The next representable value after each lane in the direction of
+  /// `toward`, like C's `nextafter`. Equal lanes give `toward`, and NaN in
+  /// either gives NaN.
+  #[inline]
+  #[must_use]
+  pub fn next_after(self, toward: Self) -> Self {
+    let r = toward.cmp_gt(self).blend(self.next_up(), self.next_down());
+    let r = self.cmp_eq(toward).blend(toward, r);
+    (self.is_nan() | toward.is_nan()).blend(self + toward, r)
+  }
+
+  /// The unit in the last place of each lane: the gap between `|x|` and the
+  /// next larger value. `MAX` gives the gap below it, infinity gives
+  /// infinity and NaN gives NaN.
+  #[inline]
+  #[must_use]
+  pub fn ulp(self) -> Self {
+    let a = self.abs();
+    let r = a.next_up() - a;
+    let r = a.cmp_eq(Self::from(f32::MAX)).blend(a - a.next_down(), r);
+    self.is_inf().blend(a, r)
+  }
+
+  /// Maps the bits of each lane to an integer that sorts like
+  /// [`f32::total_cmp`].
+  #[inline]
+  #[must_use]
+  fn total_key(self) -> i32x8 {
+    let bits = cast::<_, i32x8>(self);
+    bits ^ (i32x8::from(0).cmp_gt(bits) & i32x8::from(i32::MAX))
+  }
+
+  /// Lanewise `self < rhs` under the IEEE 754 total order used by
+  /// [`f32::total_cmp`], so `-NaN < -inf < -0.0 < 0.0 < inf < NaN`.
+  #[inline]
+  #[must_use]
+  pub fn total_lt(self, rhs: Self) -> Self {
+    cast(rhs.total_key().cmp_gt(self.total_key()))
+  }
+
+  /// The larger of each pair of lanes under the total order of
+  /// [`total_lt`](Self::total_lt).
+  #[inline]
+  #[must_use]
+  pub fn total_max(self, rhs: Self) -> Self {
+    self.total_lt(rhs).blend(rhs, self)
+  }
+
+  /// The smaller of each pair of lanes under the total order of
+  /// [`total_lt`](Self::total_lt).
+  #[inline]
+  #[must_use]
+  pub fn total_min(self, rhs: Self) -> Self {
+    rhs.total_lt(self).blend(rhs, self)
+  }
+
   #[inline]
   #[allow(non_upper_case_globals)]
   fn fraction_2(self) -> Self {
@@ -1307,6 +2022,43 @@ impl f32x8 {
     }
   }
 
+  /// `ln(1 + x)`, accurate even when `x` is close to zero.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn ln_1p(self) -> Self {
+    const_f32_as_f32x8!(P0, 3.3333331174E-1);
+    const_f32_as_f32x8!(P1, -2.4999993993E-1);
+    const_f32_as_f32x8!(P2, 2.0000714765E-1);
+    const_f32_as_f32x8!(P3, -1.6668057665E-1);
+    const_f32_as_f32x8!(P4, 1.4249322787E-1);
+    const_f32_as_f32x8!(P5, -1.2420140846E-1);
+    const_f32_as_f32x8!(P6, 1.1676998740E-1);
+    const_f32_as_f32x8!(P7, -1.1514610310E-1);
+    const_f32_as_f32x8!(P8, 7.0376836292E-2);
+    const_f32_as_f32x8!(LN2F_HI, 0.693359375);
+    const_f32_as_f32x8!(LN2F_LO, -2.12194440e-4);
+    let x1 = self + Self::ONE;
+    let x = Self::fraction_2(x1);
+    let e = Self::exponent(x1);
+    let mask = x.cmp_gt(Self::SQRT_2 * Self::HALF);
+    let x = (!mask).blend(x + x, x);
+    let fe = mask.blend(e + Self::ONE, e);
+    // with no exponent `x` is just `x1`, and `self` is `x1 - 1` before the
+    // rounding of the add
+    let x = fe.cmp_eq(Self::ZERO).blend(self, x - Self::ONE);
+    let res = polynomial_8!(x, P0, P1, P2, P3, P4, P5, P6, P7, P8);
+    let x2 = x * x;
+    let res = x2 * x * res;
+    let res = fe.mul_add(LN2F_LO, res);
+    let res = res + x2.mul_neg_add(Self::HALF, x);
+    let res = fe.mul_add(LN2F_HI, res);
+    let res = x1.cmp_eq(Self::ZERO).blend(-Self::infinity(), res);
+    let res = x1.cmp_lt(Self::ZERO).blend(Self::nan_log(), res);
+    let same = self.cmp_eq(Self::ZERO) | self.cmp_eq(Self::infinity());
+    (same | self.is_nan()).blend(self, res)
+  }
+
   #[inline]
   #[must_use]
   pub fn log2(self) -> Self {
@@ -1430,6 +2182,538 @@ impl f32x8 {
   pub fn powf(self, y: f32) -> Self {
     Self::pow_f32x8(self, f32x8::splat(y))
   }
+
+  /// Raises each lane to the power `n` by repeated squaring. For positive `n`
+  /// the result is exact whenever it fits.
+  #[inline]
+  #[must_use]
+  pub fn powi(self, n: i32) -> Self {
+    let mut base = self;
+    let mut k = n.wrapping_abs() as u32;
+    let mut result = Self::ONE;
+    while k != 0 {
+      if k & 1 != 0 {
+        result *= base;
+      }
+      base *= base;
+      k >>= 1;
+    }
+    if n < 0 {
+      Self::ONE / result
+    } else {
+      result
+    }
+  }
+
+  /// `e^x / 2` for `x >= 0`, done so that it only overflows when the result
+  /// does (`exp` gives up a little before `f32::MAX`).
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn exp_half(self) -> Self {
+    const_f32_as_f32x8!(BIG, 80.0);
+    const_f32_as_f32x8!(OVERFLOW, 90.0);
+    let big = self.cmp_gt(BIG);
+    let e = big.blend(self * Self::HALF, self).exp();
+    let r = big.blend((e * Self::HALF) * e, e * Self::HALF);
+    self.cmp_gt(OVERFLOW).blend(Self::infinity(), r)
+  }
+
+  /// Hyperbolic sine.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn sinh(self) -> Self {
+    const_f32_as_f32x8!(P0, 1.66667160211E-1);
+    const_f32_as_f32x8!(P1, 8.33028376239E-3);
+    const_f32_as_f32x8!(P2, 2.03721912945E-4);
+    let x = self.abs();
+    let x2 = x * x;
+    // e^x - e^-x cancels near zero, so small lanes use a polynomial
+    let small = polynomial_2!(x2, P0, P1, P2).mul_add(x2 * x, x);
+    let e = x.exp_half();
+    let big = e - Self::from(0.25) / e;
+    let y = x.cmp_le(Self::ONE).blend(small, big);
+    self.is_nan().blend(self, y.copysign(self))
+  }
+
+  /// Hyperbolic cosine.
+  #[inline]
+  #[must_use]
+  pub fn cosh(self) -> Self {
+    let e = self.abs().exp_half();
+    let y = e + Self::from(0.25) / e;
+    self.is_nan().blend(self, y)
+  }
+
+  /// Hyperbolic tangent.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn tanh(self) -> Self {
+    const_f32_as_f32x8!(P0, -3.33332819422E-1);
+    const_f32_as_f32x8!(P1, 1.33314422036E-1);
+    const_f32_as_f32x8!(P2, -5.37397155531E-2);
+    const_f32_as_f32x8!(P3, 2.06390887954E-2);
+    const_f32_as_f32x8!(P4, -5.70498872745E-3);
+    const_f32_as_f32x8!(SATURATED, 9.0);
+    let x = self.abs();
+    let x2 = x * x;
+    let small = polynomial_4!(x2, P0, P1, P2, P3, P4).mul_add(x2 * x, x);
+    let e = (x + x).exp();
+    let big = Self::ONE - Self::from(2.0) / (e + Self::ONE);
+    // tanh rounds to 1 long before exp overflows
+    let big = x.cmp_gt(SATURATED).blend(Self::ONE, big);
+    let y = x.cmp_le(Self::from(0.625)).blend(small, big);
+    self.is_nan().blend(self, y.copysign(self))
+  }
+
+  /// Inverse hyperbolic sine.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn asinh(self) -> Self {
+    const_f32_as_f32x8!(P0, -1.6666288134E-1);
+    const_f32_as_f32x8!(P1, 7.4847586088E-2);
+    const_f32_as_f32x8!(P2, -4.2699340972E-2);
+    const_f32_as_f32x8!(P3, 2.0122003309E-2);
+    const_f32_as_f32x8!(HUGE, 1.0E10);
+    let x = self.abs();
+    let x2 = x * x;
+    let small = polynomial_3!(x2, P0, P1, P2, P3).mul_add(x2 * x, x);
+    // x^2 overflows long before asinh does, and past `HUGE` the +1 is lost
+    // anyway, so huge lanes use ln(2x)
+    let big = x
+      .cmp_gt(HUGE)
+      .blend(x.ln() + Self::LN_2, (x + (x2 + Self::ONE).sqrt()).ln());
+    let y = x.cmp_lt(Self::HALF).blend(small, big);
+    // infinite lanes stay infinite
+    x.is_inf().blend(x, y).copysign(self)
+  }
+
+  /// Inverse hyperbolic cosine. Lanes less than 1 give NaN.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn acosh(self) -> Self {
+    const_f32_as_f32x8!(P0, 1.4142135263E0);
+    const_f32_as_f32x8!(P1, -1.1784741703E-1);
+    const_f32_as_f32x8!(P2, 2.6454905019E-2);
+    const_f32_as_f32x8!(P3, -7.5272886713E-3);
+    const_f32_as_f32x8!(P4, 1.7596881071E-3);
+    const_f32_as_f32x8!(HUGE, 1.0E10);
+    let x1 = self - Self::ONE;
+    // near 1 the log form cancels badly, so use sqrt(x - 1) * P(x - 1)
+    let small = x1.sqrt() * polynomial_4!(x1, P0, P1, P2, P3, P4);
+    let big = self.cmp_gt(HUGE).blend(
+      self.ln() + Self::LN_2,
+      (self + self.mul_sub(self, Self::ONE).sqrt()).ln(),
+    );
+    let y = x1.cmp_lt(Self::HALF).blend(small, big);
+    let y = self.is_inf().blend(self, y);
+    self.cmp_lt(Self::ONE).blend(Self::from(f32::NAN), y)
+  }
+
+  /// Inverse hyperbolic tangent. Lanes outside of `[-1, 1]` give NaN.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn atanh(self) -> Self {
+    const_f32_as_f32x8!(P0, 3.33337300303E-1);
+    const_f32_as_f32x8!(P1, 1.99782164500E-1);
+    const_f32_as_f32x8!(P2, 1.46691431730E-1);
+    const_f32_as_f32x8!(P3, 8.20452912776E-2);
+    const_f32_as_f32x8!(P4, 1.81740078349E-1);
+    const_f32_as_f32x8!(SMALL, 0.3);
+    let x = self.abs();
+    let x2 = x * x;
+    let small = polynomial_4!(x2, P0, P1, P2, P3, P4).mul_add(x2 * x, x);
+    let big = Self::HALF * ((Self::ONE + x) / (Self::ONE - x)).ln();
+    let y = x.cmp_lt(SMALL).blend(small, big);
+    // the ends of the domain go to infinity
+    let y = x.cmp_eq(Self::ONE).blend(Self::from(f32::INFINITY), y);
+    y.copysign(self)
+  }
+
+  /// `e^(-k * x^2)` for `k` a power of two, without the rounding error that
+  /// squaring `x` would add.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn exp_neg_sq(self, k: Self) -> Self {
+    const_f32_as_f32x8!(SPLIT, 64.0);
+    // `exp` gives 0 a little early, so big lanes are shifted by e^16
+    const_f32_as_f32x8!(BIG, 80.0);
+    const_f32_as_f32x8!(SHIFT, 16.0);
+    const_f32_as_f32x8!(EXP_NEG_SHIFT, 1.1253517471925912e-07);
+    // `hi` has few enough bits that `hi * hi` is exact
+    let hi = (self * SPLIT).round() / SPLIT;
+    let lo = self - hi;
+    let u = hi * hi * k;
+    let u1 = (hi + hi).mul_add(lo, lo * lo) * k;
+    let big = u.cmp_gt(BIG);
+    let e = (-u + (big & SHIFT)).exp() * (-u1).exp();
+    big.blend(e * EXP_NEG_SHIFT, e)
+  }
+
+  /// `erf(x)` for `|x| < 1`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn erf_small(self) -> Self {
+    const_f32_as_f32x8!(T0, 5.55923013010394962768E4);
+    const_f32_as_f32x8!(T1, 7.00332514112805075473E3);
+    const_f32_as_f32x8!(T2, 2.23200534594684319226E3);
+    const_f32_as_f32x8!(T3, 9.00260197203842689217E1);
+    const_f32_as_f32x8!(T4, 9.60497373987051638749E0);
+    const_f32_as_f32x8!(U0, 4.92673942608635921086E4);
+    const_f32_as_f32x8!(U1, 2.26290000613890934246E4);
+    const_f32_as_f32x8!(U2, 4.59432382970980127987E3);
+    const_f32_as_f32x8!(U3, 5.21357949780152679795E2);
+    const_f32_as_f32x8!(U4, 3.35617141647503099647E1);
+    let z = self * self;
+    self * polynomial_4!(z, T0, T1, T2, T3, T4)
+      / polynomial_5n!(z, U0, U1, U2, U3, U4)
+  }
+
+  /// `erfc(x)` for `|x| < 1`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn erfc_small(self) -> Self {
+    // `1 - erf(x)` cancels as erfc falls toward 0.16, so from 0.625 up erfc
+    // has its own fit around 0.8125
+    const_f32_as_f32x8!(MID, 0.625);
+    const_f32_as_f32x8!(CENTER, 0.8125);
+    const_f32_as_f32x8!(V0, 0.25053596);
+    const_f32_as_f32x8!(V1, -0.58311325);
+    const_f32_as_f32x8!(V2, 0.47377944);
+    const_f32_as_f32x8!(V3, -0.062239118);
+    const_f32_as_f32x8!(V4, -0.13263339);
+    const_f32_as_f32x8!(V5, 0.060622573);
+    const_f32_as_f32x8!(V6, 0.018626506);
+    let a = self.abs();
+    let t = a - CENTER;
+    let y = polynomial_6!(t, V0, V1, V2, V3, V4, V5, V6);
+    let y = self.cmp_lt(Self::ZERO).blend(Self::from(2.0) - y, y);
+    a.cmp_lt(MID).blend(Self::ONE - self.erf_small(), y)
+  }
+
+  /// `erfc(x)` for `|x| >= 1`, given `e = e^(-x^2)`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn erfc_big(self, e: Self) -> Self {
+    const_f32_as_f32x8!(P0, 5.57535335369399327526E2);
+    const_f32_as_f32x8!(P1, 1.02755188689515710272E3);
+    const_f32_as_f32x8!(P2, 9.34528527171957607540E2);
+    const_f32_as_f32x8!(P3, 5.26445194995477358631E2);
+    const_f32_as_f32x8!(P4, 1.96520832956077098242E2);
+    const_f32_as_f32x8!(P5, 4.86371970985681366614E1);
+    const_f32_as_f32x8!(P6, 7.46321056442269912687E0);
+    const_f32_as_f32x8!(P7, 5.64189564831068821977E-1);
+    const_f32_as_f32x8!(P8, 2.46196981473530512524E-10);
+    const_f32_as_f32x8!(Q0, 5.57535340817727675546E2);
+    const_f32_as_f32x8!(Q1, 1.65666309194161350182E3);
+    const_f32_as_f32x8!(Q2, 2.24633760818710981792E3);
+    const_f32_as_f32x8!(Q3, 1.82390916687909736289E3);
+    const_f32_as_f32x8!(Q4, 9.75708501743205489753E2);
+    const_f32_as_f32x8!(Q5, 3.54937778887819891062E2);
+    const_f32_as_f32x8!(Q6, 8.67072140885989742329E1);
+    const_f32_as_f32x8!(Q7, 1.32281951154744992508E1);
+    const_f32_as_f32x8!(R0, 2.97886665372100240670E0);
+    const_f32_as_f32x8!(R1, 7.40974269950448939160E0);
+    const_f32_as_f32x8!(R2, 6.16021097993053585195E0);
+    const_f32_as_f32x8!(R3, 5.01905042251180477414E0);
+    const_f32_as_f32x8!(R4, 1.27536670759978104416E0);
+    const_f32_as_f32x8!(R5, 5.64189583547755073984E-1);
+    const_f32_as_f32x8!(S0, 3.36907645100081516050E0);
+    const_f32_as_f32x8!(S1, 9.60896809063285878198E0);
+    const_f32_as_f32x8!(S2, 1.70814450747565897222E1);
+    const_f32_as_f32x8!(S3, 1.20489539808096656605E1);
+    const_f32_as_f32x8!(S4, 9.39603524938001434673E0);
+    const_f32_as_f32x8!(S5, 2.26052863220117276590E0);
+    // past this erfc is below the smallest subnormal
+    const_f32_as_f32x8!(LIMIT, 10.1);
+    let a = self.abs();
+    let p = polynomial_8!(a, P0, P1, P2, P3, P4, P5, P6, P7, P8)
+      / polynomial_8!(a, Q0, Q1, Q2, Q3, Q4, Q5, Q6, Q7, Self::ONE);
+    let r = polynomial_5!(a, R0, R1, R2, R3, R4, R5)
+      / polynomial_6n!(a, S0, S1, S2, S3, S4, S5);
+    let y = e * a.cmp_lt(Self::from(8.0)).blend(p, r);
+    let y = a.cmp_gt(LIMIT).blend(Self::ZERO, y);
+    self.cmp_lt(Self::ZERO).blend(Self::from(2.0) - y, y)
+  }
+
+  /// The error function.
+  #[inline]
+  #[must_use]
+  pub fn erf(self) -> Self {
+    let a = self.abs();
+    let big = Self::ONE - a.erfc_big(a.exp_neg_sq(Self::ONE));
+    let y = a.cmp_lt(Self::ONE).blend(self.erf_small(), big.copysign(self));
+    self.is_nan().blend(self, y)
+  }
+
+  /// The complementary error function, `1 - erf(x)`, without losing
+  /// precision for large `x`.
+  #[inline]
+  #[must_use]
+  pub fn erfc(self) -> Self {
+    let a = self.abs();
+    let big = self.erfc_big(a.exp_neg_sq(Self::ONE));
+    let y = a.cmp_lt(Self::ONE).blend(self.erfc_small(), big);
+    self.is_nan().blend(self, y)
+  }
+
+  /// The cumulative distribution function of the standard normal
+  /// distribution.
+  #[inline]
+  #[must_use]
+  pub fn norm_cdf(self) -> Self {
+    let x = -self * Self::FRAC_1_SQRT_2;
+    // the `e^(-x^2)` comes from `self`, so the rounding of `x` doesn't get
+    // blown up in the tails
+    let big = x.erfc_big(self.exp_neg_sq(Self::HALF));
+    let y = x.abs().cmp_lt(Self::ONE).blend(x.erfc_small(), big);
+    self.is_nan().blend(self, y * Self::HALF)
+  }
+
+  /// The inverse of [`norm_cdf`](Self::norm_cdf), also known as the probit
+  /// function. Lanes outside of `[0, 1]` give NaN.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn norm_inv_cdf(self) -> Self {
+    // P. J. Acklam's rational approximations, good to about 1.2e-9
+    const_f32_as_f32x8!(A0, 2.506628277459239e+00);
+    const_f32_as_f32x8!(A1, -3.066479806614716e+01);
+    const_f32_as_f32x8!(A2, 1.383577518672690e+02);
+    const_f32_as_f32x8!(A3, -2.759285104469687e+02);
+    const_f32_as_f32x8!(A4, 2.209460984245205e+02);
+    const_f32_as_f32x8!(A5, -3.969683028665376e+01);
+    const_f32_as_f32x8!(B1, -1.328068155288572e+01);
+    const_f32_as_f32x8!(B2, 6.680131188771972e+01);
+    const_f32_as_f32x8!(B3, -1.556989798598866e+02);
+    const_f32_as_f32x8!(B4, 1.615858368580409e+02);
+    const_f32_as_f32x8!(B5, -5.447609879822406e+01);
+    const_f32_as_f32x8!(C0, 2.938163982698783e+00);
+    const_f32_as_f32x8!(C1, 4.374664141464968e+00);
+    const_f32_as_f32x8!(C2, -2.549732539343734e+00);
+    const_f32_as_f32x8!(C3, -2.400758277161838e+00);
+    const_f32_as_f32x8!(C4, -3.223964580411365e-01);
+    const_f32_as_f32x8!(C5, -7.784894002430293e-03);
+    const_f32_as_f32x8!(D1, 3.754408661907416e+00);
+    const_f32_as_f32x8!(D2, 2.445134137142996e+00);
+    const_f32_as_f32x8!(D3, 3.224671290700398e-01);
+    const_f32_as_f32x8!(D4, 7.784695709041462e-03);
+    const_f32_as_f32x8!(P_LOW, 0.02425);
+    // `ln` doesn't take subnormals, so those are scaled up by 2^24 first
+    const_f32_as_f32x8!(SCALE_UP, 16777216.0);
+    const_f32_as_f32x8!(LN_SCALE, 16.635532333438686);
+    let q = self - Self::HALF;
+    let r = q * q;
+    let central = q * polynomial_5!(r, A0, A1, A2, A3, A4, A5)
+      / polynomial_5!(r, Self::ONE, B1, B2, B3, B4, B5);
+    // the tails work from the distance to the nearer end
+    let p = self.min(Self::ONE - self);
+    let tiny = p.cmp_lt(Self::from(f32::MIN_POSITIVE));
+    let ln_p = tiny.blend(p * SCALE_UP, p).ln() - (tiny & LN_SCALE);
+    let t = (Self::from(-2.0) * ln_p).sqrt();
+    let tail = polynomial_5!(t, C0, C1, C2, C3, C4, C5)
+      / polynomial_4!(t, Self::ONE, D1, D2, D3, D4);
+    let tail = q.cmp_gt(Self::ZERO).blend(-tail, tail);
+    let x = p.cmp_lt(P_LOW).blend(tail, central);
+    // one step of Halley's method brings that to full precision
+    const_f32_as_f32x8!(SQRT_2PI, 2.5066282746310002);
+    let e = x.norm_cdf() - self;
+    let u = e * SQRT_2PI * (x * x * Self::HALF).exp();
+    let x = x - u / (x * u).mul_add(Self::HALF, Self::ONE);
+    let x = self.cmp_eq(Self::ZERO).blend(-Self::infinity(), x);
+    let x = self.cmp_eq(Self::ONE).blend(Self::infinity(), x);
+    let outside = self.cmp_lt(Self::ZERO) | self.cmp_gt(Self::ONE);
+    (outside | self.is_nan()).blend(Self::from(f32::NAN), x)
+  }
+
+  /// The Lanczos sum for `x > 0`, scaled so that
+  /// `gamma(x) = sum * y^(x - 0.5) / e^y` with `y = x + g - 0.5`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn lanczos_sum(self) -> Self {
+    const_f32_as_f32x8!(N0, 4582.924);
+    const_f32_as_f32x8!(N1, 5101.4883);
+    const_f32_as_f32x8!(N2, 2271.5107);
+    const_f32_as_f32x8!(N3, 505.71443);
+    const_f32_as_f32x8!(N4, 56.294693);
+    const_f32_as_f32x8!(N5, 2.506628);
+    const_f32_as_f32x8!(D0, 0.0);
+    const_f32_as_f32x8!(D1, 24.0);
+    const_f32_as_f32x8!(D2, 50.0);
+    const_f32_as_f32x8!(D3, 35.0);
+    const_f32_as_f32x8!(D4, 10.0);
+    const_f32_as_f32x8!(D5, 1.0);
+    let direct = polynomial_5!(self, N0, N1, N2, N3, N4, N5)
+      / polynomial_5!(self, D0, D1, D2, D3, D4, D5);
+    // big lanes go through 1 / x so that the powers can't overflow
+    let r = Self::ONE / self;
+    let reversed = polynomial_5!(r, N5, N4, N3, N2, N1, N0)
+      / polynomial_5!(r, D5, D4, D3, D2, D1, D0);
+    self.cmp_lt(Self::from(5.0)).blend(direct, reversed)
+  }
+
+  /// `gamma(x)` for `x > 0` as two factors, each finite whenever the
+  /// product is.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn gamma_parts(self) -> (Self, Self) {
+    const_f32_as_f32x8!(G_MINUS_HALF, 4.5);
+    let y = self + G_MINUS_HALF;
+    let h = y.pow_f32x8((self - Self::HALF) * Self::HALF);
+    (self.lanczos_sum() * h, h / y.exp())
+  }
+
+  /// The gamma function. Zero gives infinity with the sign of the zero, and
+  /// the negative integers give NaN. For large `|x|` this is only as accurate
+  /// as [`pow_f32x8`](Self::pow_f32x8).
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn gamma(self) -> Self {
+    // below this gamma(x) rounds to 1 / x
+    const_f32_as_f32x8!(TINY, 1.4901161193847656e-8);
+    const_f32_as_f32x8!(OVERFLOW, 35.1);
+    // past this gamma(-x) is below the smallest subnormal
+    const_f32_as_f32x8!(UNDERFLOW, 42.0);
+    // up to here the factorials are exact and the error is well under 0.5
+    const_f32_as_f32x8!(EXACT, 10.0);
+    let a = self.abs();
+    let (p, q) = a.gamma_parts();
+    // gamma(-a) = -pi / (a * sin(pi * a) * gamma(a))
+    let neg = -Self::PI / (a * a.sin_pi() * p) / q;
+    let neg = a.cmp_gt(UNDERFLOW).blend(Self::ZERO, neg);
+    let pos = p * q;
+    let int = self.cmp_eq(self.round()) & self.cmp_le(EXACT);
+    let pos = int.blend(pos.round(), pos);
+    let y = self.cmp_lt(Self::ZERO).blend(neg, pos);
+    let y = a.cmp_lt(TINY).blend(Self::ONE / self, y);
+    let y = self.cmp_gt(OVERFLOW).blend(Self::infinity(), y);
+    let pole = self.cmp_lt(Self::ZERO) & self.cmp_eq(self.round());
+    (pole | self.is_nan()).blend(Self::from(f32::NAN), y)
+  }
+
+  /// `ln(gamma(2 + t))` for `-0.75 <= t < 0.5`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn ln_gamma_2p(self) -> Self {
+    // ln(gamma(2 + t)) = t * p(t), with `p` fitted around the middle of each
+    // of four pieces of the range
+    const_f32_as_f32x8!(B1, -0.4375);
+    const_f32_as_f32x8!(B2, -0.125);
+    const_f32_as_f32x8!(B3, 0.1875);
+    const_f32_as_f32x8!(M0, -0.59375);
+    const_f32_as_f32x8!(M1, -0.28125);
+    const_f32_as_f32x8!(M2, 0.03125);
+    const_f32_as_f32x8!(M3, 0.34375);
+    const_f32_as_f32x8!(P0, 0.20206583);
+    const_f32_as_f32x8!(P1, 0.43294507);
+    const_f32_as_f32x8!(P2, -0.12911138);
+    const_f32_as_f32x8!(P3, 0.057029106);
+    const_f32_as_f32x8!(P4, -0.029580334);
+    const_f32_as_f32x8!(P5, 0.016943287);
+    const_f32_as_f32x8!(P6, -0.01004794);
+    const_f32_as_f32x8!(Q0, 0.32625297);
+    const_f32_as_f32x8!(Q1, 0.36599895);
+    const_f32_as_f32x8!(Q2, -0.089000136);
+    const_f32_as_f32x8!(Q3, 0.03184458);
+    const_f32_as_f32x8!(Q4, -0.013387652);
+    const_f32_as_f32x8!(Q5, 0.006195821);
+    const_f32_as_f32x8!(Q6, -0.0029901166);
+    const_f32_as_f32x8!(R0, 0.43279627);
+    const_f32_as_f32x8!(R1, 0.31831694);
+    const_f32_as_f32x8!(R2, -0.06546526);
+    const_f32_as_f32x8!(R3, 0.01968489);
+    const_f32_as_f32x8!(R4, -0.006950747);
+    const_f32_as_f32x8!(R5, 0.0026965993);
+    const_f32_as_f32x8!(R6, -0.001095589);
+    const_f32_as_f32x8!(S0, 0.52641875);
+    const_f32_as_f32x8!(S1, 0.28243056);
+    const_f32_as_f32x8!(S2, -0.050397538);
+    const_f32_as_f32x8!(S3, 0.013072529);
+    const_f32_as_f32x8!(S4, -0.003977415);
+    const_f32_as_f32x8!(S5, 0.0013276886);
+    const_f32_as_f32x8!(S6, -0.0004653591);
+    let in_1 = self.cmp_ge(B1);
+    let in_2 = self.cmp_ge(B2);
+    let in_3 = self.cmp_ge(B3);
+    let pick = |p: Self, q: Self, r: Self, s: Self| {
+      in_3.blend(s, in_2.blend(r, in_1.blend(q, p)))
+    };
+    let u = self - pick(M0, M1, M2, M3);
+    self
+      * polynomial_6!(
+        u,
+        pick(P0, Q0, R0, S0),
+        pick(P1, Q1, R1, S1),
+        pick(P2, Q2, R2, S2),
+        pick(P3, Q3, R3, S3),
+        pick(P4, Q4, R4, S4),
+        pick(P5, Q5, R5, S5),
+        pick(P6, Q6, R6, S6)
+      )
+  }
+
+  /// The natural log of the absolute value of the gamma function, along
+  /// with the sign of the gamma function as `1.0` or `-1.0`. The poles give
+  /// infinity.
+  ///
+  /// Around the zeros at negative `x`, the first of which is near `-2.457`,
+  /// the terms of the reflection formula cancel, so there the result is only
+  /// accurate in absolute terms and not relative to its size.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn ln_gamma(self) -> (Self, Self) {
+    const_f32_as_f32x8!(G, 5.0);
+    const_f32_as_f32x8!(G_MINUS_HALF, 4.5);
+    const_f32_as_f32x8!(TINY, 1.4901161193847656e-8);
+    const_f32_as_f32x8!(LN_PI, 1.1447298858494002);
+    // `ln` doesn't take subnormals, so those are scaled up by 2^24 first
+    const_f32_as_f32x8!(SCALE_UP, 16777216.0);
+    const_f32_as_f32x8!(LN_SCALE, 16.635532333438686);
+    let a = self.abs();
+    let sub = a.cmp_lt(Self::from(f32::MIN_POSITIVE));
+    let ln_a = sub.blend(a * SCALE_UP, a).ln() - (sub & LN_SCALE);
+    let y = a + G_MINUS_HALF;
+    let pos =
+      (a - Self::HALF).mul_add(y.ln() - Self::ONE, a.lanczos_sum().ln() - G);
+    let pos = a.cmp_lt(TINY).blend(-ln_a, pos);
+    // from 0.25 to 3.5 the Lanczos form loses its relative accuracy around
+    // the zeros at 1 and 2, so there gamma(x + 1) = x * gamma(x) moves the
+    // lane to ln(gamma(2 + t)), which has its own fit
+    let n = a
+      .cmp_lt(Self::from(1.25))
+      .blend(-Self::ONE, a.cmp_ge(Self::from(2.5)) & Self::ONE);
+    let t = a - (n + Self::from(2.0));
+    let z = n.cmp_lt(Self::ZERO).blend(t, a - Self::from(2.0));
+    let mid = n.mul_add(z.ln_1p(), t.ln_gamma_2p());
+    let in_mid = a.cmp_ge(Self::from(0.25)) & a.cmp_lt(Self::from(3.5));
+    let pos = in_mid.blend(mid, pos);
+    let s = a.sin_pi();
+    // ln|gamma(-a)| = ln(pi) - ln|sin(pi * a)| - ln(a) - ln(gamma(a))
+    let neg = LN_PI - s.abs().ln() - ln_a - pos;
+    let neg = a.cmp_lt(TINY).blend(pos, neg);
+    let y = self.cmp_lt(Self::ZERO).blend(neg, pos);
+    let pole = self.cmp_le(Self::ZERO) & self.cmp_eq(self.round());
+    let y = pole.blend(Self::infinity(), y);
+    let y = self.is_nan().blend(self, y);
+    let sign = (self.cmp_lt(Self::ZERO) & s.cmp_gt(Self::ZERO))
+      .blend(-Self::ONE, Self::ONE);
+    let sign = self.cmp_eq(Self::ZERO).blend(Self::ONE.copysign(self), sign);
+    (y, sign)
+  }
 }
 
 impl Not for f32x8 {
@@ -119,6 +119,51 @@ impl Div for f64x2 {
   }
 }
 
+impl Rem for f64x2 {
+  type Output = Self;
+  /// The remainder of dividing lane by lane, with the sign of `self`, like C's
+  /// `fmod`. The result is exact.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn rem(self, rhs: Self) -> Self::Output {
+    // each step takes a quotient of at most 26 bits off, which keeps all of
+    // the products below exact
+    const_f64_as_f64x2!(STEP, 25.0);
+    const_f64_as_f64x2!(SCALE_UP, 18014398509481984.0);
+    const_f64_as_f64x2!(SCALE_BITS, 54.0);
+    // lanes past this work at a quarter of the size, so that `q * hi` can't
+    // overflow
+    const_f64_as_f64x2!(BIG, 2.247116418577895e307);
+    let y = rhs.abs();
+    let special = !self.is_finite() | rhs.is_nan() | y.cmp_eq(Self::ZERO);
+    let mut r = special.blend(Self::ZERO, self.abs());
+    // `mul_pow2n` can't get all the way up from a subnormal, so those start
+    // out scaled up by 2^54
+    let tiny = y.cmp_lt(Self::from(f64::MIN_POSITIVE));
+    let y_scaled = tiny.blend(y * SCALE_UP, y);
+    let shift = tiny & SCALE_BITS;
+    let ey = y.exponent_sub();
+    while r.cmp_ge(y).any() {
+      let k = (r.exponent_sub() - ey - STEP).max(Self::ZERO);
+      let big = r.cmp_ge(BIG);
+      let d = y_scaled.mul_pow2n(k - shift - (big & Self::from(2.0)));
+      let rq = big.blend(r * Self::from(0.25), r);
+      let q = (rq / d).trunc();
+      // `hi` is `d` rounded up to 26 bits, so `q * hi` is close enough to
+      // `r` that `r - q * hi` is exact, and `lo` is short enough that
+      // `q * lo` is exact
+      let low = u64x2::from(0x7FF_FFFF);
+      let hi = cast::<_, Self>((cast::<_, u64x2>(d) + low) & !low);
+      let lo = d - hi;
+      let r1 = (rq - q * hi) - q * lo;
+      let r1 = r1.cmp_lt(Self::ZERO).blend(r1 + d, r1);
+      r = big.blend(r1 * Self::from(4.0), r1);
+    }
+    special.blend(Self::from(f64::NAN), r.copysign(self))
+  }
+}
+
 impl Add<f64> for f64x2 {
   type Output = Self;
   #[inline]
@@ -155,6 +200,15 @@ impl Div<f64> for f64x2 {
   }
 }
 
+impl Rem<f64> for f64x2 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: f64) -> Self::Output {
+    self.rem(Self::splat(rhs))
+  }
+}
+
 impl Add<f64x2> for f64 {
   type Output = f64x2;
   #[inline]
@@ -191,6 +245,15 @@ impl Div<f64x2> for f64 {
   }
 }
 
+impl Rem<f64x2> for f64 {
+  type Output = f64x2;
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: f64x2) -> Self::Output {
+    f64x2::splat(self).rem(rhs)
+  }
+}
+
 impl BitAnd for f64x2 {
   type Output = Self;
   #[inline]
@@ -374,6 +437,10 @@ impl f64x2 {
     let non_sign_bits = f64x2::from(f64::from_bits(i64::MAX as u64));
     self & non_sign_bits
   }
+  /// Lanewise maximum. What comes out for NaN depends on the backend and the
+  /// order of the operands, so use [`max_num`](Self::max_num),
+  /// [`maximum`](Self::maximum) or [`fast_max`](Self::fast_max) when it
+  /// matters.
   #[inline]
   #[must_use]
   pub fn max(self, rhs: Self) -> Self {
@@ -388,6 +455,10 @@ impl f64x2 {
       }
     }
   }
+  /// Lanewise minimum. What comes out for NaN depends on the backend and the
+  /// order of the operands, so use [`min_num`](Self::min_num),
+  /// [`minimum`](Self::minimum) or [`fast_min`](Self::fast_min) when it
+  /// matters.
   #[inline]
   #[must_use]
   pub fn min(self, rhs: Self) -> Self {
@@ -402,6 +473,148 @@ impl f64x2 {
       }
     }
   }
+
+  /// Lanewise maximum, the way `maxpd` does it: `self` if it's greater,
+  /// otherwise `rhs`. That makes a NaN in either lane, or a pair of zeros,
+  /// give `rhs`.
+  #[inline]
+  #[must_use]
+  pub fn fast_max(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: max_m128d(self.sse, rhs.sse) }
+      } else {
+        Self { arr: [
+          if self.arr[0] > rhs.arr[0] { self.arr[0] } else { rhs.arr[0] },
+          if self.arr[1] > rhs.arr[1] { self.arr[1] } else { rhs.arr[1] },
+        ]}
+      }
+    }
+  }
+
+  /// Lanewise minimum, the way `minpd` does it: `self` if it's less,
+  /// otherwise `rhs`. That makes a NaN in either lane, or a pair of zeros,
+  /// give `rhs`.
+  #[inline]
+  #[must_use]
+  pub fn fast_min(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: min_m128d(self.sse, rhs.sse) }
+      } else {
+        Self { arr: [
+          if self.arr[0] < rhs.arr[0] { self.arr[0] } else { rhs.arr[0] },
+          if self.arr[1] < rhs.arr[1] { self.arr[1] } else { rhs.arr[1] },
+        ]}
+      }
+    }
+  }
+
+  /// Lanewise maximum that ignores NaN like [`f64::max`], so only two NaN
+  /// lanes give NaN. `-0.0` counts as less than `0.0`.
+  #[inline]
+  #[must_use]
+  pub fn max_num(self, rhs: Self) -> Self {
+    let m = rhs.fast_max(self);
+    let m = self.cmp_eq(rhs).blend(self & rhs, m);
+    self.is_nan().blend(rhs, m)
+  }
+
+  /// Lanewise minimum that ignores NaN like [`f64::min`], so only two NaN
+  /// lanes give NaN. `-0.0` counts as less than `0.0`.
+  #[inline]
+  #[must_use]
+  pub fn min_num(self, rhs: Self) -> Self {
+    let m = rhs.fast_min(self);
+    let m = self.cmp_eq(rhs).blend(self | rhs, m);
+    self.is_nan().blend(rhs, m)
+  }
+
+  /// Lanewise IEEE 754-2019 `maximum`: a NaN in either lane gives NaN, and
+  /// `-0.0` counts as less than `0.0`.
+  #[inline]
+  #[must_use]
+  pub fn maximum(self, rhs: Self) -> Self {
+    let m = self.fast_max(rhs);
+    let m = self.cmp_eq(rhs).blend(self & rhs, m);
+    (self.is_nan() | rhs.is_nan()).blend(self + rhs, m)
+  }
+
+  /// Lanewise IEEE 754-2019 `minimum`: a NaN in either lane gives NaN, and
+  /// `-0.0` counts as less than `0.0`.
+  #[inline]
+  #[must_use]
+  pub fn minimum(self, rhs: Self) -> Self {
+    let m = self.fast_min(rhs);
+    let m = self.cmp_eq(rhs).blend(self | rhs, m);
+    (self.is_nan() | rhs.is_nan()).blend(self + rhs, m)
+  }
+
+  /// Clamps each lane to `[min, max]`. NaN lanes stay NaN, and a NaN bound
+  /// is ignored.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    // the fast ops give their second operand whenever there's a NaN
+    max.fast_min(min.fast_max(self))
+  }
+
+  /// Clamps each lane to `[0, 1]`, keeping NaN.
+  #[inline]
+  #[must_use]
+  pub fn saturate(self) -> Self {
+    self.clamp(Self::ZERO, Self::ONE)
+  }
+
+  /// Linear interpolation from `self` at `t = 0` to `b` at `t = 1`. Both
+  /// ends come out exactly, and `t` outside `[0, 1]` extrapolates.
+  #[inline]
+  #[must_use]
+  pub fn lerp(self, b: Self, t: Self) -> Self {
+    t.mul_add(b, t.mul_neg_add(self, self))
+  }
+
+  /// The `t` at which `a.lerp(b, t)` gives `v`. Equal `a` and `b` give NaN
+  /// or infinity.
+  #[inline]
+  #[must_use]
+  pub fn inverse_lerp(a: Self, b: Self, v: Self) -> Self {
+    (v - a) / (b - a)
+  }
+
+  /// Maps each lane linearly from `[in_start, in_end]` onto
+  /// `[out_start, out_end]`, extrapolating outside of them.
+  #[inline]
+  #[must_use]
+  pub fn remap(
+    self,
+    in_start: Self,
+    in_end: Self,
+    out_start: Self,
+    out_end: Self,
+  ) -> Self {
+    out_start.lerp(out_end, Self::inverse_lerp(in_start, in_end, self))
+  }
+
+  /// 0 up to `edge0`, 1 from `edge1` on, and the cubic `3t^2 - 2t^3` in
+  /// between, like GLSL's `smoothstep`. NaN lanes stay NaN.
+  #[inline]
+  #[must_use]
+  pub fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+    let t = Self::inverse_lerp(edge0, edge1, self).saturate();
+    t * t * t.mul_neg_add(Self::from(2.0), Self::from(3.0))
+  }
+
+  /// Like [`smoothstep`](Self::smoothstep), but with the quintic
+  /// `6t^5 - 15t^4 + 10t^3`, which also has flat second derivatives at the
+  /// edges.
+  #[inline]
+  #[must_use]
+  pub fn smootherstep(self, edge0: Self, edge1: Self) -> Self {
+    let t = Self::inverse_lerp(edge0, edge1, self).saturate();
+    let p = t.mul_add(Self::from(6.0), Self::from(-15.0));
+    t * t * t * t.mul_add(p, Self::from(10.0))
+  }
   #[inline]
   #[must_use]
   pub fn is_nan(self) -> Self {
@@ -450,6 +663,75 @@ impl f64x2 {
       }
     }
   }
+  /// Rounds each lane toward zero.
+  #[inline]
+  #[must_use]
+  pub fn trunc(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        Self { sse: round_m128d!(self.sse, Zero) }
+      } else {
+        // adding and subtracting 2^52 rounds off all the fraction bits, and
+        // lanes that are already that big (or NaN) have no fraction bits
+        let abs = self.abs();
+        let magic = Self::from(4503599627370496.0);
+        let r = abs + magic - magic;
+        let r = r.cmp_gt(abs).blend(r - Self::from(1.0), r);
+        abs.cmp_lt(magic).blend(r.copysign(self), self)
+      }
+    }
+  }
+  /// Rounds each lane toward negative infinity.
+  #[inline]
+  #[must_use]
+  pub fn floor(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        Self { sse: round_m128d!(self.sse, NegInf) }
+      } else {
+        let t = self.trunc();
+        t.cmp_gt(self).blend(t - Self::from(1.0), t)
+      }
+    }
+  }
+  /// Rounds each lane toward positive infinity.
+  #[inline]
+  #[must_use]
+  pub fn ceil(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        Self { sse: round_m128d!(self.sse, PosInf) }
+      } else {
+        let t = self.trunc();
+        t.cmp_lt(self).blend(t + Self::from(1.0), t)
+      }
+    }
+  }
+  /// The fractional part of each lane, `self - self.trunc()`.
+  #[inline]
+  #[must_use]
+  pub fn fract(self) -> Self {
+    self - self.trunc()
+  }
+
+  /// The least nonnegative remainder of `self` divided by `rhs`, like
+  /// `f64::rem_euclid`.
+  #[inline]
+  #[must_use]
+  pub fn rem_euclid(self, rhs: Self) -> Self {
+    let r = self % rhs;
+    r.cmp_lt(Self::ZERO).blend(r + rhs.abs(), r)
+  }
+
+  /// The quotient of Euclidean division, like `f64::div_euclid`. It's
+  /// rounded so that `self` is close to `rhs * q + self.rem_euclid(rhs)`.
+  #[inline]
+  #[must_use]
+  pub fn div_euclid(self, rhs: Self) -> Self {
+    let q = (self / rhs).trunc();
+    let down = rhs.cmp_gt(Self::ZERO).blend(q - Self::ONE, q + Self::ONE);
+    (self % rhs).cmp_lt(Self::ZERO).blend(down, q)
+  }
   #[inline]
   #[must_use]
   pub fn round_int(self) -> i64x2 {
@@ -925,6 +1207,48 @@ impl f64x2 {
     // Based on the Agner Fog "vector class library":
     // https://github.com/vectorclass/version2/blob/master/vectormath_trig.h
 
+    const_f64_as_f64x2!(DP1, 7.853981554508209228515625E-1 * 2.);
+    const_f64_as_f64x2!(DP2, 7.94662735614792836714E-9 * 2.);
+    const_f64_as_f64x2!(DP3, 3.06161699786838294307E-17 * 2.);
+
+    // Cody-Waite loses too many bits past this
+    const_f64_as_f64x2!(REDUCE_MAX, 65536.0);
+
+    const_f64_as_f64x2!(TWO_OVER_PI, 2.0 / core::f64::consts::PI);
+
+    let xa = self.abs();
+
+    let y = (xa * TWO_OVER_PI).round();
+    let mut q = y.round_int();
+
+    let mut x = y.mul_neg_add(DP3, y.mul_neg_add(DP2, y.mul_neg_add(DP1, xa)));
+
+    // lanes past that are reduced one at a time with Payne-Hanek instead
+    let mut big = (xa.cmp_gt(REDUCE_MAX) & xa.is_finite()).move_mask();
+    if big != 0 {
+      let a: [f64; 2] = cast(xa);
+      let mut r: [f64; 2] = cast(x);
+      let mut k: [i64; 2] = cast(q);
+      while big != 0 {
+        let i = big.trailing_zeros() as usize;
+        let (ri, ki) = rem_pio2(a[i]);
+        r[i] = ri;
+        k[i] = ki;
+        big &= big - 1;
+      }
+      x = cast(r);
+      q = cast(k);
+    }
+
+    let (sin1, cos1) = Self::sin_cos_quadrant(x, q);
+    (sin1.flip_signs(self), cos1)
+  }
+
+  /// `sin` and `cos` of `x + q * pi/2`, for a reduced `|x| <= pi/4`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn sin_cos_quadrant(x: Self, q: i64x2) -> (Self, Self) {
     const_f64_as_f64x2!(P0sin, -1.66666666666666307295E-1);
     const_f64_as_f64x2!(P1sin, 8.33333333332211858878E-3);
     const_f64_as_f64x2!(P2sin, -1.98412698295895385996E-4);
@@ -939,37 +1263,18 @@ impl f64x2 {
     const_f64_as_f64x2!(P4cos, 2.08757008419747316778E-9);
     const_f64_as_f64x2!(P5cos, -1.13585365213876817300E-11);
 
-    const_f64_as_f64x2!(DP1, 7.853981554508209228515625E-1 * 2.);
-    const_f64_as_f64x2!(DP2, 7.94662735614792836714E-9 * 2.);
-    const_f64_as_f64x2!(DP3, 3.06161699786838294307E-17 * 2.);
-
-    const_f64_as_f64x2!(TWO_OVER_PI, 2.0 / core::f64::consts::PI);
-
-    let xa = self.abs();
-
-    let y = (xa * TWO_OVER_PI).round();
-    let q = y.round_int();
-
-    let x = y.mul_neg_add(DP3, y.mul_neg_add(DP2, y.mul_neg_add(DP1, xa)));
-
     let x2 = x * x;
-    let mut s = polynomial_5!(x2, P0sin, P1sin, P2sin, P3sin, P4sin, P5sin);
-    let mut c = polynomial_5!(x2, P0cos, P1cos, P2cos, P3cos, P4cos, P5cos);
-    s = (x * x2).mul_add(s, x);
-    c =
+    let s = polynomial_5!(x2, P0sin, P1sin, P2sin, P3sin, P4sin, P5sin);
+    let c = polynomial_5!(x2, P0cos, P1cos, P2cos, P3cos, P4cos, P5cos);
+    let s = (x * x2).mul_add(s, x);
+    let c =
       (x2 * x2).mul_add(c, x2.mul_neg_add(f64x2::from(0.5), f64x2::from(1.0)));
 
     let swap = !((q & i64x2::from(1)).cmp_eq(i64x2::from(0)));
 
-    let mut overflow: f64x2 = cast(q.cmp_gt(i64x2::from(0x80000000000000)));
-    overflow &= xa.is_finite();
-    s = overflow.blend(f64x2::from(0.0), s);
-    c = overflow.blend(f64x2::from(1.0), c);
-
     // calc sin
     let mut sin1 = cast::<_, f64x2>(swap).blend(c, s);
-    let sign_sin: i64x2 = (q << 62) ^ cast::<_, i64x2>(self);
-    sin1 = sin1.flip_signs(cast(sign_sin));
+    sin1 = sin1.flip_signs(cast(q << 62));
 
     // calc cos
     let mut cos1 = cast::<_, f64x2>(swap).blend(s, c);
@@ -996,6 +1301,48 @@ impl f64x2 {
     let (s, c) = self.sin_cos();
     s / c
   }
+
+  /// `sin(pi * x)` and `cos(pi * x)`. The period is a whole number, so the
+  /// reduction is exact however big `x` is, and the integers and
+  /// half-integers give exactly 0 or ±1.
+  #[inline]
+  #[must_use]
+  pub fn sin_cos_pi(self) -> (Self, Self) {
+    // both steps are exact: t is in [-1, 1] and r in [-1/4, 1/4]
+    let t = (self * Self::HALF).round().mul_neg_add(Self::from(2.0), self);
+    let n = (t + t).round();
+    let r = n.mul_neg_add(Self::HALF, t);
+    let (s, c) = Self::sin_cos_quadrant(r * Self::PI, n.round_int());
+    // zeros get the signs IEEE 754 asks for: sin's follows x, cos's is +
+    let s = s.cmp_eq(Self::ZERO).blend(Self::ZERO.copysign(self), s);
+    let c = c.cmp_eq(Self::ZERO).blend(Self::ZERO, c);
+    (s, c)
+  }
+
+  /// `sin(pi * x)`, exactly 0 at the integers.
+  #[inline]
+  #[must_use]
+  pub fn sin_pi(self) -> Self {
+    let (s, _) = self.sin_cos_pi();
+    s
+  }
+
+  /// `cos(pi * x)`, exactly 0 at the half-integers.
+  #[inline]
+  #[must_use]
+  pub fn cos_pi(self) -> Self {
+    let (_, c) = self.sin_cos_pi();
+    c
+  }
+
+  /// `tan(pi * x)`, exactly 0 at the integers and infinite at the
+  /// half-integers.
+  #[inline]
+  #[must_use]
+  pub fn tan_pi(self) -> Self {
+    let (s, c) = self.sin_cos_pi();
+    s / c
+  }
   #[inline]
   #[must_use]
   pub fn to_degrees(self) -> Self {
@@ -1027,6 +1374,64 @@ impl f64x2 {
       }
     }
   }
+
+  /// Cube root. Negative lanes give negative roots.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn cbrt(self) -> Self {
+    const_f64_as_f64x2!(C0, 0.49660828);
+    const_f64_as_f64x2!(C1, 0.68823454);
+    const_f64_as_f64x2!(C2, -0.18528691);
+    const_f64_as_f64x2!(CBRT_2, 1.2599210498948732);
+    const_f64_as_f64x2!(CBRT_4, 1.5874010519681994);
+    const_f64_as_f64x2!(THIRD, 1.0 / 3.0);
+    const_f64_as_f64x2!(THREE, 3.0);
+    // subnormals are scaled up by 2^54 first, which the root undoes as 2^18
+    const_f64_as_f64x2!(SCALE_UP, 18014398509481984.0);
+    const_f64_as_f64x2!(SCALE_DOWN, 1.0 / 262144.0);
+    let a = self.abs();
+    let tiny = a.cmp_lt(Self::from(f64::MIN_POSITIVE));
+    let a = tiny.blend(a * SCALE_UP, a);
+    // a = m * 2^(3q + r) with m in [0.5, 1), so the root is cbrt(m * 2^r) * 2^q
+    let m = Self::fraction_2(a);
+    let e = Self::exponent(a) + Self::ONE;
+    let q = (e / THREE).floor();
+    let r = e - q * THREE;
+    let y = polynomial_2!(m, C0, C1, C2);
+    let y = r.cmp_eq(Self::from(2.0)).blend(y * CBRT_4, y);
+    let y = r.cmp_eq(Self::ONE).blend(y * CBRT_2, y);
+    let m = m * Self::vm_pow2n(r);
+    // the first guess is good to about 10 bits, and each Newton step doubles
+    // that
+    let y = y + (m / (y * y) - y) * THIRD;
+    let y = y + (m / (y * y) - y) * THIRD;
+    let y = y + (m / (y * y) - y) * THIRD;
+    let y = y * Self::vm_pow2n(q);
+    let y = tiny.blend(y * SCALE_DOWN, y).copysign(self);
+    // zeros, infinities and NaNs are their own roots
+    (self.cmp_eq(Self::ZERO) | self.is_inf() | self.is_nan()).blend(self, y)
+  }
+
+  /// `sqrt(self^2 + other^2)`, without overflowing or underflowing along the
+  /// way.
+  #[inline]
+  #[must_use]
+  pub fn hypot(self, other: Self) -> Self {
+    let a = self.abs();
+    let b = other.abs();
+    let big = a.max(b);
+    let small = a.min(b);
+    // scale so that `big` is in [1, 2), then scale the result back
+    let e = Self::exponent(big);
+    let big = big.mul_pow2n(-e);
+    let small = small.mul_pow2n(-e);
+    let y = big.mul_add(big, small * small).sqrt().mul_pow2n(e);
+    let y = (a.is_nan() | b.is_nan()).blend(Self::from(f64::NAN), y);
+    let inf = Self::infinity();
+    (a.cmp_eq(inf) | b.cmp_eq(inf)).blend(inf, y)
+  }
+
   #[inline]
   #[must_use]
   pub fn move_mask(self) -> i32 {
@@ -1066,6 +1471,16 @@ impl f64x2 {
     cast::<_, f64x2>(c)
   }
 
+  /// `self * 2^n` for integer valued `n`, done in two steps so that `n` can
+  /// go past the normal exponent range (the result overflows or goes
+  /// subnormal as it should).
+  #[inline]
+  #[must_use]
+  fn mul_pow2n(self, n: Self) -> Self {
+    let half = (n * Self::HALF).floor();
+    self * Self::vm_pow2n(half) * Self::vm_pow2n(n - half)
+  }
+
   /// Calculate the exponent of a packed f64x2
   #[inline]
   #[must_use]
@@ -1099,6 +1514,100 @@ impl f64x2 {
     in_range.blend(z, Self::ZERO)
   }
 
+  /// `2^x`. Integer lanes give exact powers of two.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn exp2(self) -> Self {
+    const_f64_as_f64x2!(P2, 1.0 / 2.0);
+    const_f64_as_f64x2!(P3, 1.0 / 6.0);
+    const_f64_as_f64x2!(P4, 1.0 / 24.0);
+    const_f64_as_f64x2!(P5, 1.0 / 120.0);
+    const_f64_as_f64x2!(P6, 1.0 / 720.0);
+    const_f64_as_f64x2!(P7, 1.0 / 5040.0);
+    const_f64_as_f64x2!(P8, 1.0 / 40320.0);
+    const_f64_as_f64x2!(P9, 1.0 / 362880.0);
+    const_f64_as_f64x2!(P10, 1.0 / 3628800.0);
+    const_f64_as_f64x2!(P11, 1.0 / 39916800.0);
+    const_f64_as_f64x2!(P12, 1.0 / 479001600.0);
+    const_f64_as_f64x2!(P13, 1.0 / 6227020800.0);
+    const_f64_as_f64x2!(LIMIT, 2000.0);
+    let x = self.max(-LIMIT).min(LIMIT);
+    let r = x.round();
+    let x = (x - r) * Self::LN_2;
+    let z =
+      polynomial_13!(x, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12, P13);
+    let y = (z + Self::ONE).mul_pow2n(r);
+    self.is_nan().blend(self, y)
+  }
+
+  /// `10^x`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn exp10(self) -> Self {
+    const_f64_as_f64x2!(P2, 1.0 / 2.0);
+    const_f64_as_f64x2!(P3, 1.0 / 6.0);
+    const_f64_as_f64x2!(P4, 1.0 / 24.0);
+    const_f64_as_f64x2!(P5, 1.0 / 120.0);
+    const_f64_as_f64x2!(P6, 1.0 / 720.0);
+    const_f64_as_f64x2!(P7, 1.0 / 5040.0);
+    const_f64_as_f64x2!(P8, 1.0 / 40320.0);
+    const_f64_as_f64x2!(P9, 1.0 / 362880.0);
+    const_f64_as_f64x2!(P10, 1.0 / 3628800.0);
+    const_f64_as_f64x2!(P11, 1.0 / 39916800.0);
+    const_f64_as_f64x2!(P12, 1.0 / 479001600.0);
+    const_f64_as_f64x2!(P13, 1.0 / 6227020800.0);
+    const_f64_as_f64x2!(LOG10_2_HI, 0.30102999566383914);
+    const_f64_as_f64x2!(LOG10_2_LO, 1.42050232272661e-13);
+    const_f64_as_f64x2!(LIMIT, 600.0);
+    let x = self.max(-LIMIT).min(LIMIT);
+    let r = (x * Self::LOG2_10).round();
+    let x = r.mul_neg_add(LOG10_2_HI, x);
+    let x = r.mul_neg_add(LOG10_2_LO, x) * Self::LN_10;
+    let z =
+      polynomial_13!(x, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12, P13);
+    let y = (z + Self::ONE).mul_pow2n(r);
+    self.is_nan().blend(self, y)
+  }
+
+  /// `e^x - 1`, accurate even when `x` is close to zero.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn expm1(self) -> Self {
+    const_f64_as_f64x2!(P2, 1.0 / 2.0);
+    const_f64_as_f64x2!(P3, 1.0 / 6.0);
+    const_f64_as_f64x2!(P4, 1.0 / 24.0);
+    const_f64_as_f64x2!(P5, 1.0 / 120.0);
+    const_f64_as_f64x2!(P6, 1.0 / 720.0);
+    const_f64_as_f64x2!(P7, 1.0 / 5040.0);
+    const_f64_as_f64x2!(P8, 1.0 / 40320.0);
+    const_f64_as_f64x2!(P9, 1.0 / 362880.0);
+    const_f64_as_f64x2!(P10, 1.0 / 3628800.0);
+    const_f64_as_f64x2!(P11, 1.0 / 39916800.0);
+    const_f64_as_f64x2!(P12, 1.0 / 479001600.0);
+    const_f64_as_f64x2!(P13, 1.0 / 6227020800.0);
+    const_f64_as_f64x2!(LN2D_HI, 0.693145751953125);
+    const_f64_as_f64x2!(LN2D_LO, 1.42860682030941723212E-6);
+    const_f64_as_f64x2!(MIN_X, -60.0);
+    const_f64_as_f64x2!(MAX_X, 800.0);
+    const_f64_as_f64x2!(BIG, 60.0);
+    let x = self.max(MIN_X).min(MAX_X);
+    let r = (x * Self::LOG2_E).round();
+    let x = r.mul_neg_add(LN2D_HI, x);
+    let x = r.mul_neg_add(LN2D_LO, x);
+    // z = e^x - 1 for the reduced x, so the result is z * 2^r + (2^r - 1)
+    let z =
+      polynomial_13!(x, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12, P13);
+    let n2 = Self::vm_pow2n(r.min(BIG));
+    let small = z.mul_add(n2, n2 - Self::ONE);
+    // past `BIG` the - 1 is lost, and 2^r might not fit in one step
+    let big = (z + Self::ONE).mul_pow2n(r) - Self::ONE;
+    let y = r.cmp_lt(BIG).blend(small, big);
+    (self.cmp_eq(Self::ZERO) | self.is_nan()).blend(self, y)
+  }
+
   #[inline]
   #[allow(non_upper_case_globals)]
   fn exponent(self) -> f64x2 {
@@ -1112,6 +1621,160 @@ impl f64x2 {
     e
   }
 
+  /// Like `exponent`, but also right for subnormals.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn exponent_sub(self) -> Self {
+    const_f64_as_f64x2!(SCALE_UP, 18014398509481984.0);
+    const_f64_as_f64x2!(SCALE_BITS, 54.0);
+    let sub = self.abs().cmp_lt(Self::from(f64::MIN_POSITIVE));
+    sub.blend((self * SCALE_UP).exponent() - SCALE_BITS, self.exponent())
+  }
+
+  /// Splits each lane into a mantissa and an exponent, so that
+  /// `self = mantissa * 2^exponent` with `0.5 <= |mantissa| < 1`. Zero,
+  /// infinity and NaN come back unchanged with an exponent of 0.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn frexp(self) -> (Self, i64x2) {
+    const_f64_as_f64x2!(SCALE_UP, 18014398509481984.0);
+    const_f64_as_f64x2!(SCALE_BITS, 54.0);
+    let sub = self.abs().cmp_lt(Self::from(f64::MIN_POSITIVE));
+    let x = sub.blend(self * SCALE_UP, self).abs();
+    let m = x.fraction_2().copysign(self);
+    let exp = x.exponent() + Self::ONE - (sub & SCALE_BITS);
+    let keep = self.cmp_eq(Self::ZERO) | !self.is_finite();
+    (keep.blend(self, m), keep.blend(Self::ZERO, exp).round_int())
+  }
+
+  /// Multiplies each lane by 2 to the power of the matching lane of `exp`.
+  /// There's only one rounding, so subnormal results are correctly rounded
+  /// too.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn ldexp(self, exp: i64x2) -> Self {
+    // past this every finite nonzero lane overflows or underflows
+    const_f64_as_f64x2!(LIMIT, 2300.0);
+    const_f64_as_f64x2!(UP_BITS, 1023.0);
+    const_f64_as_f64x2!(UP, 8.98846567431158e307);
+    const_f64_as_f64x2!(MIN_EXP, -1022.0);
+    // stepping down by 2^-1022 * 2^53 rather than 2^-1022 keeps
+    // the intermediate normal whenever the result isn't 0
+    const_f64_as_f64x2!(DOWN_BITS, 969.0);
+    const_f64_as_f64x2!(DOWN, 2.004168360008973e-292);
+    let mut n = exp.round_float().max(-LIMIT).min(LIMIT);
+    let mut y = self;
+    for _ in 0..2 {
+      let up = n.cmp_gt(UP_BITS);
+      y = up.blend(y * UP, y);
+      n = up.blend(n - UP_BITS, n);
+      let down = n.cmp_lt(MIN_EXP);
+      y = down.blend(y * DOWN, y);
+      n = down.blend(n + DOWN_BITS, n);
+    }
+    y * n.vm_pow2n()
+  }
+
+  /// Multiplies every lane by `2^n`, rounding only once like
+  /// [`ldexp`](Self::ldexp).
+  #[inline]
+  #[must_use]
+  pub fn scalbn(self, n: i32) -> Self {
+    self.ldexp(i64x2::from(n as i64))
+  }
+
+  /// The exponent of each lane, `floor(log2(|x|))`, as an integer. Zero and
+  /// NaN give `i64::MIN`, and infinity gives `i64::MAX`.
+  #[inline]
+  #[must_use]
+  pub fn ilogb(self) -> i64x2 {
+    let exp = self.abs().exponent_sub().round_int();
+    let exp = cast::<_, i64x2>(self.cmp_eq(Self::ZERO) | self.is_nan())
+      .blend(i64x2::from(i64::MIN), exp);
+    cast::<_, i64x2>(self.is_inf()).blend(i64x2::from(i64::MAX), exp)
+  }
+
+  /// The next representable value above each lane. Zero of either sign
+  /// steps to the smallest subnormal, and `+inf` and NaN are unchanged.
+  #[inline]
+  #[must_use]
+  pub fn next_up(self) -> Self {
+    let bits = cast::<_, i64x2>(self);
+    // stepping the bits away from zero for negatives and towards it otherwise
+    let step = i64x2::from(0).cmp_gt(bits) | i64x2::from(1);
+    let up = cast::<_, Self>(bits + step);
+    let up = self.cmp_eq(Self::ZERO).blend(Self::from(f64::from_bits(1)), up);
+    (self.is_nan() | self.cmp_eq(Self::from(f64::INFINITY))).blend(self, up)
+  }
+
+  /// The next representable value below each lane. Zero of either sign
+  /// steps to the smallest negative subnormal, and `-inf` and NaN are
+  /// unchanged.
+  #[inline]
+  #[must_use]
+  pub fn next_down(self) -> Self {
+    -(-self).next_up()
+  }
+
+  /// The next representable value after each lane in the direction of
+  /// `toward`, like C's `nextafter`. Equal lanes give `toward`, and NaN in
+  /// either gives NaN.
+  #[inline]
+  #[must_use]
+  pub fn next_after(self, toward: Self) -> Self {
+    let r = toward.cmp_gt(self).blend(self.next_up(), self.next_down());
+    let r = self.cmp_eq(toward).blend(toward, r);
+    (self.is_nan() | toward.is_nan()).blend(self + toward, r)
+  }
+
+  /// The unit in the last place of each lane: the gap between `|x|` and the
+  /// next larger value. `MAX` gives the gap below it, infinity gives
+  /// infinity and NaN gives NaN.
+  #[inline]
+  #[must_use]
+  pub fn ulp(self) -> Self {
+    let a = self.abs();
+    let r = a.next_up() - a;
+    let r = a.cmp_eq(Self::from(f64::MAX)).blend(a - a.next_down(), r);
+    self.is_inf().blend(a, r)
+  }
+
+  /// Maps the bits of each lane to an integer that sorts like
+  /// [`f64::total_cmp`].
+  #[inline]
+  #[must_use]
+  fn total_key(self) -> i64x2 {
+    let bits = cast::<_, i64x2>(self);
+    bits ^ (i64x2::from(0).cmp_gt(bits) & i64x2::from(i64::MAX))
+  }
+
+  /// Lanewise `self < rhs` under the IEEE 754 total order used by
+  /// [`f64::total_cmp`], so `-NaN < -inf < -0.0 < 0.0 < inf < NaN`.
+  #[inline]
+  #[must_use]
+  pub fn total_lt(self, rhs: Self) -> Self {
+    cast(rhs.total_key().cmp_gt(self.total_key()))
+  }
+
+  /// The larger of each pair of lanes under the total order of
+  /// [`total_lt`](Self::total_lt).
+  #[inline]
+  #[must_use]
+  pub fn total_max(self, rhs: Self) -> Self {
+    self.total_lt(rhs).blend(rhs, self)
+  }
+
+  /// The smaller of each pair of lanes under the total order of
+  /// [`total_lt`](Self::total_lt).
+  #[inline]
+  #[must_use]
+  pub fn total_min(self, rhs: Self) -> Self {
+    rhs.total_lt(self).blend(rhs, self)
+  }
+
   #[inline]
   #[allow(non_upper_case_globals)]
   fn fraction_2(self) -> Self {
@@ -1176,10 +1839,10 @@ impl f64x2 {
     const_f64_as_f64x2!(Q2, 8.29875266912776603211E1);
     const_f64_as_f64x2!(Q3, 4.52279145837532221105E1);
     const_f64_as_f64x2!(Q4, 1.12873587189167450590E1);
-    const_f64_as_f64x2!(LN2F_HI, 0.693359375);
-    const_f64_as_f64x2!(LN2F_LO, -2.12194440e-4);
+    const_f64_as_f64x2!(LN2D_HI, 0.693145751953125);
+    const_f64_as_f64x2!(LN2D_LO, 1.42860682030941723212E-6);
     const_f64_as_f64x2!(VM_SQRT2, 1.414213562373095048801);
-    const_f64_as_f64x2!(VM_SMALLEST_NORMAL, 1.17549435E-38);
+    const_f64_as_f64x2!(VM_SMALLEST_NORMAL, 2.2250738585072014E-308);
 
     let x1 = self;
     let x = Self::fraction_2(x1);
@@ -1193,9 +1856,9 @@ impl f64x2 {
     let px = x2 * x * px;
     let qx = polynomial_5n!(x, Q0, Q1, Q2, Q3, Q4);
     let res = px / qx;
-    let res = fe.mul_add(LN2F_LO, res);
+    let res = fe.mul_add(LN2D_LO, res);
     let res = res + x2.mul_neg_add(f64x2::HALF, x);
-    let res = fe.mul_add(LN2F_HI, res);
+    let res = fe.mul_add(LN2D_HI, res);
     let overflow = !self.is_finite();
     let underflow = x1.cmp_lt(VM_SMALLEST_NORMAL);
     let mask = overflow | underflow;
@@ -1210,6 +1873,47 @@ impl f64x2 {
     }
   }
 
+  /// `ln(1 + x)`, accurate even when `x` is close to zero.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn ln_1p(self) -> Self {
+    const_f64_as_f64x2!(P0, 7.70838733755885391666E0);
+    const_f64_as_f64x2!(P1, 1.79368678507819816313E1);
+    const_f64_as_f64x2!(P2, 1.44989225341610930846E1);
+    const_f64_as_f64x2!(P3, 4.70579119878881725854E0);
+    const_f64_as_f64x2!(P4, 4.97494994976747001425E-1);
+    const_f64_as_f64x2!(P5, 1.01875663804580931796E-4);
+    const_f64_as_f64x2!(Q0, 2.31251620126765340583E1);
+    const_f64_as_f64x2!(Q1, 7.11544750618563894466E1);
+    const_f64_as_f64x2!(Q2, 8.29875266912776603211E1);
+    const_f64_as_f64x2!(Q3, 4.52279145837532221105E1);
+    const_f64_as_f64x2!(Q4, 1.12873587189167450590E1);
+    const_f64_as_f64x2!(LN2D_HI, 0.693145751953125);
+    const_f64_as_f64x2!(LN2D_LO, 1.42860682030941723212E-6);
+    let x1 = self + Self::ONE;
+    let x = Self::fraction_2(x1);
+    let e = Self::exponent(x1);
+    let mask = x.cmp_gt(Self::SQRT_2 * Self::HALF);
+    let x = (!mask).blend(x + x, x);
+    let fe = mask.blend(e + Self::ONE, e);
+    // with no exponent `x` is just `x1`, and `self` is `x1 - 1` before the
+    // rounding of the add
+    let x = fe.cmp_eq(Self::ZERO).blend(self, x - Self::ONE);
+    let px = polynomial_5!(x, P0, P1, P2, P3, P4, P5);
+    let x2 = x * x;
+    let px = x2 * x * px;
+    let qx = polynomial_5n!(x, Q0, Q1, Q2, Q3, Q4);
+    let res = px / qx;
+    let res = fe.mul_add(LN2D_LO, res);
+    let res = res + x2.mul_neg_add(Self::HALF, x);
+    let res = fe.mul_add(LN2D_HI, res);
+    let res = x1.cmp_eq(Self::ZERO).blend(-Self::infinity(), res);
+    let res = x1.cmp_lt(Self::ZERO).blend(Self::nan_log(), res);
+    let same = self.cmp_eq(Self::ZERO) | self.cmp_eq(Self::infinity());
+    (same | self.is_nan()).blend(self, res)
+  }
+
   #[inline]
   #[must_use]
   pub fn log2(self) -> Self {
@@ -1344,6 +2048,624 @@ impl f64x2 {
   pub fn powf(self, y: f64) -> Self {
     Self::pow_f64x2(self, f64x2::splat(y))
   }
+
+  /// Raises each lane to the power `n` by repeated squaring. For positive `n`
+  /// the result is exact whenever it fits.
+  #[inline]
+  #[must_use]
+  pub fn powi(self, n: i32) -> Self {
+    let mut base = self;
+    let mut k = n.wrapping_abs() as u32;
+    let mut result = Self::ONE;
+    while k != 0 {
+      if k & 1 != 0 {
+        result *= base;
+      }
+      base *= base;
+      k >>= 1;
+    }
+    if n < 0 {
+      Self::ONE / result
+    } else {
+      result
+    }
+  }
+
+  /// `e^x / 2` for `x >= 0`, done so that it only overflows when the result
+  /// does (`exp` gives up a little before `f64::MAX`).
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn exp_half(self) -> Self {
+    const_f64_as_f64x2!(BIG, 700.0);
+    const_f64_as_f64x2!(OVERFLOW, 720.0);
+    let big = self.cmp_gt(BIG);
+    let e = big.blend(self * Self::HALF, self).exp();
+    let r = big.blend((e * Self::HALF) * e, e * Self::HALF);
+    self.cmp_gt(OVERFLOW).blend(Self::infinity(), r)
+  }
+
+  /// Hyperbolic sine.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn sinh(self) -> Self {
+    const_f64_as_f64x2!(P0, -3.51754964808151394800E5);
+    const_f64_as_f64x2!(P1, -1.15614435765005216044E4);
+    const_f64_as_f64x2!(P2, -1.63725857525983828727E2);
+    const_f64_as_f64x2!(P3, -7.89474443963537015605E-1);
+    const_f64_as_f64x2!(Q0, -2.11052978884890840399E6);
+    const_f64_as_f64x2!(Q1, 3.61578279834431989373E4);
+    const_f64_as_f64x2!(Q2, -2.77711081420602794433E2);
+    let x = self.abs();
+    let x2 = x * x;
+    // e^x - e^-x cancels near zero, so small lanes use a polynomial
+    let small = (polynomial_3!(x2, P0, P1, P2, P3)
+      / polynomial_3!(x2, Q0, Q1, Q2, Self::ONE))
+    .mul_add(x2 * x, x);
+    let e = x.exp_half();
+    let big = e - Self::from(0.25) / e;
+    let y = x.cmp_le(Self::ONE).blend(small, big);
+    self.is_nan().blend(self, y.copysign(self))
+  }
+
+  /// Hyperbolic cosine.
+  #[inline]
+  #[must_use]
+  pub fn cosh(self) -> Self {
+    let e = self.abs().exp_half();
+    let y = e + Self::from(0.25) / e;
+    self.is_nan().blend(self, y)
+  }
+
+  /// Hyperbolic tangent.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn tanh(self) -> Self {
+    const_f64_as_f64x2!(P0, -1.61468768441708447952E3);
+    const_f64_as_f64x2!(P1, -9.92877231001918586564E1);
+    const_f64_as_f64x2!(P2, -9.64399179425052238628E-1);
+    const_f64_as_f64x2!(Q0, 4.84406305325125486048E3);
+    const_f64_as_f64x2!(Q1, 2.23548839060100448583E3);
+    const_f64_as_f64x2!(Q2, 1.12811678491632931402E2);
+    const_f64_as_f64x2!(SATURATED, 20.0);
+    let x = self.abs();
+    let x2 = x * x;
+    let small = (polynomial_2!(x2, P0, P1, P2)
+      / polynomial_3!(x2, Q0, Q1, Q2, Self::ONE))
+    .mul_add(x2 * x, x);
+    let e = (x + x).exp();
+    let big = Self::ONE - Self::from(2.0) / (e + Self::ONE);
+    // tanh rounds to 1 long before exp overflows
+    let big = x.cmp_gt(SATURATED).blend(Self::ONE, big);
+    let y = x.cmp_le(Self::from(0.625)).blend(small, big);
+    self.is_nan().blend(self, y.copysign(self))
+  }
+
+  /// Inverse hyperbolic sine.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn asinh(self) -> Self {
+    const_f64_as_f64x2!(P0, -5.56682227230859640450E0);
+    const_f64_as_f64x2!(P1, -9.09030533308377316566E0);
+    const_f64_as_f64x2!(P2, -4.37390226194356683570E0);
+    const_f64_as_f64x2!(P3, -5.91750212056387121207E-1);
+    const_f64_as_f64x2!(P4, -4.33231683752342103572E-3);
+    const_f64_as_f64x2!(Q0, 3.34009336338516356383E1);
+    const_f64_as_f64x2!(Q1, 6.95722521337257608734E1);
+    const_f64_as_f64x2!(Q2, 4.86042483805291788324E1);
+    const_f64_as_f64x2!(Q3, 1.28757002067426453537E1);
+    const_f64_as_f64x2!(HUGE, 1.0E20);
+    let x = self.abs();
+    let x2 = x * x;
+    let small = (polynomial_4!(x2, P0, P1, P2, P3, P4)
+      / polynomial_4!(x2, Q0, Q1, Q2, Q3, Self::ONE))
+    .mul_add(x2 * x, x);
+    // x^2 overflows long before asinh does, and past `HUGE` the +1 is lost
+    // anyway, so huge lanes use ln(2x)
+    let big = x
+      .cmp_gt(HUGE)
+      .blend(x.ln() + Self::LN_2, (x + (x2 + Self::ONE).sqrt()).ln());
+    let y = x.cmp_lt(Self::HALF).blend(small, big);
+    // infinite lanes stay infinite
+    x.is_inf().blend(x, y).copysign(self)
+  }
+
+  /// Inverse hyperbolic cosine. Lanes less than 1 give NaN.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn acosh(self) -> Self {
+    const_f64_as_f64x2!(P0, 1.10855947270161294369E5);
+    const_f64_as_f64x2!(P1, 1.08102874834699867335E5);
+    const_f64_as_f64x2!(P2, 3.43989375926195455866E4);
+    const_f64_as_f64x2!(P3, 3.94726656571334401102E3);
+    const_f64_as_f64x2!(P4, 1.18801130533544501356E2);
+    const_f64_as_f64x2!(Q0, 7.83869920495893927727E4);
+    const_f64_as_f64x2!(Q1, 8.29725251988426222434E4);
+    const_f64_as_f64x2!(Q2, 2.97683430363289370382E4);
+    const_f64_as_f64x2!(Q3, 4.15352677227719831579E3);
+    const_f64_as_f64x2!(Q4, 1.86145380837903397292E2);
+    const_f64_as_f64x2!(HUGE, 1.0E20);
+    let x1 = self - Self::ONE;
+    // near 1 the log form cancels badly, so use sqrt(x - 1) * P(x - 1)
+    let small = x1.sqrt()
+      * (polynomial_4!(x1, P0, P1, P2, P3, P4)
+        / polynomial_5n!(x1, Q0, Q1, Q2, Q3, Q4));
+    let big = self.cmp_gt(HUGE).blend(
+      self.ln() + Self::LN_2,
+      (self + self.mul_sub(self, Self::ONE).sqrt()).ln(),
+    );
+    let y = x1.cmp_lt(Self::HALF).blend(small, big);
+    let y = self.is_inf().blend(self, y);
+    self.cmp_lt(Self::ONE).blend(Self::from(f64::NAN), y)
+  }
+
+  /// Inverse hyperbolic tangent. Lanes outside of `[-1, 1]` give NaN.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn atanh(self) -> Self {
+    const_f64_as_f64x2!(P0, -3.09092539379866942570E1);
+    const_f64_as_f64x2!(P1, 6.54566728676544377376E1);
+    const_f64_as_f64x2!(P2, -4.61252884198732692637E1);
+    const_f64_as_f64x2!(P3, 1.20426861384072379242E1);
+    const_f64_as_f64x2!(P4, -8.54074331929669305196E-1);
+    const_f64_as_f64x2!(Q0, -9.27277618139601130017E1);
+    const_f64_as_f64x2!(Q1, 2.52006675691344555838E2);
+    const_f64_as_f64x2!(Q2, -2.49839401325893582852E2);
+    const_f64_as_f64x2!(Q3, 1.08938092147140262656E2);
+    const_f64_as_f64x2!(Q4, -1.95638849376911654834E1);
+    let x = self.abs();
+    let x2 = x * x;
+    let small = (polynomial_4!(x2, P0, P1, P2, P3, P4)
+      / polynomial_5n!(x2, Q0, Q1, Q2, Q3, Q4))
+    .mul_add(x2 * x, x);
+    let big = Self::HALF * ((Self::ONE + x) / (Self::ONE - x)).ln();
+    let y = x.cmp_lt(Self::HALF).blend(small, big);
+    // the ends of the domain go to infinity
+    let y = x.cmp_eq(Self::ONE).blend(Self::from(f64::INFINITY), y);
+    y.copysign(self)
+  }
+
+  /// `e^(-k * x^2)` for `k` a power of two, without the rounding error that
+  /// squaring `x` would add.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn exp_neg_sq(self, k: Self) -> Self {
+    const_f64_as_f64x2!(SPLIT, 128.0);
+    // `exp` gives 0 a little early, so big lanes are shifted by e^64
+    const_f64_as_f64x2!(BIG, 700.0);
+    const_f64_as_f64x2!(SHIFT, 64.0);
+    const_f64_as_f64x2!(EXP_NEG_SHIFT, 1.603810890548638e-28);
+    // `hi` has few enough bits that `hi * hi` is exact
+    let hi = (self * SPLIT).round() / SPLIT;
+    let lo = self - hi;
+    let u = hi * hi * k;
+    let u1 = (hi + hi).mul_add(lo, lo * lo) * k;
+    let big = u.cmp_gt(BIG);
+    let e = (-u + (big & SHIFT)).exp() * (-u1).exp();
+    big.blend(e * EXP_NEG_SHIFT, e)
+  }
+
+  /// `erf(x)` for `|x| < 1`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn erf_small(self) -> Self {
+    const_f64_as_f64x2!(T0, 5.55923013010394962768E4);
+    const_f64_as_f64x2!(T1, 7.00332514112805075473E3);
+    const_f64_as_f64x2!(T2, 2.23200534594684319226E3);
+    const_f64_as_f64x2!(T3, 9.00260197203842689217E1);
+    const_f64_as_f64x2!(T4, 9.60497373987051638749E0);
+    const_f64_as_f64x2!(U0, 4.92673942608635921086E4);
+    const_f64_as_f64x2!(U1, 2.26290000613890934246E4);
+    const_f64_as_f64x2!(U2, 4.59432382970980127987E3);
+    const_f64_as_f64x2!(U3, 5.21357949780152679795E2);
+    const_f64_as_f64x2!(U4, 3.35617141647503099647E1);
+    let z = self * self;
+    self * polynomial_4!(z, T0, T1, T2, T3, T4)
+      / polynomial_5n!(z, U0, U1, U2, U3, U4)
+  }
+
+  /// `erfc(x)` for `|x| < 1`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn erfc_small(self) -> Self {
+    // `1 - erf(x)` cancels as erfc falls toward 0.16, so from 0.625 up erfc
+    // has its own fit around 0.8125
+    const_f64_as_f64x2!(MID, 0.625);
+    const_f64_as_f64x2!(CENTER, 0.8125);
+    const_f64_as_f64x2!(V0, 0.25053597441363795);
+    const_f64_as_f64x2!(V1, -0.5831131597762818);
+    const_f64_as_f64x2!(V2, 0.47377944231822877);
+    const_f64_as_f64x2!(V3, -0.06225947799669141);
+    const_f64_as_f64x2!(V4, -0.1326335678366114);
+    const_f64_as_f64x2!(V5, 0.06178375288783373);
+    const_f64_as_f64x2!(V6, 0.018635851696038718);
+    const_f64_as_f64x2!(V7, -0.019036591550162987);
+    const_f64_as_f64x2!(V8, -0.00012659082242685887);
+    const_f64_as_f64x2!(V9, 0.003724147770437825);
+    const_f64_as_f64x2!(V10, -0.0005825780370367829);
+    const_f64_as_f64x2!(V11, -0.0005172372895260241);
+    const_f64_as_f64x2!(V12, 0.00015607785591872074);
+    let a = self.abs();
+    let t = a - CENTER;
+    let y =
+      polynomial_12!(t, V0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12);
+    let y = self.cmp_lt(Self::ZERO).blend(Self::from(2.0) - y, y);
+    a.cmp_lt(MID).blend(Self::ONE - self.erf_small(), y)
+  }
+
+  /// `erfc(x)` for `|x| >= 1`, given `e = e^(-x^2)`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn erfc_big(self, e: Self) -> Self {
+    const_f64_as_f64x2!(P0, 5.57535335369399327526E2);
+    const_f64_as_f64x2!(P1, 1.02755188689515710272E3);
+    const_f64_as_f64x2!(P2, 9.34528527171957607540E2);
+    const_f64_as_f64x2!(P3, 5.26445194995477358631E2);
+    const_f64_as_f64x2!(P4, 1.96520832956077098242E2);
+    const_f64_as_f64x2!(P5, 4.86371970985681366614E1);
+    const_f64_as_f64x2!(P6, 7.46321056442269912687E0);
+    const_f64_as_f64x2!(P7, 5.64189564831068821977E-1);
+    const_f64_as_f64x2!(P8, 2.46196981473530512524E-10);
+    const_f64_as_f64x2!(Q0, 5.57535340817727675546E2);
+    const_f64_as_f64x2!(Q1, 1.65666309194161350182E3);
+    const_f64_as_f64x2!(Q2, 2.24633760818710981792E3);
+    const_f64_as_f64x2!(Q3, 1.82390916687909736289E3);
+    const_f64_as_f64x2!(Q4, 9.75708501743205489753E2);
+    const_f64_as_f64x2!(Q5, 3.54937778887819891062E2);
+    const_f64_as_f64x2!(Q6, 8.67072140885989742329E1);
+    const_f64_as_f64x2!(Q7, 1.32281951154744992508E1);
+    const_f64_as_f64x2!(R0, 2.97886665372100240670E0);
+    const_f64_as_f64x2!(R1, 7.40974269950448939160E0);
+    const_f64_as_f64x2!(R2, 6.16021097993053585195E0);
+    const_f64_as_f64x2!(R3, 5.01905042251180477414E0);
+    const_f64_as_f64x2!(R4, 1.27536670759978104416E0);
+    const_f64_as_f64x2!(R5, 5.64189583547755073984E-1);
+    const_f64_as_f64x2!(S0, 3.36907645100081516050E0);
+    const_f64_as_f64x2!(S1, 9.60896809063285878198E0);
+    const_f64_as_f64x2!(S2, 1.70814450747565897222E1);
+    const_f64_as_f64x2!(S3, 1.20489539808096656605E1);
+    const_f64_as_f64x2!(S4, 9.39603524938001434673E0);
+    const_f64_as_f64x2!(S5, 2.26052863220117276590E0);
+    // past this erfc is below the smallest subnormal
+    const_f64_as_f64x2!(LIMIT, 27.3);
+    let a = self.abs();
+    let p = polynomial_8!(a, P0, P1, P2, P3, P4, P5, P6, P7, P8)
+      / polynomial_8!(a, Q0, Q1, Q2, Q3, Q4, Q5, Q6, Q7, Self::ONE);
+    let r = polynomial_5!(a, R0, R1, R2, R3, R4, R5)
+      / polynomial_6n!(a, S0, S1, S2, S3, S4, S5);
+    let y = e * a.cmp_lt(Self::from(8.0)).blend(p, r);
+    let y = a.cmp_gt(LIMIT).blend(Self::ZERO, y);
+    self.cmp_lt(Self::ZERO).blend(Self::from(2.0) - y, y)
+  }
+
+  /// The error function.
+  #[inline]
+  #[must_use]
+  pub fn erf(self) -> Self {
+    let a = self.abs();
+    let big = Self::ONE - a.erfc_big(a.exp_neg_sq(Self::ONE));
+    let y = a.cmp_lt(Self::ONE).blend(self.erf_small(), big.copysign(self));
+    self.is_nan().blend(self, y)
+  }
+
+  /// The complementary error function, `1 - erf(x)`, without losing
+  /// precision for large `x`.
+  #[inline]
+  #[must_use]
+  pub fn erfc(self) -> Self {
+    let a = self.abs();
+    let big = self.erfc_big(a.exp_neg_sq(Self::ONE));
+    let y = a.cmp_lt(Self::ONE).blend(self.erfc_small(), big);
+    self.is_nan().blend(self, y)
+  }
+
+  /// The cumulative distribution function of the standard normal
+  /// distribution.
+  #[inline]
+  #[must_use]
+  pub fn norm_cdf(self) -> Self {
+    let x = -self * Self::FRAC_1_SQRT_2;
+    // the `e^(-x^2)` comes from `self`, so the rounding of `x` doesn't get
+    // blown up in the tails
+    let big = x.erfc_big(self.exp_neg_sq(Self::HALF));
+    let y = x.abs().cmp_lt(Self::ONE).blend(x.erfc_small(), big);
+    self.is_nan().blend(self, y * Self::HALF)
+  }
+
+  /// The inverse of [`norm_cdf`](Self::norm_cdf), also known as the probit
+  /// function. Lanes outside of `[0, 1]` give NaN.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn norm_inv_cdf(self) -> Self {
+    // P. J. Acklam's rational approximations, good to about 1.2e-9
+    const_f64_as_f64x2!(A0, 2.506628277459239e+00);
+    const_f64_as_f64x2!(A1, -3.066479806614716e+01);
+    const_f64_as_f64x2!(A2, 1.383577518672690e+02);
+    const_f64_as_f64x2!(A3, -2.759285104469687e+02);
+    const_f64_as_f64x2!(A4, 2.209460984245205e+02);
+    const_f64_as_f64x2!(A5, -3.969683028665376e+01);
+    const_f64_as_f64x2!(B1, -1.328068155288572e+01);
+    const_f64_as_f64x2!(B2, 6.680131188771972e+01);
+    const_f64_as_f64x2!(B3, -1.556989798598866e+02);
+    const_f64_as_f64x2!(B4, 1.615858368580409e+02);
+    const_f64_as_f64x2!(B5, -5.447609879822406e+01);
+    const_f64_as_f64x2!(C0, 2.938163982698783e+00);
+    const_f64_as_f64x2!(C1, 4.374664141464968e+00);
+    const_f64_as_f64x2!(C2, -2.549732539343734e+00);
+    const_f64_as_f64x2!(C3, -2.400758277161838e+00);
+    const_f64_as_f64x2!(C4, -3.223964580411365e-01);
+    const_f64_as_f64x2!(C5, -7.784894002430293e-03);
+    const_f64_as_f64x2!(D1, 3.754408661907416e+00);
+    const_f64_as_f64x2!(D2, 2.445134137142996e+00);
+    const_f64_as_f64x2!(D3, 3.224671290700398e-01);
+    const_f64_as_f64x2!(D4, 7.784695709041462e-03);
+    const_f64_as_f64x2!(P_LOW, 0.02425);
+    // `ln` doesn't take subnormals, so those are scaled up by 2^54 first
+    const_f64_as_f64x2!(SCALE_UP, 18014398509481984.0);
+    const_f64_as_f64x2!(LN_SCALE, 37.42994775023705);
+    let q = self - Self::HALF;
+    let r = q * q;
+    let central = q * polynomial_5!(r, A0, A1, A2, A3, A4, A5)
+      / polynomial_5!(r, Self::ONE, B1, B2, B3, B4, B5);
+    // the tails work from the distance to the nearer end
+    let p = self.min(Self::ONE - self);
+    let tiny = p.cmp_lt(Self::from(f64::MIN_POSITIVE));
+    let ln_p = tiny.blend(p * SCALE_UP, p).ln() - (tiny & LN_SCALE);
+    let t = (Self::from(-2.0) * ln_p).sqrt();
+    let tail = polynomial_5!(t, C0, C1, C2, C3, C4, C5)
+      / polynomial_4!(t, Self::ONE, D1, D2, D3, D4);
+    let tail = q.cmp_gt(Self::ZERO).blend(-tail, tail);
+    let x = p.cmp_lt(P_LOW).blend(tail, central);
+    // one step of Halley's method brings that to full precision
+    const_f64_as_f64x2!(SQRT_2PI, 2.5066282746310002);
+    let e = x.norm_cdf() - self;
+    let u = e * SQRT_2PI * (x * x * Self::HALF).exp();
+    let x = x - u / (x * u).mul_add(Self::HALF, Self::ONE);
+    let x = self.cmp_eq(Self::ZERO).blend(-Self::infinity(), x);
+    let x = self.cmp_eq(Self::ONE).blend(Self::infinity(), x);
+    let outside = self.cmp_lt(Self::ZERO) | self.cmp_gt(Self::ONE);
+    (outside | self.is_nan()).blend(Self::from(f64::NAN), x)
+  }
+
+  /// The Lanczos sum for `x > 0`, scaled so that
+  /// `gamma(x) = sum * y^(x - 0.5) / e^y` with `y = x + g - 0.5`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn lanczos_sum(self) -> Self {
+    const_f64_as_f64x2!(N0, 23531376880.41076);
+    const_f64_as_f64x2!(N1, 42919803642.6491);
+    const_f64_as_f64x2!(N2, 35711959237.35567);
+    const_f64_as_f64x2!(N3, 17921034426.03721);
+    const_f64_as_f64x2!(N4, 6039542586.352028);
+    const_f64_as_f64x2!(N5, 1439720407.3117216);
+    const_f64_as_f64x2!(N6, 248874557.86205417);
+    const_f64_as_f64x2!(N7, 31426415.585400194);
+    const_f64_as_f64x2!(N8, 2876370.6289353725);
+    const_f64_as_f64x2!(N9, 186056.26539522348);
+    const_f64_as_f64x2!(N10, 8071.672002365816);
+    const_f64_as_f64x2!(N11, 210.82427775157936);
+    const_f64_as_f64x2!(N12, 2.5066282746310002);
+    const_f64_as_f64x2!(D0, 0.0);
+    const_f64_as_f64x2!(D1, 39916800.0);
+    const_f64_as_f64x2!(D2, 120543840.0);
+    const_f64_as_f64x2!(D3, 150917976.0);
+    const_f64_as_f64x2!(D4, 105258076.0);
+    const_f64_as_f64x2!(D5, 45995730.0);
+    const_f64_as_f64x2!(D6, 13339535.0);
+    const_f64_as_f64x2!(D7, 2637558.0);
+    const_f64_as_f64x2!(D8, 357423.0);
+    const_f64_as_f64x2!(D9, 32670.0);
+    const_f64_as_f64x2!(D10, 1925.0);
+    const_f64_as_f64x2!(D11, 66.0);
+    const_f64_as_f64x2!(D12, 1.0);
+    let direct = polynomial_12!(
+      self, N0, N1, N2, N3, N4, N5, N6, N7, N8, N9, N10, N11, N12
+    ) / polynomial_12!(
+      self, D0, D1, D2, D3, D4, D5, D6, D7, D8, D9, D10, D11, D12
+    );
+    // big lanes go through 1 / x so that the powers can't overflow
+    let r = Self::ONE / self;
+    let reversed =
+      polynomial_12!(r, N12, N11, N10, N9, N8, N7, N6, N5, N4, N3, N2, N1, N0)
+        / polynomial_12!(
+          r, D12, D11, D10, D9, D8, D7, D6, D5, D4, D3, D2, D1, D0
+        );
+    self.cmp_lt(Self::from(5.0)).blend(direct, reversed)
+  }
+
+  /// `gamma(x)` for `x > 0` as two factors, each finite whenever the
+  /// product is.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn gamma_parts(self) -> (Self, Self) {
+    const_f64_as_f64x2!(G_MINUS_HALF, 5.524680040776729583740234375);
+    let y = self + G_MINUS_HALF;
+    let h = y.pow_f64x2((self - Self::HALF) * Self::HALF);
+    (self.lanczos_sum() * h, h / y.exp())
+  }
+
+  /// The gamma function. Zero gives infinity with the sign of the zero, and
+  /// the negative integers give NaN. For large `|x|` this is only as accurate
+  /// as [`pow_f64x2`](Self::pow_f64x2).
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn gamma(self) -> Self {
+    // below this gamma(x) rounds to 1 / x
+    const_f64_as_f64x2!(TINY, 2.7755575615628914e-17);
+    const_f64_as_f64x2!(OVERFLOW, 171.7);
+    // past this gamma(-x) is below the smallest subnormal
+    const_f64_as_f64x2!(UNDERFLOW, 190.0);
+    // up to here the factorials are exact and the error is well under 0.5
+    const_f64_as_f64x2!(EXACT, 18.0);
+    let a = self.abs();
+    let (p, q) = a.gamma_parts();
+    // gamma(-a) = -pi / (a * sin(pi * a) * gamma(a))
+    let neg = -Self::PI / (a * a.sin_pi() * p) / q;
+    let neg = a.cmp_gt(UNDERFLOW).blend(Self::ZERO, neg);
+    let pos = p * q;
+    let int = self.cmp_eq(self.round()) & self.cmp_le(EXACT);
+    let pos = int.blend(pos.round(), pos);
+    let y = self.cmp_lt(Self::ZERO).blend(neg, pos);
+    let y = a.cmp_lt(TINY).blend(Self::ONE / self, y);
+    let y = self.cmp_gt(OVERFLOW).blend(Self::infinity(), y);
+    let pole = self.cmp_lt(Self::ZERO) & self.cmp_eq(self.round());
+    (pole | self.is_nan()).blend(Self::from(f64::NAN), y)
+  }
+
+  /// `ln(gamma(2 + t))` for `-0.75 <= t < 0.5`.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  fn ln_gamma_2p(self) -> Self {
+    // ln(gamma(2 + t)) = t * p(t), with `p` fitted around the middle of each
+    // of four pieces of the range
+    const_f64_as_f64x2!(B1, -0.4375);
+    const_f64_as_f64x2!(B2, -0.125);
+    const_f64_as_f64x2!(B3, 0.1875);
+    const_f64_as_f64x2!(M0, -0.59375);
+    const_f64_as_f64x2!(M1, -0.28125);
+    const_f64_as_f64x2!(M2, 0.03125);
+    const_f64_as_f64x2!(M3, 0.34375);
+    const_f64_as_f64x2!(P0, 0.20206582098950845);
+    const_f64_as_f64x2!(P1, 0.43294506449211795);
+    const_f64_as_f64x2!(P2, -0.1291113799111424);
+    const_f64_as_f64x2!(P3, 0.05703231087662347);
+    const_f64_as_f64x2!(P4, -0.02958233839040435);
+    const_f64_as_f64x2!(P5, 0.01668175701388974);
+    const_f64_as_f64x2!(P6, -0.009884306032762712);
+    const_f64_as_f64x2!(P7, 0.006047100184756447);
+    const_f64_as_f64x2!(P8, -0.0037822129725598426);
+    const_f64_as_f64x2!(P9, 0.0024029220014827363);
+    const_f64_as_f64x2!(P10, -0.0015461110986229587);
+    const_f64_as_f64x2!(P11, 0.0010400960851433565);
+    const_f64_as_f64x2!(P12, -0.0006813102105139176);
+    const_f64_as_f64x2!(Q0, 0.3262529721682658);
+    const_f64_as_f64x2!(Q1, 0.36599893937318645);
+    const_f64_as_f64x2!(Q2, -0.0890001378325575);
+    const_f64_as_f64x2!(Q3, 0.03184535671476401);
+    const_f64_as_f64x2!(Q4, -0.01338804915206617);
+    const_f64_as_f64x2!(Q5, 0.006132269527746501);
+    const_f64_as_f64x2!(Q6, -0.0029576558843485193);
+    const_f64_as_f64x2!(Q7, 0.0014754319354878579);
+    const_f64_as_f64x2!(Q8, -0.0007534354486849031);
+    const_f64_as_f64x2!(Q9, 0.00039126533305883264);
+    const_f64_as_f64x2!(Q10, -0.000205857505977643);
+    const_f64_as_f64x2!(Q11, 0.00011197141288525724);
+    const_f64_as_f64x2!(Q12, -6.000719462962658e-05);
+    const_f64_as_f64x2!(R0, 0.4327962772795399);
+    const_f64_as_f64x2!(R1, 0.31831692196197486);
+    const_f64_as_f64x2!(R2, -0.06546525961162587);
+    const_f64_as_f64x2!(R3, 0.019685130837190912);
+    const_f64_as_f64x2!(R4, -0.006950850917150425);
+    const_f64_as_f64x2!(R5, 0.0026769514327511044);
+    const_f64_as_f64x2!(R6, -0.001087119356670677);
+    const_f64_as_f64x2!(R7, 0.00045721923009400274);
+    const_f64_as_f64x2!(R8, -0.00019705772136424375);
+    const_f64_as_f64x2!(R9, 8.645024326765174e-05);
+    const_f64_as_f64x2!(R10, -3.844494075790673e-05);
+    const_f64_as_f64x2!(R11, 1.7565672547473098e-05);
+    const_f64_as_f64x2!(R12, -7.962394536395654e-06);
+    const_f64_as_f64x2!(S0, 0.5264187604539451);
+    const_f64_as_f64x2!(S1, 0.28243056134224703);
+    const_f64_as_f64x2!(S2, -0.050397538679421025);
+    const_f64_as_f64x2!(S3, 0.013072617351848923);
+    const_f64_as_f64x2!(S4, -0.003977447964114919);
+    const_f64_as_f64x2!(S5, 0.0013204776663996642);
+    const_f64_as_f64x2!(S6, -0.0004626725113592041);
+    const_f64_as_f64x2!(S7, 0.00016804846052168436);
+    const_f64_as_f64x2!(S8, -6.260167082392621e-05);
+    const_f64_as_f64x2!(S9, 2.3755828980648668e-05);
+    const_f64_as_f64x2!(S10, -9.14291922930443e-06);
+    const_f64_as_f64x2!(S11, 3.6019748079236816e-06);
+    const_f64_as_f64x2!(S12, -1.4141114883647151e-06);
+    let in_1 = self.cmp_ge(B1);
+    let in_2 = self.cmp_ge(B2);
+    let in_3 = self.cmp_ge(B3);
+    let pick = |p: Self, q: Self, r: Self, s: Self| {
+      in_3.blend(s, in_2.blend(r, in_1.blend(q, p)))
+    };
+    let u = self - pick(M0, M1, M2, M3);
+    self
+      * polynomial_12!(
+        u,
+        pick(P0, Q0, R0, S0),
+        pick(P1, Q1, R1, S1),
+        pick(P2, Q2, R2, S2),
+        pick(P3, Q3, R3, S3),
+        pick(P4, Q4, R4, S4),
+        pick(P5, Q5, R5, S5),
+        pick(P6, Q6, R6, S6),
+        pick(P7, Q7, R7, S7),
+        pick(P8, Q8, R8, S8),
+        pick(P9, Q9, R9, S9),
+        pick(P10, Q10, R10, S10),
+        pick(P11, Q11, R11, S11),
+        pick(P12, Q12, R12, S12)
+      )
+  }
+
+  /// The natural log of the absolute value of the gamma function, along
+  /// with the sign of the gamma function as `1.0` or `-1.0`. The poles give
+  /// infinity.
+  ///
+  /// Around the zeros at negative `x`, the first of which is near `-2.457`,
+  /// the terms of the reflection formula cancel, so there the result is only
+  /// accurate in absolute terms and not relative to its size.
+  #[inline]
+  #[must_use]
+  #[allow(non_upper_case_globals)]
+  pub fn ln_gamma(self) -> (Self, Self) {
+    const_f64_as_f64x2!(G, 6.024680040776729583740234375);
+    const_f64_as_f64x2!(G_MINUS_HALF, 5.524680040776729583740234375);
+    const_f64_as_f64x2!(TINY, 2.7755575615628914e-17);
+    const_f64_as_f64x2!(LN_PI, 1.1447298858494002);
+    // `ln` doesn't take subnormals, so those are scaled up by 2^54 first
+    const_f64_as_f64x2!(SCALE_UP, 18014398509481984.0);
+    const_f64_as_f64x2!(LN_SCALE, 37.42994775023705);
+    let a = self.abs();
+    let sub = a.cmp_lt(Self::from(f64::MIN_POSITIVE));
+    let ln_a = sub.blend(a * SCALE_UP, a).ln() - (sub & LN_SCALE);
+    let y = a + G_MINUS_HALF;
+    let pos =
+      (a - Self::HALF).mul_add(y.ln() - Self::ONE, a.lanczos_sum().ln() - G);
+    let pos = a.cmp_lt(TINY).blend(-ln_a, pos);
+    // from 0.25 to 3.5 the Lanczos form loses its relative accuracy around
+    // the zeros at 1 and 2, so there gamma(x + 1) = x * gamma(x) moves the
+    // lane to ln(gamma(2 + t)), which has its own fit
+    let n = a
+      .cmp_lt(Self::from(1.25))
+      .blend(-Self::ONE, a.cmp_ge(Self::from(2.5)) & Self::ONE);
+    let t = a - (n + Self::from(2.0));
+    let z = n.cmp_lt(Self::ZERO).blend(t, a - Self::from(2.0));
+    let mid = n.mul_add(z.ln_1p(), t.ln_gamma_2p());
+    let in_mid = a.cmp_ge(Self::from(0.25)) & a.cmp_lt(Self::from(3.5));
+    let pos = in_mid.blend(mid, pos);
+    let s = a.sin_pi();
+    // ln|gamma(-a)| = ln(pi) - ln|sin(pi * a)| - ln(a) - ln(gamma(a))
+    let neg = LN_PI - s.abs().ln() - ln_a - pos;
+    let neg = a.cmp_lt(TINY).blend(pos, neg);
+    let y = self.cmp_lt(Self::ZERO).blend(neg, pos);
+    let pole = self.cmp_le(Self::ZERO) & self.cmp_eq(self.round());
+    let y = pole.blend(Self::infinity(), y);
+    let y = self.is_nan().blend(self, y);
+    let sign = (self.cmp_lt(Self::ZERO) & s.cmp_gt(Self::ZERO))
+      .blend(-Self::ONE, Self::ONE);
+    let sign = self.cmp_eq(Self::ZERO).blend(Self::ONE.copysign(self), sign);
+    (y, sign)
+  }
 }
 
 impl Not for f64x2 {
@@ -1355,8 +2677,8 @@ impl Not for f64x2 {
       } else {
 
         Self { arr: [
-          (self.arr[0].to_bits() ^ u64::MAX) as f64,
-          (self.arr[1].to_bits() ^ u64::MAX) as f64,
+          f64::from_bits(self.arr[0].to_bits() ^ u64::MAX),
+          f64::from_bits(self.arr[1].to_bits() ^ u64::MAX),
         ]}
       }
     }
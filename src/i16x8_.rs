@@ -87,6 +87,74 @@ impl Mul for i16x8 {
   }
 }
 
+impl Div for i16x8 {
+  type Output = Self;
+  /// Divides lane by lane, which is done one lane at a time because there's no
+  /// SIMD integer divide. Panics if any lane of `rhs` is 0.
+  ///
+  /// `i16::MIN / -1` wraps around to `i16::MIN`.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: Self) -> Self::Output {
+    let arr: [i16; 8] = cast(self);
+    let rhs: [i16; 8] = cast(rhs);
+    cast([
+      arr[0].wrapping_div(rhs[0]),
+      arr[1].wrapping_div(rhs[1]),
+      arr[2].wrapping_div(rhs[2]),
+      arr[3].wrapping_div(rhs[3]),
+      arr[4].wrapping_div(rhs[4]),
+      arr[5].wrapping_div(rhs[5]),
+      arr[6].wrapping_div(rhs[6]),
+      arr[7].wrapping_div(rhs[7]),
+    ])
+  }
+}
+
+impl Rem for i16x8 {
+  type Output = Self;
+  /// Takes the remainder lane by lane. Panics if any lane of `rhs` is 0.
+  ///
+  /// `i16::MIN % -1` is 0.
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: Self) -> Self::Output {
+    let arr: [i16; 8] = cast(self);
+    let rhs: [i16; 8] = cast(rhs);
+    cast([
+      arr[0].wrapping_rem(rhs[0]),
+      arr[1].wrapping_rem(rhs[1]),
+      arr[2].wrapping_rem(rhs[2]),
+      arr[3].wrapping_rem(rhs[3]),
+      arr[4].wrapping_rem(rhs[4]),
+      arr[5].wrapping_rem(rhs[5]),
+      arr[6].wrapping_rem(rhs[6]),
+      arr[7].wrapping_rem(rhs[7]),
+    ])
+  }
+}
+
+impl Div<i16> for i16x8 {
+  type Output = Self;
+  /// Divides every lane by `rhs` through a [`Divisor`]. If you divide by the
+  /// same value more than once, make the `Divisor` yourself and reuse it.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: i16) -> Self::Output {
+    self / Divisor::<Self>::new(rhs)
+  }
+}
+
+impl Rem<i16> for i16x8 {
+  type Output = Self;
+  /// Takes the remainder of every lane by `rhs` through a [`Divisor`].
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: i16) -> Self::Output {
+    self % Divisor::<Self>::new(rhs)
+  }
+}
+
 impl BitAnd for i16x8 {
   type Output = Self;
   #[inline]
@@ -296,6 +364,41 @@ impl CmpLt for i16x8 {
 }
 
 impl i16x8 {
+  #[inline]
+  #[must_use]
+  pub fn move_mask(self) -> i32 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        move_mask_i8_m128i(pack_i16_to_i8_m128i(self.sse, zeroed_m128i()))
+      }
+      else {
+        ((self.arr[0] < 0) as i32) << 0 |
+        ((self.arr[1] < 0) as i32) << 1 |
+        ((self.arr[2] < 0) as i32) << 2 |
+        ((self.arr[3] < 0) as i32) << 3 |
+        ((self.arr[4] < 0) as i32) << 4 |
+        ((self.arr[5] < 0) as i32) << 5 |
+        ((self.arr[6] < 0) as i32) << 6 |
+        ((self.arr[7] < 0) as i32) << 7
+      }
+    }
+  }
+  #[inline]
+  #[must_use]
+  pub fn any(self) -> bool {
+    self.move_mask() != 0
+  }
+  #[inline]
+  #[must_use]
+  pub fn all(self) -> bool {
+    // eight lanes
+    self.move_mask() == 0b11111111
+  }
+  #[inline]
+  #[must_use]
+  pub fn none(self) -> bool {
+    !self.any()
+  }
   #[inline]
   #[must_use]
   pub fn blend(self, t: Self, f: Self) -> Self {
@@ -350,4 +453,245 @@ impl i16x8 {
       }
     }
   }
+  /// Clamps each lane to `[min, max]`.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    self.max(min).min(max)
+  }
+  /// The smallest lane.
+  #[inline]
+  #[must_use]
+  pub fn reduce_min(self) -> i16 {
+    // flipping the sign bit turns signed order into unsigned order
+    let flipped: u16x8 = cast(self ^ i16x8::from(i16::MIN));
+    flipped.reduce_min() as i16 ^ i16::MIN
+  }
+  /// The largest lane.
+  #[inline]
+  #[must_use]
+  pub fn reduce_max(self) -> i16 {
+    // and flipping the rest as well reverses it
+    let flipped: u16x8 = cast(self ^ i16x8::from(i16::MAX));
+    flipped.reduce_min() as i16 ^ i16::MAX
+  }
+  /// Shifts each lane left by the count in the same lane of `counts`.
+  ///
+  /// Counts of 16 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shl_each(self, counts: u16x8) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        let counts: Self = cast(counts);
+        // shift by each bit of the count in turn, then zero the lanes with
+        // counts that are too large
+        let mut x = self;
+        for i in 0..4 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step as i16);
+          x = (counts & bit).cmp_eq(bit).blend(x << step, x);
+        }
+        x & (counts & Self::splat(!15)).cmp_eq(Self::default())
+      } else {
+        let arr: [i16; 8] = cast(self);
+        let counts: [u16; 8] = cast(counts);
+        cast([
+          if counts[0] < 16 { arr[0] << counts[0] } else { 0 },
+          if counts[1] < 16 { arr[1] << counts[1] } else { 0 },
+          if counts[2] < 16 { arr[2] << counts[2] } else { 0 },
+          if counts[3] < 16 { arr[3] << counts[3] } else { 0 },
+          if counts[4] < 16 { arr[4] << counts[4] } else { 0 },
+          if counts[5] < 16 { arr[5] << counts[5] } else { 0 },
+          if counts[6] < 16 { arr[6] << counts[6] } else { 0 },
+          if counts[7] < 16 { arr[7] << counts[7] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Shifts each lane right by the count in the same lane of `counts`.
+  ///
+  /// Counts of 16 or more give a full sign fill.
+  #[inline]
+  #[must_use]
+  pub fn shr_each(self, counts: u16x8) -> Self {
+    // flip negative lanes so that a logical shift does a sign fill
+    let sign = Self::default().cmp_gt(self);
+    let x: u16x8 = cast(self ^ sign);
+    cast::<u16x8, Self>(x.shr_each(counts)) ^ sign
+  }
+  /// Adds each lane, clamping to the range of `i16` instead of wrapping.
+  #[inline]
+  #[must_use]
+  pub fn saturating_add(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: add_saturating_i16_m128i(self.sse, rhs.sse) }
+      } else {
+        let arr: [i16; 8] = cast(self);
+        let rhs: [i16; 8] = cast(rhs);
+        cast([
+          arr[0].saturating_add(rhs[0]),
+          arr[1].saturating_add(rhs[1]),
+          arr[2].saturating_add(rhs[2]),
+          arr[3].saturating_add(rhs[3]),
+          arr[4].saturating_add(rhs[4]),
+          arr[5].saturating_add(rhs[5]),
+          arr[6].saturating_add(rhs[6]),
+          arr[7].saturating_add(rhs[7]),
+        ])
+      }
+    }
+  }
+  /// Subtracts each lane, clamping to the range of `i16` instead of wrapping.
+  #[inline]
+  #[must_use]
+  pub fn saturating_sub(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: sub_saturating_i16_m128i(self.sse, rhs.sse) }
+      } else {
+        let arr: [i16; 8] = cast(self);
+        let rhs: [i16; 8] = cast(rhs);
+        cast([
+          arr[0].saturating_sub(rhs[0]),
+          arr[1].saturating_sub(rhs[1]),
+          arr[2].saturating_sub(rhs[2]),
+          arr[3].saturating_sub(rhs[3]),
+          arr[4].saturating_sub(rhs[4]),
+          arr[5].saturating_sub(rhs[5]),
+          arr[6].saturating_sub(rhs[6]),
+          arr[7].saturating_sub(rhs[7]),
+        ])
+      }
+    }
+  }
+  /// Multiplies each lane, clamping to the range of `i16` instead of wrapping.
+  #[inline]
+  #[must_use]
+  pub fn saturating_mul(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // widen the products to i32 and pack them back down with saturation
+        let lo = mul_i16_keep_low_m128i(self.sse, rhs.sse);
+        let hi = mul_i16_keep_high_m128i(self.sse, rhs.sse);
+        Self {
+          sse: pack_i32_to_i16_m128i(
+            unpack_low_i16_m128i(lo, hi),
+            unpack_high_i16_m128i(lo, hi),
+          ),
+        }
+      } else {
+        let arr: [i16; 8] = cast(self);
+        let rhs: [i16; 8] = cast(rhs);
+        cast([
+          arr[0].saturating_mul(rhs[0]),
+          arr[1].saturating_mul(rhs[1]),
+          arr[2].saturating_mul(rhs[2]),
+          arr[3].saturating_mul(rhs[3]),
+          arr[4].saturating_mul(rhs[4]),
+          arr[5].saturating_mul(rhs[5]),
+          arr[6].saturating_mul(rhs[6]),
+          arr[7].saturating_mul(rhs[7]),
+        ])
+      }
+    }
+  }
+  /// Sign extends the low 4 lanes to `i32`.
+  #[inline]
+  #[must_use]
+  pub fn widen_low(self) -> i32x4 {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        cast(convert_to_i32_m128i_from_lower4_i16_m128i(self.sse))
+      } else if #[cfg(target_feature="sse2")] {
+        cast(shr_imm_i32_m128i!(unpack_low_i16_m128i(self.sse, self.sse), 16))
+      } else {
+        let a: [i16; 8] = cast(self);
+        let mut out = [0_i32; 4];
+        for (o, &x) in out.iter_mut().zip(a[..4].iter()) {
+          *o = i32::from(x);
+        }
+        i32x4::from(out)
+      }
+    }
+  }
+  /// Sign extends the high 4 lanes to `i32`.
+  #[inline]
+  #[must_use]
+  pub fn widen_high(self) -> i32x4 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        cast(shr_imm_i32_m128i!(unpack_high_i16_m128i(self.sse, self.sse), 16))
+      } else {
+        let a: [i16; 8] = cast(self);
+        let mut out = [0_i32; 4];
+        for (o, &x) in out.iter_mut().zip(a[4..].iter()) {
+          *o = i32::from(x);
+        }
+        i32x4::from(out)
+      }
+    }
+  }
+  /// Sign extends every lane to `i32`.
+  #[inline]
+  #[must_use]
+  pub fn widen(self) -> i32x8 {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        cast(convert_to_i32_m256i_from_i16_m128i(self.sse))
+      } else {
+        i32x8::from_halves(self.widen_low(), self.widen_high())
+      }
+    }
+  }
+  /// Narrows to `i8` by keeping the low bits, with the lanes of `self`
+  /// in the low half of the output and the lanes of `hi` in the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_wrapping(self, hi: Self) -> i8x16 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // the mask keeps each lane in range, so the pack can't saturate it
+        let mask = set_splat_i16_m128i(0xFF);
+        cast(pack_i16_to_u8_m128i(
+          bitand_m128i(self.sse, mask),
+          bitand_m128i(hi.sse, mask),
+        ))
+      } else {
+        // little endian, so the low bits of each lane are the even narrow lanes
+        cast::<_, i8x16>(self).unzip_even(cast(hi))
+      }
+    }
+  }
+  /// Narrows to `i8`, saturating lanes that are out of range, with the
+  /// lanes of `self` in the low half of the output and the lanes of `hi` in
+  /// the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating(self, hi: Self) -> i8x16 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        cast(pack_i16_to_i8_m128i(self.sse, hi.sse))
+      } else {
+        let (min, max) = (Self::splat(i8::MIN.into()), Self::splat(127));
+        self.clamp(min, max).narrow_wrapping(hi.clamp(min, max))
+      }
+    }
+  }
+  /// Narrows to `u8`, saturating lanes that are out of range so negative
+  /// lanes become 0, with the lanes of `self` in the low half of the output
+  /// and the lanes of `hi` in the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating_unsigned(self, hi: Self) -> u8x16 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        cast(pack_i16_to_u8_m128i(self.sse, hi.sse))
+      } else {
+        let (min, max) = (Self::splat(0), Self::splat(255));
+        cast(self.clamp(min, max).narrow_wrapping(hi.clamp(min, max)))
+      }
+    }
+  }
 }
@@ -104,6 +104,66 @@ impl Mul<i32> for i32x4 {
   }
 }
 
+impl Div for i32x4 {
+  type Output = Self;
+  /// Divides lane by lane, which is done one lane at a time because there's no
+  /// SIMD integer divide. Panics if any lane of `rhs` is 0.
+  ///
+  /// `i32::MIN / -1` wraps around to `i32::MIN`.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: Self) -> Self::Output {
+    let arr: [i32; 4] = cast(self);
+    let rhs: [i32; 4] = cast(rhs);
+    cast([
+      arr[0].wrapping_div(rhs[0]),
+      arr[1].wrapping_div(rhs[1]),
+      arr[2].wrapping_div(rhs[2]),
+      arr[3].wrapping_div(rhs[3]),
+    ])
+  }
+}
+
+impl Rem for i32x4 {
+  type Output = Self;
+  /// Takes the remainder lane by lane. Panics if any lane of `rhs` is 0.
+  ///
+  /// `i32::MIN % -1` is 0.
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: Self) -> Self::Output {
+    let arr: [i32; 4] = cast(self);
+    let rhs: [i32; 4] = cast(rhs);
+    cast([
+      arr[0].wrapping_rem(rhs[0]),
+      arr[1].wrapping_rem(rhs[1]),
+      arr[2].wrapping_rem(rhs[2]),
+      arr[3].wrapping_rem(rhs[3]),
+    ])
+  }
+}
+
+impl Div<i32> for i32x4 {
+  type Output = Self;
+  /// Divides every lane by `rhs` through a [`Divisor`]. If you divide by the
+  /// same value more than once, make the `Divisor` yourself and reuse it.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: i32) -> Self::Output {
+    self / Divisor::<Self>::new(rhs)
+  }
+}
+
+impl Rem<i32> for i32x4 {
+  type Output = Self;
+  /// Takes the remainder of every lane by `rhs` through a [`Divisor`].
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: i32) -> Self::Output {
+    self % Divisor::<Self>::new(rhs)
+  }
+}
+
 impl BitAnd for i32x4 {
   type Output = Self;
   #[inline]
@@ -292,6 +352,14 @@ impl i32x4 {
       }
     }
   }
+
+  /// Transposes a 4x4 matrix held as four rows, so lane `j` of row `i` in the
+  /// output is lane `i` of row `j` in the input.
+  #[inline]
+  #[must_use]
+  pub fn transpose4(data: [Self; 4]) -> [Self; 4] {
+    cast(f32x4::transpose4(cast(data)))
+  }
   #[inline]
   #[must_use]
   pub fn abs(self) -> Self {
@@ -331,6 +399,12 @@ impl i32x4 {
       }
     }
   }
+  /// Clamps each lane to `[min, max]`.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    self.max(min).min(max)
+  }
   #[inline]
   #[must_use]
   pub fn round_float(self) -> f32x4 {
@@ -354,7 +428,7 @@ impl i32x4 {
   pub fn move_mask(self) -> i32 {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        move_mask_i8_m128i(self.sse)
+        move_mask_m128(cast(self.sse))
       }
       else {
         ((self.arr[0] < 0) as i32) << 0 |
@@ -372,7 +446,7 @@ impl i32x4 {
   #[inline]
   #[must_use]
   pub fn all(self) -> bool {
-    // eight lanes
+    // four lanes
     self.move_mask() == 0b1111
   }
   #[inline]
@@ -380,4 +454,153 @@ impl i32x4 {
   pub fn none(self) -> bool {
     !self.any()
   }
+  /// Shifts each lane left by the count in the same lane of `counts`.
+  ///
+  /// Counts of 32 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shl_each(self, counts: u32x4) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { sse: shl_each_u32_m128i(self.sse, cast(counts)) }
+      } else if #[cfg(target_feature="sse2")] {
+        let counts: Self = cast(counts);
+        // shift by each bit of the count in turn, then zero the lanes with
+        // counts that are too large
+        let mut x = self;
+        for i in 0..5 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step as i32);
+          x = (counts & bit).cmp_eq(bit).blend(x << step, x);
+        }
+        x & (counts & Self::splat(!31)).cmp_eq(Self::default())
+      } else {
+        let arr: [i32; 4] = cast(self);
+        let counts: [u32; 4] = cast(counts);
+        cast([
+          if counts[0] < 32 { arr[0] << counts[0] } else { 0 },
+          if counts[1] < 32 { arr[1] << counts[1] } else { 0 },
+          if counts[2] < 32 { arr[2] << counts[2] } else { 0 },
+          if counts[3] < 32 { arr[3] << counts[3] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Shifts each lane right by the count in the same lane of `counts`.
+  ///
+  /// Counts of 32 or more give a full sign fill.
+  #[inline]
+  #[must_use]
+  pub fn shr_each(self, counts: u32x4) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { sse: shr_each_i32_m128i(self.sse, cast(counts)) }
+      } else {
+        // flip negative lanes so that a logical shift does a sign fill
+        let sign = Self::default().cmp_gt(self);
+        let x: u32x4 = cast(self ^ sign);
+        cast::<u32x4, Self>(x.shr_each(counts)) ^ sign
+      }
+    }
+  }
+  /// Sign extends the low 2 lanes to `i64`.
+  #[inline]
+  #[must_use]
+  pub fn widen_low(self) -> i64x2 {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        cast(convert_to_i64_m128i_from_lower2_i32_m128i(self.sse))
+      } else if #[cfg(target_feature="sse2")] {
+        let sign = cmp_gt_mask_i32_m128i(zeroed_m128i(), self.sse);
+        cast(unpack_low_i32_m128i(self.sse, sign))
+      } else {
+        let a: [i32; 4] = cast(self);
+        let mut out = [0_i64; 2];
+        for (o, &x) in out.iter_mut().zip(a[..2].iter()) {
+          *o = i64::from(x);
+        }
+        i64x2::from(out)
+      }
+    }
+  }
+  /// Sign extends the high 2 lanes to `i64`.
+  #[inline]
+  #[must_use]
+  pub fn widen_high(self) -> i64x2 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        let sign = cmp_gt_mask_i32_m128i(zeroed_m128i(), self.sse);
+        cast(unpack_high_i32_m128i(self.sse, sign))
+      } else {
+        let a: [i32; 4] = cast(self);
+        let mut out = [0_i64; 2];
+        for (o, &x) in out.iter_mut().zip(a[2..].iter()) {
+          *o = i64::from(x);
+        }
+        i64x2::from(out)
+      }
+    }
+  }
+  /// Sign extends every lane to `i64`.
+  #[inline]
+  #[must_use]
+  pub fn widen(self) -> i64x4 {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        cast(convert_to_i64_m256i_from_i32_m128i(self.sse))
+      } else {
+        i64x4::from_halves(self.widen_low(), self.widen_high())
+      }
+    }
+  }
+  /// Narrows to `i16` by keeping the low bits, with the lanes of `self`
+  /// in the low half of the output and the lanes of `hi` in the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_wrapping(self, hi: Self) -> i16x8 {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        // the mask keeps each lane in range, so the pack can't saturate it
+        let mask = set_splat_i32_m128i(0xFFFF);
+        cast(pack_i32_to_u16_m128i(
+          bitand_m128i(self.sse, mask),
+          bitand_m128i(hi.sse, mask),
+        ))
+      } else {
+        // little endian, so the low bits of each lane are the even narrow lanes
+        cast::<_, i16x8>(self).unzip_even(cast(hi))
+      }
+    }
+  }
+  /// Narrows to `i16`, saturating lanes that are out of range, with the
+  /// lanes of `self` in the low half of the output and the lanes of `hi` in
+  /// the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating(self, hi: Self) -> i16x8 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        cast(pack_i32_to_i16_m128i(self.sse, hi.sse))
+      } else {
+        let min = Self::splat(i16::MIN.into());
+        let max = Self::splat(i16::MAX.into());
+        self.clamp(min, max).narrow_wrapping(hi.clamp(min, max))
+      }
+    }
+  }
+  /// Narrows to `u16`, saturating lanes that are out of range so negative
+  /// lanes become 0, with the lanes of `self` in the low half of the output
+  /// and the lanes of `hi` in the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating_unsigned(self, hi: Self) -> u16x8 {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        cast(pack_i32_to_u16_m128i(self.sse, hi.sse))
+      } else {
+        let (min, max) = (Self::splat(0), Self::splat(u16::MAX.into()));
+        cast(self.clamp(min, max).narrow_wrapping(hi.clamp(min, max)))
+      }
+    }
+  }
 }
@@ -153,6 +153,74 @@ impl Mul<i32x8> for i32 {
   }
 }
 
+impl Div for i32x8 {
+  type Output = Self;
+  /// Divides lane by lane, which is done one lane at a time because there's no
+  /// SIMD integer divide. Panics if any lane of `rhs` is 0.
+  ///
+  /// `i32::MIN / -1` wraps around to `i32::MIN`.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: Self) -> Self::Output {
+    let arr: [i32; 8] = cast(self);
+    let rhs: [i32; 8] = cast(rhs);
+    cast([
+      arr[0].wrapping_div(rhs[0]),
+      arr[1].wrapping_div(rhs[1]),
+      arr[2].wrapping_div(rhs[2]),
+      arr[3].wrapping_div(rhs[3]),
+      arr[4].wrapping_div(rhs[4]),
+      arr[5].wrapping_div(rhs[5]),
+      arr[6].wrapping_div(rhs[6]),
+      arr[7].wrapping_div(rhs[7]),
+    ])
+  }
+}
+
+impl Rem for i32x8 {
+  type Output = Self;
+  /// Takes the remainder lane by lane. Panics if any lane of `rhs` is 0.
+  ///
+  /// `i32::MIN % -1` is 0.
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: Self) -> Self::Output {
+    let arr: [i32; 8] = cast(self);
+    let rhs: [i32; 8] = cast(rhs);
+    cast([
+      arr[0].wrapping_rem(rhs[0]),
+      arr[1].wrapping_rem(rhs[1]),
+      arr[2].wrapping_rem(rhs[2]),
+      arr[3].wrapping_rem(rhs[3]),
+      arr[4].wrapping_rem(rhs[4]),
+      arr[5].wrapping_rem(rhs[5]),
+      arr[6].wrapping_rem(rhs[6]),
+      arr[7].wrapping_rem(rhs[7]),
+    ])
+  }
+}
+
+impl Div<i32> for i32x8 {
+  type Output = Self;
+  /// Divides every lane by `rhs` through a [`Divisor`]. If you divide by the
+  /// same value more than once, make the `Divisor` yourself and reuse it.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: i32) -> Self::Output {
+    self / Divisor::<Self>::new(rhs)
+  }
+}
+
+impl Rem<i32> for i32x8 {
+  type Output = Self;
+  /// Takes the remainder of every lane by `rhs` through a [`Divisor`].
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: i32) -> Self::Output {
+    self % Divisor::<Self>::new(rhs)
+  }
+}
+
 impl BitAnd for i32x8 {
   type Output = Self;
   #[inline]
@@ -393,6 +461,14 @@ impl i32x8 {
       }
     }
   }
+
+  /// Transposes an 8x8 matrix held as eight rows, so lane `j` of row `i` in the
+  /// output is lane `i` of row `j` in the input.
+  #[inline]
+  #[must_use]
+  pub fn transpose8(data: [Self; 8]) -> [Self; 8] {
+    cast(f32x8::transpose8(cast(data)))
+  }
   #[inline]
   #[must_use]
   pub fn abs(self) -> Self {
@@ -442,6 +518,12 @@ impl i32x8 {
       }
     }
   }
+  /// Clamps each lane to `[min, max]`.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    self.max(min).min(max)
+  }
   #[inline]
   #[must_use]
   pub fn round_float(self) -> f32x8 {
@@ -471,9 +553,9 @@ impl i32x8 {
   pub fn move_mask(self) -> i32 {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        move_mask_m256i(self.avx2)
+        move_mask_m256(cast(self.avx2))
       } else if #[cfg(target_feature="sse2")] {
-        move_mask_i8_m128i(self.sse0) << 4 ^ move_mask_i8_m128i(self.sse1)
+        move_mask_m128(cast(self.sse1)) << 4 | move_mask_m128(cast(self.sse0))
       }
       else {
         (((self.arr[0]) < 0) as i32) << 0 |
@@ -503,6 +585,93 @@ impl i32x8 {
   pub fn none(self) -> bool {
     !self.any()
   }
+  /// Shifts each lane left by the count in the same lane of `counts`.
+  ///
+  /// Counts of 32 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shl_each(self, counts: u32x8) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx2: shl_each_u32_m256i(self.avx2, cast(counts)) }
+      } else if #[cfg(target_feature="sse2")] {
+        let counts: Self = cast(counts);
+        // shift by each bit of the count in turn, then zero the lanes with
+        // counts that are too large
+        let mut x = self;
+        for i in 0..5 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step as i32);
+          x = (counts & bit).cmp_eq(bit).blend(x << step, x);
+        }
+        x & (counts & Self::splat(!31)).cmp_eq(Self::default())
+      } else {
+        let arr: [i32; 8] = cast(self);
+        let counts: [u32; 8] = cast(counts);
+        cast([
+          if counts[0] < 32 { arr[0] << counts[0] } else { 0 },
+          if counts[1] < 32 { arr[1] << counts[1] } else { 0 },
+          if counts[2] < 32 { arr[2] << counts[2] } else { 0 },
+          if counts[3] < 32 { arr[3] << counts[3] } else { 0 },
+          if counts[4] < 32 { arr[4] << counts[4] } else { 0 },
+          if counts[5] < 32 { arr[5] << counts[5] } else { 0 },
+          if counts[6] < 32 { arr[6] << counts[6] } else { 0 },
+          if counts[7] < 32 { arr[7] << counts[7] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Shifts each lane right by the count in the same lane of `counts`.
+  ///
+  /// Counts of 32 or more give a full sign fill.
+  #[inline]
+  #[must_use]
+  pub fn shr_each(self, counts: u32x8) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx2: shr_each_i32_m256i(self.avx2, cast(counts)) }
+      } else {
+        // flip negative lanes so that a logical shift does a sign fill
+        let sign = Self::default().cmp_gt(self);
+        let x: u32x8 = cast(self ^ sign);
+        cast::<u32x8, Self>(x.shr_each(counts)) ^ sign
+      }
+    }
+  }
+  /// Sign extends the low 4 lanes to `i64`.
+  #[inline]
+  #[must_use]
+  pub fn widen_low(self) -> i64x4 {
+    self.low().widen()
+  }
+  /// Sign extends the high 4 lanes to `i64`.
+  #[inline]
+  #[must_use]
+  pub fn widen_high(self) -> i64x4 {
+    self.high().widen()
+  }
+  /// Narrows to `i16` by keeping the low bits.
+  #[inline]
+  #[must_use]
+  pub fn narrow_wrapping(self) -> i16x8 {
+    let (lo, hi) = self.split();
+    lo.narrow_wrapping(hi)
+  }
+  /// Narrows to `i16`, saturating lanes that are out of range.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating(self) -> i16x8 {
+    let (lo, hi) = self.split();
+    lo.narrow_saturating(hi)
+  }
+  /// Narrows to `u16`, saturating lanes that are out of range so negative
+  /// lanes become 0.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating_unsigned(self) -> u16x8 {
+    let (lo, hi) = self.split();
+    lo.narrow_saturating_unsigned(hi)
+  }
 }
 
 impl Not for i32x8 {
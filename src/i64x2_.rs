@@ -51,6 +51,92 @@ impl Sub for i64x2 {
   }
 }
 
+impl Mul for i64x2 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: mul_i64_keep_low_m128i(self.sse, rhs.sse) }
+      } else {
+        Self { arr: [
+          self.arr[0].wrapping_mul(rhs.arr[0]),
+          self.arr[1].wrapping_mul(rhs.arr[1]),
+        ]}
+      }
+    }
+  }
+}
+
+impl Mul<i64> for i64x2 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: i64) -> Self::Output {
+    self.mul(Self::splat(rhs))
+  }
+}
+
+impl Mul<i64x2> for i64 {
+  type Output = i64x2;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: i64x2) -> Self::Output {
+    i64x2::splat(self) * rhs
+  }
+}
+
+impl Div for i64x2 {
+  type Output = Self;
+  /// Divides lane by lane, which is done one lane at a time because there's no
+  /// SIMD integer divide. Panics if any lane of `rhs` is 0.
+  ///
+  /// `i64::MIN / -1` wraps around to `i64::MIN`.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: Self) -> Self::Output {
+    let arr: [i64; 2] = cast(self);
+    let rhs: [i64; 2] = cast(rhs);
+    cast([arr[0].wrapping_div(rhs[0]), arr[1].wrapping_div(rhs[1])])
+  }
+}
+
+impl Rem for i64x2 {
+  type Output = Self;
+  /// Takes the remainder lane by lane. Panics if any lane of `rhs` is 0.
+  ///
+  /// `i64::MIN % -1` is 0.
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: Self) -> Self::Output {
+    let arr: [i64; 2] = cast(self);
+    let rhs: [i64; 2] = cast(rhs);
+    cast([arr[0].wrapping_rem(rhs[0]), arr[1].wrapping_rem(rhs[1])])
+  }
+}
+
+impl Div<i64> for i64x2 {
+  type Output = Self;
+  /// Divides every lane by `rhs` through a [`Divisor`]. If you divide by the
+  /// same value more than once, make the `Divisor` yourself and reuse it.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: i64) -> Self::Output {
+    self / Divisor::<Self>::new(rhs)
+  }
+}
+
+impl Rem<i64> for i64x2 {
+  type Output = Self;
+  /// Takes the remainder of every lane by `rhs` through a [`Divisor`].
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: i64) -> Self::Output {
+    self % Divisor::<Self>::new(rhs)
+  }
+}
+
 impl BitAnd for i64x2 {
   type Output = Self;
   #[inline]
@@ -219,6 +305,35 @@ impl CmpLt for i64x2 {
 }
 
 impl i64x2 {
+  #[inline]
+  #[must_use]
+  pub fn move_mask(self) -> i32 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        move_mask_m128d(cast(self.sse))
+      }
+      else {
+        ((self.arr[0] < 0) as i32) << 0 |
+        ((self.arr[1] < 0) as i32) << 1
+      }
+    }
+  }
+  #[inline]
+  #[must_use]
+  pub fn any(self) -> bool {
+    self.move_mask() != 0
+  }
+  #[inline]
+  #[must_use]
+  pub fn all(self) -> bool {
+    // two lanes
+    self.move_mask() == 0b11
+  }
+  #[inline]
+  #[must_use]
+  pub fn none(self) -> bool {
+    !self.any()
+  }
   #[inline]
   #[must_use]
   pub fn blend(self, t: Self, f: Self) -> Self {
@@ -230,6 +345,13 @@ impl i64x2 {
       }
     }
   }
+  /// Clamps each lane to `[min, max]`.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    let x = min.cmp_gt(self).blend(min, self);
+    x.cmp_gt(max).blend(max, x)
+  }
 
   #[inline]
   #[must_use]
@@ -237,4 +359,69 @@ impl i64x2 {
     let arr: [i64; 2] = cast(self);
     cast([arr[0] as f64, arr[1] as f64])
   }
+  /// Shifts each lane left by the count in the same lane of `counts`.
+  ///
+  /// Counts of 64 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shl_each(self, counts: u64x2) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { sse: shl_each_u64_m128i(self.sse, cast(counts)) }
+      } else {
+        let arr: [i64; 2] = cast(self);
+        let counts: [u64; 2] = cast(counts);
+        cast([
+          if counts[0] < 64 { arr[0] << counts[0] } else { 0 },
+          if counts[1] < 64 { arr[1] << counts[1] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Shifts each lane right by the count in the same lane of `counts`.
+  ///
+  /// Counts of 64 or more give a full sign fill.
+  #[inline]
+  #[must_use]
+  pub fn shr_each(self, counts: u64x2) -> Self {
+    // flip negative lanes so that a logical shift does a sign fill
+    let sign = Self::default().cmp_gt(self);
+    let x: u64x2 = cast(self ^ sign);
+    cast::<u64x2, Self>(x.shr_each(counts)) ^ sign
+  }
+  /// Narrows to `i32` by keeping the low bits, with the lanes of `self`
+  /// in the low half of the output and the lanes of `hi` in the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_wrapping(self, hi: Self) -> i32x4 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // little endian, so the low 32 bits of each lane are the even `f32`
+        let (lo, hi): (m128, m128) = (cast(self.sse), cast(hi.sse));
+        cast(shuffle_abi_f32_all_m128!(lo, hi, [a:0, a:2, b:0, b:2]))
+      } else {
+        // little endian, so the low bits of each lane are the even narrow lanes
+        cast::<_, i32x4>(self).unzip_even(cast(hi))
+      }
+    }
+  }
+  /// Narrows to `i32`, saturating lanes that are out of range, with the
+  /// lanes of `self` in the low half of the output and the lanes of `hi` in
+  /// the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating(self, hi: Self) -> i32x4 {
+    let min = Self::splat(i32::MIN.into());
+    let max = Self::splat(i32::MAX.into());
+    self.clamp(min, max).narrow_wrapping(hi.clamp(min, max))
+  }
+  /// Narrows to `u32`, saturating lanes that are out of range so negative
+  /// lanes become 0, with the lanes of `self` in the low half of the output
+  /// and the lanes of `hi` in the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating_unsigned(self, hi: Self) -> u32x4 {
+    let (min, max) = (Self::splat(0), Self::splat(u32::MAX.into()));
+    cast(self.clamp(min, max).narrow_wrapping(hi.clamp(min, max)))
+  }
 }
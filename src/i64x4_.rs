@@ -63,6 +63,106 @@ impl Sub for i64x4 {
   }
 }
 
+impl Mul for i64x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx2: mul_i64_keep_low_m256i(self.avx2, rhs.avx2) }
+      } else if #[cfg(target_feature="sse2")] {
+        Self { sse0: mul_i64_keep_low_m128i(self.sse0, rhs.sse0), sse1: mul_i64_keep_low_m128i(self.sse1, rhs.sse1) }
+      } else {
+        Self { arr: [
+          self.arr[0].wrapping_mul(rhs.arr[0]),
+          self.arr[1].wrapping_mul(rhs.arr[1]),
+          self.arr[2].wrapping_mul(rhs.arr[2]),
+          self.arr[3].wrapping_mul(rhs.arr[3]),
+        ]}
+      }
+    }
+  }
+}
+
+impl Mul<i64> for i64x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: i64) -> Self::Output {
+    self.mul(Self::splat(rhs))
+  }
+}
+
+impl Mul<i64x4> for i64 {
+  type Output = i64x4;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: i64x4) -> Self::Output {
+    i64x4::splat(self) * rhs
+  }
+}
+
+impl Div for i64x4 {
+  type Output = Self;
+  /// Divides lane by lane, which is done one lane at a time because there's no
+  /// SIMD integer divide. Panics if any lane of `rhs` is 0.
+  ///
+  /// `i64::MIN / -1` wraps around to `i64::MIN`.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: Self) -> Self::Output {
+    let arr: [i64; 4] = cast(self);
+    let rhs: [i64; 4] = cast(rhs);
+    cast([
+      arr[0].wrapping_div(rhs[0]),
+      arr[1].wrapping_div(rhs[1]),
+      arr[2].wrapping_div(rhs[2]),
+      arr[3].wrapping_div(rhs[3]),
+    ])
+  }
+}
+
+impl Rem for i64x4 {
+  type Output = Self;
+  /// Takes the remainder lane by lane. Panics if any lane of `rhs` is 0.
+  ///
+  /// `i64::MIN % -1` is 0.
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: Self) -> Self::Output {
+    let arr: [i64; 4] = cast(self);
+    let rhs: [i64; 4] = cast(rhs);
+    cast([
+      arr[0].wrapping_rem(rhs[0]),
+      arr[1].wrapping_rem(rhs[1]),
+      arr[2].wrapping_rem(rhs[2]),
+      arr[3].wrapping_rem(rhs[3]),
+    ])
+  }
+}
+
+impl Div<i64> for i64x4 {
+  type Output = Self;
+  /// Divides every lane by `rhs` through a [`Divisor`]. If you divide by the
+  /// same value more than once, make the `Divisor` yourself and reuse it.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: i64) -> Self::Output {
+    self / Divisor::<Self>::new(rhs)
+  }
+}
+
+impl Rem<i64> for i64x4 {
+  type Output = Self;
+  /// Takes the remainder of every lane by `rhs` through a [`Divisor`].
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: i64) -> Self::Output {
+    self % Divisor::<Self>::new(rhs)
+  }
+}
+
 impl BitAnd for i64x4 {
   type Output = Self;
   #[inline]
@@ -266,6 +366,39 @@ impl CmpLt for i64x4 {
 }
 
 impl i64x4 {
+  #[inline]
+  #[must_use]
+  pub fn move_mask(self) -> i32 {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        move_mask_m256d(cast(self.avx2))
+      } else if #[cfg(target_feature="sse2")] {
+        move_mask_m128d(cast(self.sse1)) << 2 | move_mask_m128d(cast(self.sse0))
+      }
+      else {
+        ((self.arr[0] < 0) as i32) << 0 |
+        ((self.arr[1] < 0) as i32) << 1 |
+        ((self.arr[2] < 0) as i32) << 2 |
+        ((self.arr[3] < 0) as i32) << 3
+      }
+    }
+  }
+  #[inline]
+  #[must_use]
+  pub fn any(self) -> bool {
+    self.move_mask() != 0
+  }
+  #[inline]
+  #[must_use]
+  pub fn all(self) -> bool {
+    // four lanes
+    self.move_mask() == 0b1111
+  }
+  #[inline]
+  #[must_use]
+  pub fn none(self) -> bool {
+    !self.any()
+  }
   #[inline]
   #[must_use]
   pub fn blend(self, t: Self, f: Self) -> Self {
@@ -280,12 +413,81 @@ impl i64x4 {
     }
   }
 
+  /// Transposes a 4x4 matrix held as four rows, so lane `j` of row `i` in the
+  /// output is lane `i` of row `j` in the input.
+  #[inline]
+  #[must_use]
+  pub fn transpose4(data: [Self; 4]) -> [Self; 4] {
+    cast(f64x4::transpose4(cast(data)))
+  }
+  /// Clamps each lane to `[min, max]`.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    let x = min.cmp_gt(self).blend(min, self);
+    x.cmp_gt(max).blend(max, x)
+  }
+
   #[inline]
   #[must_use]
   pub fn round_float(self) -> f64x4 {
     let arr: [i64; 4] = cast(self);
     cast([arr[0] as f64, arr[1] as f64, arr[2] as f64, arr[3] as f64])
   }
+  /// Shifts each lane left by the count in the same lane of `counts`.
+  ///
+  /// Counts of 64 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shl_each(self, counts: u64x4) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx2: shl_each_u64_m256i(self.avx2, cast(counts)) }
+      } else {
+        let arr: [i64; 4] = cast(self);
+        let counts: [u64; 4] = cast(counts);
+        cast([
+          if counts[0] < 64 { arr[0] << counts[0] } else { 0 },
+          if counts[1] < 64 { arr[1] << counts[1] } else { 0 },
+          if counts[2] < 64 { arr[2] << counts[2] } else { 0 },
+          if counts[3] < 64 { arr[3] << counts[3] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Shifts each lane right by the count in the same lane of `counts`.
+  ///
+  /// Counts of 64 or more give a full sign fill.
+  #[inline]
+  #[must_use]
+  pub fn shr_each(self, counts: u64x4) -> Self {
+    // flip negative lanes so that a logical shift does a sign fill
+    let sign = Self::default().cmp_gt(self);
+    let x: u64x4 = cast(self ^ sign);
+    cast::<u64x4, Self>(x.shr_each(counts)) ^ sign
+  }
+  /// Narrows to `i32` by keeping the low bits.
+  #[inline]
+  #[must_use]
+  pub fn narrow_wrapping(self) -> i32x4 {
+    let (lo, hi) = self.split();
+    lo.narrow_wrapping(hi)
+  }
+  /// Narrows to `i32`, saturating lanes that are out of range.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating(self) -> i32x4 {
+    let (lo, hi) = self.split();
+    lo.narrow_saturating(hi)
+  }
+  /// Narrows to `u32`, saturating lanes that are out of range so negative
+  /// lanes become 0.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating_unsigned(self) -> u32x4 {
+    let (lo, hi) = self.split();
+    lo.narrow_saturating_unsigned(hi)
+  }
 }
 
 impl Not for i64x4 {
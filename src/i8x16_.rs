@@ -79,6 +79,56 @@ impl Sub for i8x16 {
   }
 }
 
+impl Mul for i8x16 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: mul_i8_keep_low_m128i(self.sse, rhs.sse) }
+      } else {
+        Self { arr: [
+          self.arr[0].wrapping_mul(rhs.arr[0]),
+          self.arr[1].wrapping_mul(rhs.arr[1]),
+          self.arr[2].wrapping_mul(rhs.arr[2]),
+          self.arr[3].wrapping_mul(rhs.arr[3]),
+          self.arr[4].wrapping_mul(rhs.arr[4]),
+          self.arr[5].wrapping_mul(rhs.arr[5]),
+          self.arr[6].wrapping_mul(rhs.arr[6]),
+          self.arr[7].wrapping_mul(rhs.arr[7]),
+          self.arr[8].wrapping_mul(rhs.arr[8]),
+          self.arr[9].wrapping_mul(rhs.arr[9]),
+          self.arr[10].wrapping_mul(rhs.arr[10]),
+          self.arr[11].wrapping_mul(rhs.arr[11]),
+          self.arr[12].wrapping_mul(rhs.arr[12]),
+          self.arr[13].wrapping_mul(rhs.arr[13]),
+          self.arr[14].wrapping_mul(rhs.arr[14]),
+          self.arr[15].wrapping_mul(rhs.arr[15]),
+        ]}
+      }
+    }
+  }
+}
+
+impl Mul<i8> for i8x16 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: i8) -> Self::Output {
+    self.mul(Self::splat(rhs))
+  }
+}
+
+impl Mul<i8x16> for i8 {
+  type Output = i8x16;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: i8x16) -> Self::Output {
+    i8x16::splat(self) * rhs
+  }
+}
+
 impl BitAnd for i8x16 {
   type Output = Self;
   #[inline]
@@ -175,6 +225,100 @@ impl BitXor for i8x16 {
   }
 }
 
+macro_rules! impl_shl_t_for_i8x16 {
+  ($($shift_type:ty),+ $(,)?) => {
+    $(impl Shl<$shift_type> for i8x16 {
+      type Output = Self;
+      /// Shifts all lanes by the value given.
+      #[inline]
+      #[must_use]
+      fn shl(self, rhs: $shift_type) -> Self::Output {
+        let u = rhs as u64;
+        pick! {
+          if #[cfg(target_feature="sse2")] {
+            // shift as u16 lanes, then clear the bits that crossed a byte
+            let shift = cast([u, 0]);
+            let mask = Self::splat((0xFF_u64 << u.min(8)) as i8);
+            Self { sse: shl_all_u16_m128i(self.sse, shift) } & mask
+          } else {
+            Self { arr: [
+              self.arr[0] << u,
+              self.arr[1] << u,
+              self.arr[2] << u,
+              self.arr[3] << u,
+              self.arr[4] << u,
+              self.arr[5] << u,
+              self.arr[6] << u,
+              self.arr[7] << u,
+              self.arr[8] << u,
+              self.arr[9] << u,
+              self.arr[10] << u,
+              self.arr[11] << u,
+              self.arr[12] << u,
+              self.arr[13] << u,
+              self.arr[14] << u,
+              self.arr[15] << u,
+            ]}
+          }
+        }
+      }
+    })+
+  };
+}
+impl_shl_t_for_i8x16!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+
+/// The shift count for an `i16` lane that holds a byte in its high half, so
+/// that the byte is shifted right by `u` and sign filled.
+#[cfg(target_feature = "sse2")]
+#[inline]
+#[must_use]
+pub(crate) fn i16_count_for_byte_shr(u: u64) -> m128i {
+  cast([u.min(8) + 8, 0])
+}
+
+macro_rules! impl_shr_t_for_i8x16 {
+  ($($shift_type:ty),+ $(,)?) => {
+    $(impl Shr<$shift_type> for i8x16 {
+      type Output = Self;
+      /// Shifts all lanes by the value given.
+      #[inline]
+      #[must_use]
+      fn shr(self, rhs: $shift_type) -> Self::Output {
+        let u = rhs as u64;
+        pick! {
+          if #[cfg(target_feature="sse2")] {
+            // sign extend each byte into an i16 lane, shift, and pack back down
+            let shift = i16_count_for_byte_shr(u);
+            let lo = shr_all_i16_m128i(unpack_low_i8_m128i(self.sse, self.sse), shift);
+            let hi = shr_all_i16_m128i(unpack_high_i8_m128i(self.sse, self.sse), shift);
+            Self { sse: pack_i16_to_i8_m128i(lo, hi) }
+          } else {
+            Self { arr: [
+              self.arr[0] >> u,
+              self.arr[1] >> u,
+              self.arr[2] >> u,
+              self.arr[3] >> u,
+              self.arr[4] >> u,
+              self.arr[5] >> u,
+              self.arr[6] >> u,
+              self.arr[7] >> u,
+              self.arr[8] >> u,
+              self.arr[9] >> u,
+              self.arr[10] >> u,
+              self.arr[11] >> u,
+              self.arr[12] >> u,
+              self.arr[13] >> u,
+              self.arr[14] >> u,
+              self.arr[15] >> u,
+            ]}
+          }
+        }
+      }
+    })+
+  };
+}
+impl_shr_t_for_i8x16!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+
 impl CmpEq for i8x16 {
   type Output = Self;
   #[inline]
@@ -334,6 +478,28 @@ impl i8x16 {
       }
     }
   }
+  /// Clamps each lane to `[min, max]`.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    self.max(min).min(max)
+  }
+  /// The smallest lane.
+  #[inline]
+  #[must_use]
+  pub fn reduce_min(self) -> i8 {
+    // flipping the sign bit turns signed order into unsigned order
+    let flipped: u8x16 = cast(self ^ i8x16::from(i8::MIN));
+    flipped.reduce_min() as i8 ^ i8::MIN
+  }
+  /// The largest lane.
+  #[inline]
+  #[must_use]
+  pub fn reduce_max(self) -> i8 {
+    // and flipping the rest as well reverses it
+    let flipped: u8x16 = cast(self ^ i8x16::from(i8::MAX));
+    flipped.reduce_min() as i8 ^ i8::MAX
+  }
 
   #[inline]
   #[must_use]
@@ -378,4 +544,169 @@ impl i8x16 {
   pub fn none(self) -> bool {
     !self.any()
   }
+  /// Shifts each lane left by the count in the same lane of `counts`.
+  ///
+  /// Counts of 8 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shl_each(self, counts: u8x16) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        let counts: Self = cast(counts);
+        // shift by each bit of the count in turn, then zero the lanes with
+        // counts that are too large
+        let mut x = self;
+        for i in 0..3 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step as i8);
+          x = (counts & bit).cmp_eq(bit).blend(x << step, x);
+        }
+        x & (counts & Self::splat(!7)).cmp_eq(Self::default())
+      } else {
+        let arr: [i8; 16] = cast(self);
+        let counts: [u8; 16] = cast(counts);
+        cast([
+          if counts[0] < 8 { arr[0] << counts[0] } else { 0 },
+          if counts[1] < 8 { arr[1] << counts[1] } else { 0 },
+          if counts[2] < 8 { arr[2] << counts[2] } else { 0 },
+          if counts[3] < 8 { arr[3] << counts[3] } else { 0 },
+          if counts[4] < 8 { arr[4] << counts[4] } else { 0 },
+          if counts[5] < 8 { arr[5] << counts[5] } else { 0 },
+          if counts[6] < 8 { arr[6] << counts[6] } else { 0 },
+          if counts[7] < 8 { arr[7] << counts[7] } else { 0 },
+          if counts[8] < 8 { arr[8] << counts[8] } else { 0 },
+          if counts[9] < 8 { arr[9] << counts[9] } else { 0 },
+          if counts[10] < 8 { arr[10] << counts[10] } else { 0 },
+          if counts[11] < 8 { arr[11] << counts[11] } else { 0 },
+          if counts[12] < 8 { arr[12] << counts[12] } else { 0 },
+          if counts[13] < 8 { arr[13] << counts[13] } else { 0 },
+          if counts[14] < 8 { arr[14] << counts[14] } else { 0 },
+          if counts[15] < 8 { arr[15] << counts[15] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Shifts each lane right by the count in the same lane of `counts`.
+  ///
+  /// Counts of 8 or more give a full sign fill.
+  #[inline]
+  #[must_use]
+  pub fn shr_each(self, counts: u8x16) -> Self {
+    // flip negative lanes so that a logical shift does a sign fill
+    let sign = Self::default().cmp_gt(self);
+    let x: u8x16 = cast(self ^ sign);
+    cast::<u8x16, Self>(x.shr_each(counts)) ^ sign
+  }
+  /// Adds each lane, clamping to the range of `i8` instead of wrapping.
+  #[inline]
+  #[must_use]
+  pub fn saturating_add(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: add_saturating_i8_m128i(self.sse, rhs.sse) }
+      } else {
+        let arr: [i8; 16] = cast(self);
+        let rhs: [i8; 16] = cast(rhs);
+        cast([
+          arr[0].saturating_add(rhs[0]),
+          arr[1].saturating_add(rhs[1]),
+          arr[2].saturating_add(rhs[2]),
+          arr[3].saturating_add(rhs[3]),
+          arr[4].saturating_add(rhs[4]),
+          arr[5].saturating_add(rhs[5]),
+          arr[6].saturating_add(rhs[6]),
+          arr[7].saturating_add(rhs[7]),
+          arr[8].saturating_add(rhs[8]),
+          arr[9].saturating_add(rhs[9]),
+          arr[10].saturating_add(rhs[10]),
+          arr[11].saturating_add(rhs[11]),
+          arr[12].saturating_add(rhs[12]),
+          arr[13].saturating_add(rhs[13]),
+          arr[14].saturating_add(rhs[14]),
+          arr[15].saturating_add(rhs[15]),
+        ])
+      }
+    }
+  }
+  /// Subtracts each lane, clamping to the range of `i8` instead of wrapping.
+  #[inline]
+  #[must_use]
+  pub fn saturating_sub(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: sub_saturating_i8_m128i(self.sse, rhs.sse) }
+      } else {
+        let arr: [i8; 16] = cast(self);
+        let rhs: [i8; 16] = cast(rhs);
+        cast([
+          arr[0].saturating_sub(rhs[0]),
+          arr[1].saturating_sub(rhs[1]),
+          arr[2].saturating_sub(rhs[2]),
+          arr[3].saturating_sub(rhs[3]),
+          arr[4].saturating_sub(rhs[4]),
+          arr[5].saturating_sub(rhs[5]),
+          arr[6].saturating_sub(rhs[6]),
+          arr[7].saturating_sub(rhs[7]),
+          arr[8].saturating_sub(rhs[8]),
+          arr[9].saturating_sub(rhs[9]),
+          arr[10].saturating_sub(rhs[10]),
+          arr[11].saturating_sub(rhs[11]),
+          arr[12].saturating_sub(rhs[12]),
+          arr[13].saturating_sub(rhs[13]),
+          arr[14].saturating_sub(rhs[14]),
+          arr[15].saturating_sub(rhs[15]),
+        ])
+      }
+    }
+  }
+  /// Looks up each lane of `idx` in `self` as a 16 entry table, so lane `i`
+  /// is `self[idx[i]]`, or 0 if `idx[i]` is negative or 16 or more.
+  #[inline]
+  #[must_use]
+  pub fn swizzle(self, idx: i8x16) -> Self {
+    cast(cast::<_, u8x16>(self).swizzle(cast(idx)))
+  }
+  /// Like [`swizzle`](Self::swizzle), but lanes with an index of 16 or more
+  /// are unspecified. Negative indices still give 0.
+  #[inline]
+  #[must_use]
+  pub fn swizzle_relaxed(self, idx: i8x16) -> Self {
+    cast(cast::<_, u8x16>(self).swizzle_relaxed(cast(idx)))
+  }
+  /// Sign extends the low 8 lanes to `i16`.
+  #[inline]
+  #[must_use]
+  pub fn widen_low(self) -> i16x8 {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        cast(convert_to_i16_m128i_from_lower8_i8_m128i(self.sse))
+      } else if #[cfg(target_feature="sse2")] {
+        cast(shr_imm_i16_m128i!(unpack_low_i8_m128i(self.sse, self.sse), 8))
+      } else {
+        let a: [i8; 16] = cast(self);
+        let mut out = [0_i16; 8];
+        for (o, &x) in out.iter_mut().zip(a[..8].iter()) {
+          *o = i16::from(x);
+        }
+        i16x8::from(out)
+      }
+    }
+  }
+  /// Sign extends the high 8 lanes to `i16`.
+  #[inline]
+  #[must_use]
+  pub fn widen_high(self) -> i16x8 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        cast(shr_imm_i16_m128i!(unpack_high_i8_m128i(self.sse, self.sse), 8))
+      } else {
+        let a: [i8; 16] = cast(self);
+        let mut out = [0_i16; 8];
+        for (o, &x) in out.iter_mut().zip(a[8..].iter()) {
+          *o = i16::from(x);
+        }
+        i16x8::from(out)
+      }
+    }
+  }
 }
@@ -119,6 +119,74 @@ impl Sub for i8x32 {
   }
 }
 
+impl Mul for i8x32 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx: mul_i8_keep_low_m256i(self.avx, rhs.avx) }
+      } else if #[cfg(target_feature="sse2")] {
+        Self { sse0: mul_i8_keep_low_m128i(self.sse0, rhs.sse0), sse1: mul_i8_keep_low_m128i(self.sse1, rhs.sse1) }
+      } else {
+        Self { arr: [
+          self.arr[0].wrapping_mul(rhs.arr[0]),
+          self.arr[1].wrapping_mul(rhs.arr[1]),
+          self.arr[2].wrapping_mul(rhs.arr[2]),
+          self.arr[3].wrapping_mul(rhs.arr[3]),
+          self.arr[4].wrapping_mul(rhs.arr[4]),
+          self.arr[5].wrapping_mul(rhs.arr[5]),
+          self.arr[6].wrapping_mul(rhs.arr[6]),
+          self.arr[7].wrapping_mul(rhs.arr[7]),
+          self.arr[8].wrapping_mul(rhs.arr[8]),
+          self.arr[9].wrapping_mul(rhs.arr[9]),
+          self.arr[10].wrapping_mul(rhs.arr[10]),
+          self.arr[11].wrapping_mul(rhs.arr[11]),
+          self.arr[12].wrapping_mul(rhs.arr[12]),
+          self.arr[13].wrapping_mul(rhs.arr[13]),
+          self.arr[14].wrapping_mul(rhs.arr[14]),
+          self.arr[15].wrapping_mul(rhs.arr[15]),
+          self.arr[16].wrapping_mul(rhs.arr[16]),
+          self.arr[17].wrapping_mul(rhs.arr[17]),
+          self.arr[18].wrapping_mul(rhs.arr[18]),
+          self.arr[19].wrapping_mul(rhs.arr[19]),
+          self.arr[20].wrapping_mul(rhs.arr[20]),
+          self.arr[21].wrapping_mul(rhs.arr[21]),
+          self.arr[22].wrapping_mul(rhs.arr[22]),
+          self.arr[23].wrapping_mul(rhs.arr[23]),
+          self.arr[24].wrapping_mul(rhs.arr[24]),
+          self.arr[25].wrapping_mul(rhs.arr[25]),
+          self.arr[26].wrapping_mul(rhs.arr[26]),
+          self.arr[27].wrapping_mul(rhs.arr[27]),
+          self.arr[28].wrapping_mul(rhs.arr[28]),
+          self.arr[29].wrapping_mul(rhs.arr[29]),
+          self.arr[30].wrapping_mul(rhs.arr[30]),
+          self.arr[31].wrapping_mul(rhs.arr[31]),
+        ]}
+      }
+    }
+  }
+}
+
+impl Mul<i8> for i8x32 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: i8) -> Self::Output {
+    self.mul(Self::splat(rhs))
+  }
+}
+
+impl Mul<i8x32> for i8 {
+  type Output = i8x32;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: i8x32) -> Self::Output {
+    i8x32::splat(self) * rhs
+  }
+}
+
 impl BitAnd for i8x32 {
   type Output = Self;
   #[inline]
@@ -269,6 +337,134 @@ impl BitXor for i8x32 {
   }
 }
 
+macro_rules! impl_shl_t_for_i8x32 {
+  ($($shift_type:ty),+ $(,)?) => {
+    $(impl Shl<$shift_type> for i8x32 {
+      type Output = Self;
+      /// Shifts all lanes by the value given.
+      #[inline]
+      #[must_use]
+      fn shl(self, rhs: $shift_type) -> Self::Output {
+        let u = rhs as u64;
+        pick! {
+          if #[cfg(target_feature="avx2")] {
+            // shift as u16 lanes, then clear the bits that crossed a byte
+            let shift = cast([u, 0]);
+            let mask = Self::splat((0xFF_u64 << u.min(8)) as i8);
+            Self { avx: shl_all_u16_m256i(self.avx, shift) } & mask
+          } else if #[cfg(target_feature="sse2")] {
+            let shift = cast([u, 0]);
+            let mask = Self::splat((0xFF_u64 << u.min(8)) as i8);
+            Self { sse0: shl_all_u16_m128i(self.sse0, shift), sse1: shl_all_u16_m128i(self.sse1, shift) } & mask
+          } else {
+            Self { arr: [
+              self.arr[0] << u,
+              self.arr[1] << u,
+              self.arr[2] << u,
+              self.arr[3] << u,
+              self.arr[4] << u,
+              self.arr[5] << u,
+              self.arr[6] << u,
+              self.arr[7] << u,
+              self.arr[8] << u,
+              self.arr[9] << u,
+              self.arr[10] << u,
+              self.arr[11] << u,
+              self.arr[12] << u,
+              self.arr[13] << u,
+              self.arr[14] << u,
+              self.arr[15] << u,
+              self.arr[16] << u,
+              self.arr[17] << u,
+              self.arr[18] << u,
+              self.arr[19] << u,
+              self.arr[20] << u,
+              self.arr[21] << u,
+              self.arr[22] << u,
+              self.arr[23] << u,
+              self.arr[24] << u,
+              self.arr[25] << u,
+              self.arr[26] << u,
+              self.arr[27] << u,
+              self.arr[28] << u,
+              self.arr[29] << u,
+              self.arr[30] << u,
+              self.arr[31] << u,
+            ]}
+          }
+        }
+      }
+    })+
+  };
+}
+impl_shl_t_for_i8x32!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+
+macro_rules! impl_shr_t_for_i8x32 {
+  ($($shift_type:ty),+ $(,)?) => {
+    $(impl Shr<$shift_type> for i8x32 {
+      type Output = Self;
+      /// Shifts all lanes by the value given.
+      #[inline]
+      #[must_use]
+      fn shr(self, rhs: $shift_type) -> Self::Output {
+        let u = rhs as u64;
+        pick! {
+          if #[cfg(target_feature="avx2")] {
+            // sign extend each byte into an i16 lane, shift, and pack back down
+            let shift = i16_count_for_byte_shr(u);
+            let lo = shr_all_i16_m256i(unpack_low_i8_m256i(self.avx, self.avx), shift);
+            let hi = shr_all_i16_m256i(unpack_high_i8_m256i(self.avx, self.avx), shift);
+            Self { avx: pack_i16_to_i8_m256i(lo, hi) }
+          } else if #[cfg(target_feature="sse2")] {
+            let shift = i16_count_for_byte_shr(u);
+            let lo0 = shr_all_i16_m128i(unpack_low_i8_m128i(self.sse0, self.sse0), shift);
+            let hi0 = shr_all_i16_m128i(unpack_high_i8_m128i(self.sse0, self.sse0), shift);
+            let lo1 = shr_all_i16_m128i(unpack_low_i8_m128i(self.sse1, self.sse1), shift);
+            let hi1 = shr_all_i16_m128i(unpack_high_i8_m128i(self.sse1, self.sse1), shift);
+            Self { sse0: pack_i16_to_i8_m128i(lo0, hi0), sse1: pack_i16_to_i8_m128i(lo1, hi1) }
+          } else {
+            Self { arr: [
+              self.arr[0] >> u,
+              self.arr[1] >> u,
+              self.arr[2] >> u,
+              self.arr[3] >> u,
+              self.arr[4] >> u,
+              self.arr[5] >> u,
+              self.arr[6] >> u,
+              self.arr[7] >> u,
+              self.arr[8] >> u,
+              self.arr[9] >> u,
+              self.arr[10] >> u,
+              self.arr[11] >> u,
+              self.arr[12] >> u,
+              self.arr[13] >> u,
+              self.arr[14] >> u,
+              self.arr[15] >> u,
+              self.arr[16] >> u,
+              self.arr[17] >> u,
+              self.arr[18] >> u,
+              self.arr[19] >> u,
+              self.arr[20] >> u,
+              self.arr[21] >> u,
+              self.arr[22] >> u,
+              self.arr[23] >> u,
+              self.arr[24] >> u,
+              self.arr[25] >> u,
+              self.arr[26] >> u,
+              self.arr[27] >> u,
+              self.arr[28] >> u,
+              self.arr[29] >> u,
+              self.arr[30] >> u,
+              self.arr[31] >> u,
+            ]}
+          }
+        }
+      }
+    })+
+  };
+}
+impl_shr_t_for_i8x32!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+
 impl CmpEq for i8x32 {
   type Output = Self;
   #[inline]
@@ -420,6 +616,67 @@ impl CmpLt for i8x32 {
 }
 
 impl i8x32 {
+  #[inline]
+  #[must_use]
+  pub fn move_mask(self) -> i32 {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        move_mask_m256i(self.avx)
+      } else if #[cfg(target_feature="sse2")] {
+        move_mask_i8_m128i(self.sse1) << 16 | move_mask_i8_m128i(self.sse0)
+      }
+      else {
+        ((self.arr[0] < 0) as i32) << 0 |
+        ((self.arr[1] < 0) as i32) << 1 |
+        ((self.arr[2] < 0) as i32) << 2 |
+        ((self.arr[3] < 0) as i32) << 3 |
+        ((self.arr[4] < 0) as i32) << 4 |
+        ((self.arr[5] < 0) as i32) << 5 |
+        ((self.arr[6] < 0) as i32) << 6 |
+        ((self.arr[7] < 0) as i32) << 7 |
+        ((self.arr[8] < 0) as i32) << 8 |
+        ((self.arr[9] < 0) as i32) << 9 |
+        ((self.arr[10] < 0) as i32) << 10 |
+        ((self.arr[11] < 0) as i32) << 11 |
+        ((self.arr[12] < 0) as i32) << 12 |
+        ((self.arr[13] < 0) as i32) << 13 |
+        ((self.arr[14] < 0) as i32) << 14 |
+        ((self.arr[15] < 0) as i32) << 15 |
+        ((self.arr[16] < 0) as i32) << 16 |
+        ((self.arr[17] < 0) as i32) << 17 |
+        ((self.arr[18] < 0) as i32) << 18 |
+        ((self.arr[19] < 0) as i32) << 19 |
+        ((self.arr[20] < 0) as i32) << 20 |
+        ((self.arr[21] < 0) as i32) << 21 |
+        ((self.arr[22] < 0) as i32) << 22 |
+        ((self.arr[23] < 0) as i32) << 23 |
+        ((self.arr[24] < 0) as i32) << 24 |
+        ((self.arr[25] < 0) as i32) << 25 |
+        ((self.arr[26] < 0) as i32) << 26 |
+        ((self.arr[27] < 0) as i32) << 27 |
+        ((self.arr[28] < 0) as i32) << 28 |
+        ((self.arr[29] < 0) as i32) << 29 |
+        ((self.arr[30] < 0) as i32) << 30 |
+        ((self.arr[31] < 0) as i32) << 31
+      }
+    }
+  }
+  #[inline]
+  #[must_use]
+  pub fn any(self) -> bool {
+    self.move_mask() != 0
+  }
+  #[inline]
+  #[must_use]
+  pub fn all(self) -> bool {
+    // thirty-two lanes
+    self.move_mask() == -1
+  }
+  #[inline]
+  #[must_use]
+  pub fn none(self) -> bool {
+    !self.any()
+  }
   #[inline]
   #[must_use]
   pub fn blend(self, t: Self, f: Self) -> Self {
@@ -507,4 +764,263 @@ impl i8x32 {
       }
     }
   }
+  /// Clamps each lane to `[min, max]`.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    self.max(min).min(max)
+  }
+  /// Shifts each lane left by the count in the same lane of `counts`.
+  ///
+  /// Counts are read as unsigned, and counts of 8 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shl_each(self, counts: i8x32) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // shift by each bit of the count in turn, then zero the lanes with
+        // counts that are too large
+        let mut x = self;
+        for i in 0..3 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step as i8);
+          x = (counts & bit).cmp_eq(bit).blend(x << step, x);
+        }
+        x & (counts & Self::splat(!7)).cmp_eq(Self::default())
+      } else {
+        let arr: [i8; 32] = cast(self);
+        let counts: [u8; 32] = cast(counts);
+        cast([
+          if counts[0] < 8 { arr[0] << counts[0] } else { 0 },
+          if counts[1] < 8 { arr[1] << counts[1] } else { 0 },
+          if counts[2] < 8 { arr[2] << counts[2] } else { 0 },
+          if counts[3] < 8 { arr[3] << counts[3] } else { 0 },
+          if counts[4] < 8 { arr[4] << counts[4] } else { 0 },
+          if counts[5] < 8 { arr[5] << counts[5] } else { 0 },
+          if counts[6] < 8 { arr[6] << counts[6] } else { 0 },
+          if counts[7] < 8 { arr[7] << counts[7] } else { 0 },
+          if counts[8] < 8 { arr[8] << counts[8] } else { 0 },
+          if counts[9] < 8 { arr[9] << counts[9] } else { 0 },
+          if counts[10] < 8 { arr[10] << counts[10] } else { 0 },
+          if counts[11] < 8 { arr[11] << counts[11] } else { 0 },
+          if counts[12] < 8 { arr[12] << counts[12] } else { 0 },
+          if counts[13] < 8 { arr[13] << counts[13] } else { 0 },
+          if counts[14] < 8 { arr[14] << counts[14] } else { 0 },
+          if counts[15] < 8 { arr[15] << counts[15] } else { 0 },
+          if counts[16] < 8 { arr[16] << counts[16] } else { 0 },
+          if counts[17] < 8 { arr[17] << counts[17] } else { 0 },
+          if counts[18] < 8 { arr[18] << counts[18] } else { 0 },
+          if counts[19] < 8 { arr[19] << counts[19] } else { 0 },
+          if counts[20] < 8 { arr[20] << counts[20] } else { 0 },
+          if counts[21] < 8 { arr[21] << counts[21] } else { 0 },
+          if counts[22] < 8 { arr[22] << counts[22] } else { 0 },
+          if counts[23] < 8 { arr[23] << counts[23] } else { 0 },
+          if counts[24] < 8 { arr[24] << counts[24] } else { 0 },
+          if counts[25] < 8 { arr[25] << counts[25] } else { 0 },
+          if counts[26] < 8 { arr[26] << counts[26] } else { 0 },
+          if counts[27] < 8 { arr[27] << counts[27] } else { 0 },
+          if counts[28] < 8 { arr[28] << counts[28] } else { 0 },
+          if counts[29] < 8 { arr[29] << counts[29] } else { 0 },
+          if counts[30] < 8 { arr[30] << counts[30] } else { 0 },
+          if counts[31] < 8 { arr[31] << counts[31] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Shifts each lane right by the count in the same lane of `counts`.
+  ///
+  /// Counts are read as unsigned, and counts of 8 or more give a full sign
+  /// fill.
+  #[inline]
+  #[must_use]
+  pub fn shr_each(self, counts: i8x32) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // shift by each bit of the count in turn, then sign fill the lanes
+        // with counts that are too large
+        let mut x = self;
+        for i in 0..3 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step as i8);
+          x = (counts & bit).cmp_eq(bit).blend(x >> step, x);
+        }
+        let in_range = (counts & Self::splat(!7)).cmp_eq(Self::default());
+        in_range.blend(x, self >> 7)
+      } else {
+        let arr: [i8; 32] = cast(self);
+        let counts: [u8; 32] = cast(counts);
+        cast([
+          arr[0] >> counts[0].min(7),
+          arr[1] >> counts[1].min(7),
+          arr[2] >> counts[2].min(7),
+          arr[3] >> counts[3].min(7),
+          arr[4] >> counts[4].min(7),
+          arr[5] >> counts[5].min(7),
+          arr[6] >> counts[6].min(7),
+          arr[7] >> counts[7].min(7),
+          arr[8] >> counts[8].min(7),
+          arr[9] >> counts[9].min(7),
+          arr[10] >> counts[10].min(7),
+          arr[11] >> counts[11].min(7),
+          arr[12] >> counts[12].min(7),
+          arr[13] >> counts[13].min(7),
+          arr[14] >> counts[14].min(7),
+          arr[15] >> counts[15].min(7),
+          arr[16] >> counts[16].min(7),
+          arr[17] >> counts[17].min(7),
+          arr[18] >> counts[18].min(7),
+          arr[19] >> counts[19].min(7),
+          arr[20] >> counts[20].min(7),
+          arr[21] >> counts[21].min(7),
+          arr[22] >> counts[22].min(7),
+          arr[23] >> counts[23].min(7),
+          arr[24] >> counts[24].min(7),
+          arr[25] >> counts[25].min(7),
+          arr[26] >> counts[26].min(7),
+          arr[27] >> counts[27].min(7),
+          arr[28] >> counts[28].min(7),
+          arr[29] >> counts[29].min(7),
+          arr[30] >> counts[30].min(7),
+          arr[31] >> counts[31].min(7),
+        ])
+      }
+    }
+  }
+  /// Adds each lane, clamping to the range of `i8` instead of wrapping.
+  #[inline]
+  #[must_use]
+  pub fn saturating_add(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx: add_saturating_i8_m256i(self.avx, rhs.avx) }
+      } else if #[cfg(target_feature="sse2")] {
+        Self {
+          sse0: add_saturating_i8_m128i(self.sse0, rhs.sse0),
+          sse1: add_saturating_i8_m128i(self.sse1, rhs.sse1),
+        }
+      } else {
+        let arr: [i8; 32] = cast(self);
+        let rhs: [i8; 32] = cast(rhs);
+        cast([
+          arr[0].saturating_add(rhs[0]),
+          arr[1].saturating_add(rhs[1]),
+          arr[2].saturating_add(rhs[2]),
+          arr[3].saturating_add(rhs[3]),
+          arr[4].saturating_add(rhs[4]),
+          arr[5].saturating_add(rhs[5]),
+          arr[6].saturating_add(rhs[6]),
+          arr[7].saturating_add(rhs[7]),
+          arr[8].saturating_add(rhs[8]),
+          arr[9].saturating_add(rhs[9]),
+          arr[10].saturating_add(rhs[10]),
+          arr[11].saturating_add(rhs[11]),
+          arr[12].saturating_add(rhs[12]),
+          arr[13].saturating_add(rhs[13]),
+          arr[14].saturating_add(rhs[14]),
+          arr[15].saturating_add(rhs[15]),
+          arr[16].saturating_add(rhs[16]),
+          arr[17].saturating_add(rhs[17]),
+          arr[18].saturating_add(rhs[18]),
+          arr[19].saturating_add(rhs[19]),
+          arr[20].saturating_add(rhs[20]),
+          arr[21].saturating_add(rhs[21]),
+          arr[22].saturating_add(rhs[22]),
+          arr[23].saturating_add(rhs[23]),
+          arr[24].saturating_add(rhs[24]),
+          arr[25].saturating_add(rhs[25]),
+          arr[26].saturating_add(rhs[26]),
+          arr[27].saturating_add(rhs[27]),
+          arr[28].saturating_add(rhs[28]),
+          arr[29].saturating_add(rhs[29]),
+          arr[30].saturating_add(rhs[30]),
+          arr[31].saturating_add(rhs[31]),
+        ])
+      }
+    }
+  }
+  /// Subtracts each lane, clamping to the range of `i8` instead of wrapping.
+  #[inline]
+  #[must_use]
+  pub fn saturating_sub(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx: sub_saturating_i8_m256i(self.avx, rhs.avx) }
+      } else if #[cfg(target_feature="sse2")] {
+        Self {
+          sse0: sub_saturating_i8_m128i(self.sse0, rhs.sse0),
+          sse1: sub_saturating_i8_m128i(self.sse1, rhs.sse1),
+        }
+      } else {
+        let arr: [i8; 32] = cast(self);
+        let rhs: [i8; 32] = cast(rhs);
+        cast([
+          arr[0].saturating_sub(rhs[0]),
+          arr[1].saturating_sub(rhs[1]),
+          arr[2].saturating_sub(rhs[2]),
+          arr[3].saturating_sub(rhs[3]),
+          arr[4].saturating_sub(rhs[4]),
+          arr[5].saturating_sub(rhs[5]),
+          arr[6].saturating_sub(rhs[6]),
+          arr[7].saturating_sub(rhs[7]),
+          arr[8].saturating_sub(rhs[8]),
+          arr[9].saturating_sub(rhs[9]),
+          arr[10].saturating_sub(rhs[10]),
+          arr[11].saturating_sub(rhs[11]),
+          arr[12].saturating_sub(rhs[12]),
+          arr[13].saturating_sub(rhs[13]),
+          arr[14].saturating_sub(rhs[14]),
+          arr[15].saturating_sub(rhs[15]),
+          arr[16].saturating_sub(rhs[16]),
+          arr[17].saturating_sub(rhs[17]),
+          arr[18].saturating_sub(rhs[18]),
+          arr[19].saturating_sub(rhs[19]),
+          arr[20].saturating_sub(rhs[20]),
+          arr[21].saturating_sub(rhs[21]),
+          arr[22].saturating_sub(rhs[22]),
+          arr[23].saturating_sub(rhs[23]),
+          arr[24].saturating_sub(rhs[24]),
+          arr[25].saturating_sub(rhs[25]),
+          arr[26].saturating_sub(rhs[26]),
+          arr[27].saturating_sub(rhs[27]),
+          arr[28].saturating_sub(rhs[28]),
+          arr[29].saturating_sub(rhs[29]),
+          arr[30].saturating_sub(rhs[30]),
+          arr[31].saturating_sub(rhs[31]),
+        ])
+      }
+    }
+  }
+  /// Looks up each lane of `idx` in `self`, treating each 128-bit half as its
+  /// own 16 entry table like `vpshufb`. Indices only reach lanes in their own
+  /// half, and a lane is 0 if its index is negative or 16 or more.
+  #[inline]
+  #[must_use]
+  pub fn swizzle(self, idx: i8x32) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        // push indices of 16 or more up to the high bit, which zeroes the lane
+        let idx = add_saturating_u8_m256i(idx.avx, set_splat_i8_m256i(0x70));
+        Self { avx: shuffle_av_i8z_half_m256i(self.avx, idx) }
+      } else {
+        let [lo, hi]: [i8x16; 2] = cast(self);
+        let [idx_lo, idx_hi]: [i8x16; 2] = cast(idx);
+        cast([lo.swizzle(idx_lo), hi.swizzle(idx_hi)])
+      }
+    }
+  }
+  /// Like [`swizzle`](Self::swizzle), but lanes with an index of 16 or more
+  /// are unspecified. Negative indices still give 0.
+  #[inline]
+  #[must_use]
+  pub fn swizzle_relaxed(self, idx: i8x32) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx: shuffle_av_i8z_half_m256i(self.avx, idx.avx) }
+      } else {
+        let [lo, hi]: [i8x16; 2] = cast(self);
+        let [idx_lo, idx_hi]: [i8x16; 2] = cast(idx);
+        cast([lo.swizzle_relaxed(idx_lo), hi.swizzle_relaxed(idx_hi)])
+      }
+    }
+  }
 }
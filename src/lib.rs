@@ -22,7 +22,6 @@
 
 // TODO
 // Add/Sub/Mul/Div with constant
-// Shuffle left/right/by index
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -159,6 +158,22 @@ macro_rules! polynomial_8 {
   }};
 }
 
+macro_rules! polynomial_12 {
+  ($x:expr, $c0:expr, $c1:expr, $c2:expr, $c3:expr, $c4:expr, $c5:expr, $c6:expr, $c7:expr, $c8:expr, $c9:expr, $c10:expr, $c11:expr, $c12:expr $(,)?) => {{
+    let x = $x;
+    let x2 = x * x;
+    let x4 = x2 * x2;
+    let x8 = x4 * x4;
+    x8.mul_add(
+      x4.mul_add($c12, x2.mul_add(x.mul_add($c11, $c10), x.mul_add($c9, $c8))),
+      x4.mul_add(
+        x2.mul_add(x.mul_add($c7, $c6), x.mul_add($c5, $c4)),
+        x2.mul_add(x.mul_add($c3, $c2), x.mul_add($c1, $c0)),
+      ),
+    )
+  }};
+}
+
 macro_rules! polynomial_13 {
   // calculates polynomial c13*x^13 + c12*x^12 + ... + c1*x + c0
   ($x:expr,  $c2:expr, $c3:expr, $c4:expr, $c5:expr,$c6:expr, $c7:expr, $c8:expr,$c9:expr, $c10:expr, $c11:expr, $c12:expr, $c13:expr  $(,)?) => {{
@@ -251,6 +266,12 @@ pub use u64x2_::*;
 mod u64x4_;
 pub use u64x4_::*;
 
+mod divisor;
+pub use divisor::*;
+
+mod trig_reduce;
+use trig_reduce::*;
+
 #[allow(non_camel_case_types)]
 #[repr(C, align(16))]
 union ConstUnionHack128bit {
@@ -275,6 +296,98 @@ where
   n ^ ((n ^ y) & mask)
 }
 
+/// Wrapping `i8` multiply, built from two widened `i16` multiplies (one for
+/// the even bytes and one for the odd bytes).
+#[cfg(target_feature = "sse2")]
+#[inline]
+#[must_use]
+fn mul_i8_keep_low_m128i(a: m128i, b: m128i) -> m128i {
+  let even = mul_i16_keep_low_m128i(a, b);
+  let odd =
+    mul_i16_keep_low_m128i(shr_imm_u16_m128i!(a, 8), shr_imm_u16_m128i!(b, 8));
+  let even = bitand_m128i(even, set_splat_i16_m128i(0x00FF));
+  bitor_m128i(even, shl_imm_u16_m128i!(odd, 8))
+}
+
+/// Wrapping `i8` multiply, built from two widened `i16` multiplies (one for
+/// the even bytes and one for the odd bytes).
+#[cfg(target_feature = "avx2")]
+#[inline]
+#[must_use]
+fn mul_i8_keep_low_m256i(a: m256i, b: m256i) -> m256i {
+  let even = mul_i16_keep_low_m256i(a, b);
+  let odd =
+    mul_i16_keep_low_m256i(shr_imm_u16_m256i!(a, 8), shr_imm_u16_m256i!(b, 8));
+  let even = bitand_m256i(even, set_splat_i16_m256i(0x00FF));
+  bitor_m256i(even, shl_imm_u16_m256i!(odd, 8))
+}
+
+/// Wrapping `i64` multiply, built from `u32 x u32 -> u64` partial products.
+///
+/// With `a = ah * 2^32 + al` (and the same for `b`), the low 64 bits of the
+/// product are `al * bl + ((ah * bl + al * bh) << 32)`.
+#[cfg(target_feature = "sse2")]
+#[inline]
+#[must_use]
+fn mul_i64_keep_low_m128i(a: m128i, b: m128i) -> m128i {
+  let low = mul_widen_u32_odd_m128i(a, b);
+  let cross = add_i64_m128i(
+    mul_widen_u32_odd_m128i(shr_imm_u64_m128i!(a, 32), b),
+    mul_widen_u32_odd_m128i(a, shr_imm_u64_m128i!(b, 32)),
+  );
+  add_i64_m128i(low, shl_imm_u64_m128i!(cross, 32))
+}
+
+/// Wrapping `i64` multiply, built from `u32 x u32 -> u64` partial products.
+#[cfg(target_feature = "avx2")]
+#[inline]
+#[must_use]
+fn mul_i64_keep_low_m256i(a: m256i, b: m256i) -> m256i {
+  let low = mul_u64_low_bits_m256i(a, b);
+  let cross = add_i64_m256i(
+    mul_u64_low_bits_m256i(shr_imm_u64_m256i!(a, 32), b),
+    mul_u64_low_bits_m256i(a, shr_imm_u64_m256i!(b, 32)),
+  );
+  add_i64_m256i(low, shl_imm_u64_m256i!(cross, 32))
+}
+
+/// Moves a 128-bit vector down by `shift` bits, which must be 8, 16, 32 or
+/// 64, filling in zeros.
+#[inline]
+#[must_use]
+fn shr_bits_128<T: Pod>(v: T, shift: u32) -> T {
+  pick! {
+    if #[cfg(target_feature="sse2")] {
+      let v: m128i = cast(v);
+      cast(match shift {
+        64 => byte_shr_imm_u128_m128i!(v, 8),
+        32 => byte_shr_imm_u128_m128i!(v, 4),
+        16 => byte_shr_imm_u128_m128i!(v, 2),
+        _ => byte_shr_imm_u128_m128i!(v, 1),
+      })
+    } else {
+      // going through bytes keeps lane 0 at the bottom on any endianness
+      let bytes: [u8; 16] = cast(v);
+      cast((u128::from_le_bytes(bytes) >> shift).to_le_bytes())
+    }
+  }
+}
+
+/// Folds all the lanes of a 128-bit vector together with `f`, halving the
+/// number of lanes each step. The result is in lane 0, and the other lanes
+/// are junk.
+#[inline]
+#[must_use]
+fn fold_lanes_128<T: Pod>(v: T, lane_bits: u32, f: impl Fn(T, T) -> T) -> T {
+  let mut v = v;
+  let mut shift = 64;
+  while shift >= lane_bits {
+    v = f(v, shr_bits_128(v, shift));
+    shift /= 2;
+  }
+  v
+}
+
 /// given `type.op(type)` and type is Copy, impls `type.op(&type)`
 macro_rules! bulk_impl_op_ref_self_for {
   ($(($op:ident, $method:ident) => [$($t:ty),+]),+ $(,)?) => {
@@ -296,8 +409,9 @@ macro_rules! bulk_impl_op_ref_self_for {
 bulk_impl_op_ref_self_for! {
   (Add, add) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
   (Sub, sub) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
-  (Mul, mul) => [f32x8, f32x4, f64x4, f64x2, i16x8, i32x8, i32x4],
-  (Div, div) => [f32x8, f32x4, f64x4, f64x2],
+  (Mul, mul) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
+  (Div, div) => [f32x8, f32x4, f64x4, f64x2, i16x8, i32x8, i32x4, i64x4, i64x2, u16x8, u32x8, u32x4, u64x4, u64x2],
+  (Rem, rem) => [f32x8, f32x4, f64x4, f64x2, i16x8, i32x8, i32x4, i64x4, i64x2, u16x8, u32x8, u32x4, u64x4, u64x2],
   (BitAnd, bitand) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
   (BitOr, bitor) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
   (BitXor, bitxor) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
@@ -326,10 +440,12 @@ bulk_impl_op_assign_for! {
   (AddAssign<&Self>, add, add_assign) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
   (SubAssign<Self>, sub, sub_assign) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
   (SubAssign<&Self>, sub, sub_assign) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
-  (MulAssign<Self>, mul, mul_assign) => [f32x8, f32x4, f64x4, f64x2, i16x8, i32x8, i32x4],
-  (MulAssign<&Self>, mul, mul_assign) => [f32x8, f32x4, f64x4, f64x2, i16x8, i32x8, i32x4],
-  (DivAssign<Self>, div, div_assign) => [f32x8, f32x4, f64x4, f64x2],
-  (DivAssign<&Self>, div, div_assign) => [f32x8, f32x4, f64x4, f64x2],
+  (MulAssign<Self>, mul, mul_assign) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
+  (MulAssign<&Self>, mul, mul_assign) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
+  (DivAssign<Self>, div, div_assign) => [f32x8, f32x4, f64x4, f64x2, i16x8, i32x8, i32x4, i64x4, i64x2, u16x8, u32x8, u32x4, u64x4, u64x2],
+  (DivAssign<&Self>, div, div_assign) => [f32x8, f32x4, f64x4, f64x2, i16x8, i32x8, i32x4, i64x4, i64x2, u16x8, u32x8, u32x4, u64x4, u64x2],
+  (RemAssign<Self>, rem, rem_assign) => [f32x8, f32x4, f64x4, f64x2, i16x8, i32x8, i32x4, i64x4, i64x2, u16x8, u32x8, u32x4, u64x4, u64x2],
+  (RemAssign<&Self>, rem, rem_assign) => [f32x8, f32x4, f64x4, f64x2, i16x8, i32x8, i32x4, i64x4, i64x2, u16x8, u32x8, u32x4, u64x4, u64x2],
   (BitAndAssign<Self>, bitand, bitand_assign) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
   (BitAndAssign<&Self>, bitand, bitand_assign) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
   (BitOrAssign<Self>, bitor, bitor_assign) => [f32x8, f32x4, f64x4, f64x2, i8x32, i8x16, i16x8, i32x8, i32x4, i64x2, u8x16, u16x8, u32x8, u32x4, u64x4, u64x2],
@@ -449,7 +565,948 @@ macro_rules! impl_integer_product {
 }
 
 impl_integer_product! {
-  i16x8, i32x4, i32x8,
+  i8x32, i8x16, i16x8, i32x4, i32x8, i64x4, i64x2, u8x16, u32x8, u64x4, u64x2,
+}
+
+/// Adds horizontal reductions to a type. A 128-bit type is given as
+/// `(element, lanes, lane_bits)` and folds its lanes with a shuffle tree,
+/// while a 256-bit type is given as `[half, element]` and folds its two
+/// halves together before reducing the half.
+macro_rules! impl_reduce {
+  ($t:ident ($e:ty, $n:literal, $bits:literal): $($name:ident, $f:expr, $doc:literal;)+) => {
+    impl $t {
+      $(
+        #[doc = $doc]
+        #[inline]
+        #[must_use]
+        pub fn $name(self) -> $e {
+          let folded: [$e; $n] = cast(fold_lanes_128(self, $bits, $f));
+          folded[0]
+        }
+      )+
+    }
+  };
+  ($t:ident [$half:ident, $e:ty]: $($name:ident, $f:expr, $doc:literal;)+) => {
+    impl $t {
+      $(
+        #[doc = $doc]
+        #[inline]
+        #[must_use]
+        pub fn $name(self) -> $e {
+          let [lo, hi]: [$half; 2] = cast(self);
+          let f: fn($half, $half) -> $half = $f;
+          f(lo, hi).$name()
+        }
+      )+
+    }
+  };
+}
+
+macro_rules! impl_float_reductions {
+  ($($t:ident $how:tt),+ $(,)?) => {
+    $(
+      impl_reduce!($t $how:
+        reduce_min, |a, b| a.min_num(b), "The smallest lane, like folding with [`min_num`](Self::min_num), \
+          so NaN only comes out when every lane is NaN.";
+        reduce_max, |a, b| a.max_num(b), "The largest lane, like folding with [`max_num`](Self::max_num), \
+          so NaN only comes out when every lane is NaN.";
+        reduce_mul, |a, b| a * b, "The product of all the lanes.";
+      );
+    )+
+  };
+}
+
+impl_float_reductions! {
+  f32x4 (f32, 4, 32), f64x2 (f64, 2, 64), f32x8 [f32x4, f32], f64x4 [f64x2, f64],
+}
+
+macro_rules! impl_integer_reductions {
+  ($($t:ident $how:tt),+ $(,)?) => {
+    $(
+      impl_reduce!($t $how:
+        reduce_mul, |a, b| a * b, "The product of all the lanes, wrapping on overflow.";
+        reduce_and, |a, b| a & b, "All the lanes ANDed together.";
+        reduce_or, |a, b| a | b, "All the lanes ORed together.";
+        reduce_xor, |a, b| a ^ b, "All the lanes XORed together.";
+      );
+    )+
+  };
+}
+
+impl_integer_reductions! {
+  i8x16 (i8, 16, 8), i16x8 (i16, 8, 16), i32x4 (i32, 4, 32), i64x2 (i64, 2, 64),
+  u8x16 (u8, 16, 8), u16x8 (u16, 8, 16), u32x4 (u32, 4, 32), u64x2 (u64, 2, 64),
+  i8x32 [i8x16, i8], i32x8 [i32x4, i32], i64x4 [i64x2, i64], u32x8 [u32x4, u32],
+  u64x4 [u64x2, u64],
+}
+
+// the 8 and 16 bit types have their own, which use `phminposuw`
+macro_rules! impl_min_max_reductions {
+  ($($t:ident $how:tt: $min:expr, $max:expr;)+) => {
+    $(
+      impl_reduce!($t $how:
+        reduce_min, $min, "The smallest lane.";
+        reduce_max, $max, "The largest lane.";
+      );
+    )+
+  };
+}
+
+impl_min_max_reductions! {
+  i32x4 (i32, 4, 32): |a, b| a.min(b), |a, b| a.max(b);
+  u32x4 (u32, 4, 32): |a, b| a.min(b), |a, b| a.max(b);
+  i64x2 (i64, 2, 64): |a, b| a.cmp_gt(b).blend(b, a), |a, b| a.cmp_gt(b).blend(a, b);
+  u64x2 (u64, 2, 64): |a, b| a.cmp_gt(b).blend(b, a), |a, b| a.cmp_gt(b).blend(a, b);
+  i8x32 [i8x16, i8]: |a, b| a.min(b), |a, b| a.max(b);
+  i32x8 [i32x4, i32]: |a, b| a.min(b), |a, b| a.max(b);
+  u32x8 [u32x4, u32]: |a, b| a.min(b), |a, b| a.max(b);
+  i64x4 [i64x2, i64]: |a, b| a.cmp_gt(b).blend(b, a), |a, b| a.cmp_gt(b).blend(a, b);
+  u64x4 [u64x2, u64]: |a, b| a.cmp_gt(b).blend(b, a), |a, b| a.cmp_gt(b).blend(a, b);
+}
+
+/// Adds lane queries built on `move_mask` to a type, given as
+/// `(element, lanes)`.
+macro_rules! impl_lane_queries {
+  ($($t:ident ($e:ty, $n:literal)),+ $(,)?) => {
+    $(impl $t {
+      /// The index of the first lane with its sign bit set, such as the first
+      /// true lane of a comparison mask, or `None` if no lane is set.
+      #[inline]
+      #[must_use]
+      pub fn first_set(self) -> Option<usize> {
+        let mask = self.move_mask() as u32;
+        if mask == 0 {
+          None
+        } else {
+          Some(mask.trailing_zeros() as usize)
+        }
+      }
+      /// The index of the last lane with its sign bit set, or `None` if no lane
+      /// is set.
+      #[inline]
+      #[must_use]
+      pub fn last_set(self) -> Option<usize> {
+        let mask = self.move_mask() as u32;
+        if mask == 0 {
+          None
+        } else {
+          Some(31 - mask.leading_zeros() as usize)
+        }
+      }
+      /// The number of lanes with their sign bit set.
+      #[inline]
+      #[must_use]
+      pub fn count_set(self) -> u32 {
+        (self.move_mask() as u32).count_ones()
+      }
+      /// The smallest lane and its index, taking the first lane on ties.
+      ///
+      /// For floats, NaN lanes are skipped unless every lane is NaN, which
+      /// gives lane 0.
+      #[inline]
+      #[must_use]
+      pub fn argmin(self) -> ($e, usize) {
+        let i = self.cmp_eq(Self::splat(self.reduce_min())).first_set().unwrap_or(0);
+        let arr: [$e; $n] = cast(self);
+        (arr[i], i)
+      }
+      /// The largest lane and its index, taking the first lane on ties.
+      ///
+      /// For floats, NaN lanes are skipped unless every lane is NaN, which
+      /// gives lane 0.
+      #[inline]
+      #[must_use]
+      pub fn argmax(self) -> ($e, usize) {
+        let i = self.cmp_eq(Self::splat(self.reduce_max())).first_set().unwrap_or(0);
+        let arr: [$e; $n] = cast(self);
+        (arr[i], i)
+      }
+    })+
+  };
+}
+
+impl_lane_queries! {
+  f32x4 (f32, 4), f32x8 (f32, 8), f64x2 (f64, 2), f64x4 (f64, 4),
+  i8x16 (i8, 16), i16x8 (i16, 8), i32x4 (i32, 4), i64x2 (i64, 2),
+  i8x32 (i8, 32), i32x8 (i32, 8), i64x4 (i64, 4),
+  u8x16 (u8, 16), u16x8 (u16, 8), u32x4 (u32, 4), u64x2 (u64, 2),
+  u32x8 (u32, 8), u64x4 (u64, 4),
+}
+
+/// Adds `reverse` to a type, given as `(element, lanes, [lanes reversed])`,
+/// along with the array shuffle that the lane move macros fall back to when
+/// there's no instruction for the move.
+macro_rules! impl_lane_moves {
+  ($($t:ident ($e:ty, $n:literal, [$($r:literal),+])),+ $(,)?) => {
+    $(impl $t {
+      /// Reverses the order of the lanes.
+      #[inline]
+      #[must_use]
+      pub fn reverse(self) -> Self {
+        crate::shuffle!($t, self, [$($r),+])
+      }
+      #[doc(hidden)]
+      #[inline]
+      #[must_use]
+      pub fn __shuffle(self, idx: [usize; $n]) -> Self {
+        let arr: [$e; $n] = cast(self);
+        let mut out = arr;
+        for (lane, &i) in out.iter_mut().zip(idx.iter()) {
+          *lane = arr[i];
+        }
+        cast(out)
+      }
+    })+
+  };
+}
+
+impl_lane_moves! {
+  f32x4 (f32, 4, [3, 2, 1, 0]),
+  f32x8 (f32, 8, [7, 6, 5, 4, 3, 2, 1, 0]),
+  f64x2 (f64, 2, [1, 0]),
+  f64x4 (f64, 4, [3, 2, 1, 0]),
+  i8x16 (i8, 16, [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]),
+  i16x8 (i16, 8, [7, 6, 5, 4, 3, 2, 1, 0]),
+  i32x4 (i32, 4, [3, 2, 1, 0]),
+  i64x2 (i64, 2, [1, 0]),
+  i8x32 (i8, 32, [
+    31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16,
+    15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0
+  ]),
+  i32x8 (i32, 8, [7, 6, 5, 4, 3, 2, 1, 0]),
+  i64x4 (i64, 4, [3, 2, 1, 0]),
+  u8x16 (u8, 16, [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]),
+  u16x8 (u16, 8, [7, 6, 5, 4, 3, 2, 1, 0]),
+  u32x4 (u32, 4, [3, 2, 1, 0]),
+  u64x2 (u64, 2, [1, 0]),
+  u32x8 (u32, 8, [7, 6, 5, 4, 3, 2, 1, 0]),
+  u64x4 (u64, 4, [3, 2, 1, 0]),
+}
+
+#[doc(hidden)]
+pub mod __private {
+  pub use bytemuck::cast;
+  pub use safe_arch;
+}
+
+/// Picks lanes of a vector by index, so lane `i` of the output is lane
+/// `idx[i]` of the input.
+///
+/// The first argument is the name of the vector type. The indices have to be
+/// constants, and one past the last lane is a compile error.
+///
+/// This is a `shufps` or `pshufd` for the 128-bit types with 32 and 64 bit
+/// lanes, a `pshufb` for 8 and 16 bit lanes (with `ssse3`), and a `vpermd` or
+/// `vpermq` for the 256-bit types (with `avx2`). Otherwise the lanes are moved
+/// as an array.
+///
+/// ```
+/// # use wide::*;
+/// let v = i32x4::from([1, 2, 3, 4]);
+/// assert_eq!(shuffle!(i32x4, v, [3, 3, 0, 1]), i32x4::from([4, 4, 1, 2]));
+/// ```
+///
+/// ```compile_fail
+/// # use wide::*;
+/// let v = i32x4::from([1, 2, 3, 4]);
+/// let _ = shuffle!(i32x4, v, [0, 1, 2, 4]);
+/// ```
+#[macro_export]
+macro_rules! shuffle {
+  ($t:ident, $v:expr, [$($i:expr),+ $(,)?]) => {
+    $crate::__lane_moves!($t shuffle ($v) [$($i),+])
+  };
+}
+
+/// Picks lanes of two vectors by index, where indices below the lane count
+/// pick from the first vector and the rest pick from the second.
+///
+/// The indices have to be constants below twice the lane count. This is a
+/// [`shuffle!`] of each vector and a blend.
+///
+/// ```
+/// # use wide::*;
+/// let a = i32x4::from([1, 2, 3, 4]);
+/// let b = i32x4::from([5, 6, 7, 8]);
+/// assert_eq!(shuffle2!(i32x4, a, b, [0, 4, 7, 2]), i32x4::from([1, 5, 8, 3]));
+/// ```
+#[macro_export]
+macro_rules! shuffle2 {
+  ($t:ident, $a:expr, $b:expr, [$($i:expr),+ $(,)?]) => {
+    $crate::__lane_moves!($t shuffle2 ($a) ($b) [$($i),+])
+  };
+}
+
+/// Rotates the lanes of a vector `n` places toward lane 0, so lane `i` of the
+/// output is lane `(i + n) % lanes` of the input.
+///
+/// `n` has to be a constant no bigger than the lane count. This is a
+/// `palignr` for 8 and 16 bit lanes (with `ssse3`, or two byte shifts with
+/// just `sse2`), and a [`shuffle!`] otherwise.
+///
+/// ```
+/// # use wide::*;
+/// let v = i32x4::from([1, 2, 3, 4]);
+/// assert_eq!(rotate_lanes_left!(i32x4, v, 1), i32x4::from([2, 3, 4, 1]));
+/// ```
+#[macro_export]
+macro_rules! rotate_lanes_left {
+  ($t:ident, $v:expr, $n:expr) => {
+    $crate::__lane_moves!($t rotate_lanes_left ($v) ($n))
+  };
+}
+
+/// Rotates the lanes of a vector `n` places away from lane 0, so lane `i` of
+/// the output is lane `(i - n) % lanes` of the input.
+///
+/// `n` has to be a constant no bigger than the lane count. This is a
+/// [`rotate_lanes_left!`] by the lane count minus `n`.
+///
+/// ```
+/// # use wide::*;
+/// let v = i32x4::from([1, 2, 3, 4]);
+/// assert_eq!(rotate_lanes_right!(i32x4, v, 1), i32x4::from([4, 1, 2, 3]));
+/// ```
+#[macro_export]
+macro_rules! rotate_lanes_right {
+  ($t:ident, $v:expr, $n:expr) => {
+    $crate::__lane_moves!($t rotate_lanes_right ($v) ($n))
+  };
+}
+
+/// Moves the lanes of a vector `n` places toward lane 0, so lane `i` of the
+/// output is lane `i + n` of the input, and the top `n` lanes are filled
+/// with 0.
+///
+/// `n` has to be a constant no bigger than the lane count. This is a `psrldq`
+/// for the 128-bit types (with `sse2`), and a [`shuffle!`] and a mask
+/// otherwise.
+///
+/// ```
+/// # use wide::*;
+/// let v = i32x4::from([1, 2, 3, 4]);
+/// assert_eq!(shift_lanes_left!(i32x4, v, 1), i32x4::from([2, 3, 4, 0]));
+/// ```
+#[macro_export]
+macro_rules! shift_lanes_left {
+  ($t:ident, $v:expr, $n:expr) => {
+    $crate::__lane_moves!($t shift_lanes_left ($v) ($n))
+  };
+}
+
+/// Moves the lanes of a vector `n` places away from lane 0, so lane `i` of the
+/// output is lane `i - n` of the input, and the bottom `n` lanes are filled
+/// with 0.
+///
+/// `n` has to be a constant no bigger than the lane count. This is a `pslldq`
+/// for the 128-bit types (with `sse2`), and a [`shuffle!`] and a mask
+/// otherwise.
+///
+/// ```
+/// # use wide::*;
+/// let v = i32x4::from([1, 2, 3, 4]);
+/// assert_eq!(shift_lanes_right!(i32x4, v, 1), i32x4::from([0, 1, 2, 3]));
+/// ```
+#[macro_export]
+macro_rules! shift_lanes_right {
+  ($t:ident, $v:expr, $n:expr) => {
+    $crate::__lane_moves!($t shift_lanes_right ($v) ($n))
+  };
+}
+
+/// The lane move macros, by type and then by the instructions available.
+///
+/// Each type is given as `[type lanes (lane numbers) kind unsigned_element]`,
+/// where the kind picks the instructions. The range checks are array lengths
+/// of 0 when the check passes and 1 when it fails, so a bad index or count
+/// is a type error.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lane_moves {
+  (f32x4 $($r:tt)*) => {
+    $crate::__lane_moves!(@[f32x4 4 (0 1 2 3) ps u32] $($r)*)
+  };
+  (i32x4 $($r:tt)*) => {
+    $crate::__lane_moves!(@[i32x4 4 (0 1 2 3) d4 u32] $($r)*)
+  };
+  (u32x4 $($r:tt)*) => {
+    $crate::__lane_moves!(@[u32x4 4 (0 1 2 3) d4 u32] $($r)*)
+  };
+  (f64x2 $($r:tt)*) => {
+    $crate::__lane_moves!(@[f64x2 2 (0 1) q2 u64] $($r)*)
+  };
+  (i64x2 $($r:tt)*) => {
+    $crate::__lane_moves!(@[i64x2 2 (0 1) q2 u64] $($r)*)
+  };
+  (u64x2 $($r:tt)*) => {
+    $crate::__lane_moves!(@[u64x2 2 (0 1) q2 u64] $($r)*)
+  };
+  (i16x8 $($r:tt)*) => {
+    $crate::__lane_moves!(@[i16x8 8 (0 1 2 3 4 5 6 7) w8 u16] $($r)*)
+  };
+  (u16x8 $($r:tt)*) => {
+    $crate::__lane_moves!(@[u16x8 8 (0 1 2 3 4 5 6 7) w8 u16] $($r)*)
+  };
+  (i8x16 $($r:tt)*) => {
+    $crate::__lane_moves!(@[i8x16 16
+      (0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15) b16 u8] $($r)*)
+  };
+  (u8x16 $($r:tt)*) => {
+    $crate::__lane_moves!(@[u8x16 16
+      (0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15) b16 u8] $($r)*)
+  };
+  (f32x8 $($r:tt)*) => {
+    $crate::__lane_moves!(@[f32x8 8 (0 1 2 3 4 5 6 7) d8 u32] $($r)*)
+  };
+  (i32x8 $($r:tt)*) => {
+    $crate::__lane_moves!(@[i32x8 8 (0 1 2 3 4 5 6 7) d8 u32] $($r)*)
+  };
+  (u32x8 $($r:tt)*) => {
+    $crate::__lane_moves!(@[u32x8 8 (0 1 2 3 4 5 6 7) d8 u32] $($r)*)
+  };
+  (f64x4 $($r:tt)*) => {
+    $crate::__lane_moves!(@[f64x4 4 (0 1 2 3) q4 u64] $($r)*)
+  };
+  (i64x4 $($r:tt)*) => {
+    $crate::__lane_moves!(@[i64x4 4 (0 1 2 3) q4 u64] $($r)*)
+  };
+  (u64x4 $($r:tt)*) => {
+    $crate::__lane_moves!(@[u64x4 4 (0 1 2 3) q4 u64] $($r)*)
+  };
+  (i8x32 $($r:tt)*) => {
+    $crate::__lane_moves!(@[i8x32 32
+      (0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+       16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31) b32 u8] $($r)*)
+  };
+
+  (@[$t:ident $n:tt $l:tt $k:ident $u:ident]
+    shuffle ($v:expr) [$($i:expr),+]) => {{
+    const _: [(); 0] = [(); 0 $(+ ((($i) as usize) >= $n) as usize)+];
+    let v: $crate::$t = $v;
+    $crate::__lane_moves!(@shuffle $k $t v [$((($i) as usize)),+])
+  }};
+  (@[$t:ident $n:tt $l:tt $k:ident $u:ident]
+    shuffle2 ($a:expr) ($b:expr) [$($i:expr),+]) => {{
+    const _: [(); 0] = [(); 0 $(+ ((($i) as usize) >= 2 * $n) as usize)+];
+    const M: [$u; $n] = [$(((($i as usize) >= $n) as $u).wrapping_neg()),+];
+    let a: $crate::$t = $a;
+    let b: $crate::$t = $b;
+    let a = $crate::__lane_moves!(@shuffle $k $t a [$((($i) as usize) % $n),+]);
+    let b = $crate::__lane_moves!(@shuffle $k $t b [$((($i) as usize) % $n),+]);
+    $crate::__private::cast::<_, $crate::$t>(M).blend(b, a)
+  }};
+  (@[$t:ident $n:tt $l:tt $k:ident $u:ident]
+    rotate_lanes_left ($v:expr) ($c:expr)) => {{
+    const _: [(); 0] = [(); ((($c) as usize) > $n) as usize];
+    let v: $crate::$t = $v;
+    $crate::__lane_moves!(@rotate $k [$t $n $l $k $u] v (($c) as usize))
+  }};
+  (@[$t:ident $n:tt $l:tt $k:ident $u:ident]
+    rotate_lanes_right ($v:expr) ($c:expr)) => {{
+    const _: [(); 0] = [(); ((($c) as usize) > $n) as usize];
+    let v: $crate::$t = $v;
+    $crate::__lane_moves!(@rotate $k [$t $n $l $k $u] v ($n - (($c) as usize)))
+  }};
+  (@[$t:ident $n:tt $l:tt $k:ident $u:ident]
+    shift_lanes_left ($v:expr) ($c:expr)) => {{
+    const _: [(); 0] = [(); ((($c) as usize) > $n) as usize];
+    let v: $crate::$t = $v;
+    $crate::__lane_moves!(@shift $k [$t $n $l $k $u] v (($c) as usize)
+      byte_shr_imm_u128_m128i left)
+  }};
+  (@[$t:ident $n:tt $l:tt $k:ident $u:ident]
+    shift_lanes_right ($v:expr) ($c:expr)) => {{
+    const _: [(); 0] = [(); ((($c) as usize) > $n) as usize];
+    let v: $crate::$t = $v;
+    $crate::__lane_moves!(@shift $k [$t $n $l $k $u] v (($c) as usize)
+      byte_shl_imm_u128_m128i right)
+  }};
+
+  (@shuffle ps $t:ident $v:ident [$a:expr, $b:expr, $c:expr, $d:expr]) => {{
+    #[cfg(target_feature = "sse")]
+    let r = {
+      let x: $crate::__private::safe_arch::m128 = $crate::__private::cast($v);
+      $crate::__private::cast::<_, $crate::$t>(
+        $crate::__private::safe_arch::shuffle_abi_f32_all_m128!(
+          x, x, [a:$a, a:$b, b:$c, b:$d]
+        ),
+      )
+    };
+    #[cfg(not(target_feature = "sse"))]
+    let r = $v.__shuffle([$a, $b, $c, $d]);
+    r
+  }};
+  (@shuffle d4 $t:ident $v:ident [$a:expr, $b:expr, $c:expr, $d:expr]) => {{
+    #[cfg(target_feature = "sse2")]
+    let r = {
+      let x: $crate::__private::safe_arch::m128i = $crate::__private::cast($v);
+      $crate::__private::cast::<_, $crate::$t>(
+        $crate::__private::safe_arch::shuffle_ai_f32_all_m128i!(
+          x, [$a, $b, $c, $d]
+        ),
+      )
+    };
+    #[cfg(not(target_feature = "sse2"))]
+    let r = $v.__shuffle([$a, $b, $c, $d]);
+    r
+  }};
+  (@shuffle q2 $t:ident $v:ident [$a:expr, $b:expr]) => {{
+    #[cfg(target_feature = "sse2")]
+    let r = {
+      let x: $crate::__private::safe_arch::m128i = $crate::__private::cast($v);
+      $crate::__private::cast::<_, $crate::$t>(
+        $crate::__private::safe_arch::shuffle_ai_f32_all_m128i!(
+          x, [2 * $a, 2 * $a + 1, 2 * $b, 2 * $b + 1]
+        ),
+      )
+    };
+    #[cfg(not(target_feature = "sse2"))]
+    let r = $v.__shuffle([$a, $b]);
+    r
+  }};
+  (@shuffle w8 $t:ident $v:ident [$($i:expr),+]) => {{
+    #[cfg(target_feature = "ssse3")]
+    let r = {
+      const BYTES: [u8; 16] = [$(($i * 2) as u8, ($i * 2 + 1) as u8),+];
+      let x: $crate::__private::safe_arch::m128i = $crate::__private::cast($v);
+      $crate::__private::cast::<_, $crate::$t>(
+        $crate::__private::safe_arch::shuffle_av_i8z_all_m128i(
+          x,
+          $crate::__private::cast(BYTES),
+        ),
+      )
+    };
+    #[cfg(not(target_feature = "ssse3"))]
+    let r = $v.__shuffle([$($i),+]);
+    r
+  }};
+  (@shuffle b16 $t:ident $v:ident [$($i:expr),+]) => {{
+    #[cfg(target_feature = "ssse3")]
+    let r = {
+      const BYTES: [u8; 16] = [$($i as u8),+];
+      let x: $crate::__private::safe_arch::m128i = $crate::__private::cast($v);
+      $crate::__private::cast::<_, $crate::$t>(
+        $crate::__private::safe_arch::shuffle_av_i8z_all_m128i(
+          x,
+          $crate::__private::cast(BYTES),
+        ),
+      )
+    };
+    #[cfg(not(target_feature = "ssse3"))]
+    let r = $v.__shuffle([$($i),+]);
+    r
+  }};
+  (@shuffle d8 $t:ident $v:ident [$($i:expr),+]) => {{
+    #[cfg(target_feature = "avx2")]
+    let r = {
+      const LANES: [u32; 8] = [$($i as u32),+];
+      let x: $crate::__private::safe_arch::m256i = $crate::__private::cast($v);
+      $crate::__private::cast::<_, $crate::$t>(
+        $crate::__private::safe_arch::shuffle_av_i32_all_m256i(
+          x,
+          $crate::__private::cast(LANES),
+        ),
+      )
+    };
+    #[cfg(not(target_feature = "avx2"))]
+    let r = $v.__shuffle([$($i),+]);
+    r
+  }};
+  (@shuffle q4 $t:ident $v:ident [$a:expr, $b:expr, $c:expr, $d:expr]) => {{
+    #[cfg(target_feature = "avx2")]
+    let r = {
+      let x: $crate::__private::safe_arch::m256i = $crate::__private::cast($v);
+      $crate::__private::cast::<_, $crate::$t>(
+        $crate::__private::safe_arch::shuffle_ai_i64_all_m256i!(
+          x, [$a as i32, $b as i32, $c as i32, $d as i32]
+        ),
+      )
+    };
+    #[cfg(not(target_feature = "avx2"))]
+    let r = $v.__shuffle([$a, $b, $c, $d]);
+    r
+  }};
+  (@shuffle b32 $t:ident $v:ident [$($i:expr),+]) => {
+    $v.__shuffle([$($i),+])
+  };
+
+  (@rotate w8 $info:tt $v:ident ($c:expr)) => {
+    $crate::__lane_moves!(@rotate_bytes $info $v ($c))
+  };
+  (@rotate b16 $info:tt $v:ident ($c:expr)) => {
+    $crate::__lane_moves!(@rotate_bytes $info $v ($c))
+  };
+  (@rotate $k:ident [$t:ident $n:tt ($($l:tt)+) $k2:ident $u:ident]
+    $v:ident ($c:expr)) => {
+    $crate::__lane_moves!(@shuffle $k $t $v [$(($l + $c) % $n),+])
+  };
+  (@rotate_bytes [$t:ident $n:tt ($($l:tt)+) $k:ident $u:ident]
+    $v:ident ($c:expr)) => {{
+    #[cfg(target_feature = "ssse3")]
+    let r = {
+      let x: $crate::__private::safe_arch::m128i = $crate::__private::cast($v);
+      $crate::__private::cast::<_, $crate::$t>(
+        $crate::__private::safe_arch::combined_byte_shr_imm_m128i!(
+          x, x, $c * (16 / $n)
+        ),
+      )
+    };
+    #[cfg(all(target_feature = "sse2", not(target_feature = "ssse3")))]
+    let r = {
+      let x: $crate::__private::safe_arch::m128i = $crate::__private::cast($v);
+      $crate::__private::cast::<_, $crate::$t>(
+        $crate::__private::safe_arch::bitor_m128i(
+          $crate::__private::safe_arch::byte_shr_imm_u128_m128i!(
+            x, $c * (16 / $n)
+          ),
+          $crate::__private::safe_arch::byte_shl_imm_u128_m128i!(
+            x, 16 - $c * (16 / $n)
+          ),
+        ),
+      )
+    };
+    #[cfg(not(target_feature = "sse2"))]
+    let r = $v.__shuffle([$(($l + $c) % $n),+]);
+    r
+  }};
+
+  (@shift d8 $info:tt $v:ident ($c:expr) $b:ident $dir:ident) => {
+    $crate::__lane_moves!(@shift_masked $info $v ($c) $dir)
+  };
+  (@shift q4 $info:tt $v:ident ($c:expr) $b:ident $dir:ident) => {
+    $crate::__lane_moves!(@shift_masked $info $v ($c) $dir)
+  };
+  (@shift b32 $info:tt $v:ident ($c:expr) $b:ident $dir:ident) => {
+    $crate::__lane_moves!(@shift_masked $info $v ($c) $dir)
+  };
+  (@shift $k:ident [$t:ident $n:tt $l:tt $k2:ident $u:ident]
+    $v:ident ($c:expr) $b:ident $dir:ident) => {{
+    #[cfg(target_feature = "sse2")]
+    let r = {
+      let x: $crate::__private::safe_arch::m128i = $crate::__private::cast($v);
+      $crate::__private::cast::<_, $crate::$t>(
+        $crate::__private::safe_arch::$b!(x, $c * (16 / $n)),
+      )
+    };
+    #[cfg(not(target_feature = "sse2"))]
+    let r = $crate::__lane_moves!(@shift_masked [$t $n $l $k $u] $v ($c) $dir);
+    r
+  }};
+  (@shift_masked [$t:ident $n:tt ($($l:tt)+) $k:ident $u:ident]
+    $v:ident ($c:expr) left) => {{
+    const M: [$u; $n] = [$((($l + $c < $n) as $u).wrapping_neg()),+];
+    $crate::__lane_moves!(@shuffle $k $t $v [$(($l + $c) % $n),+])
+      & $crate::__private::cast::<_, $crate::$t>(M)
+  }};
+  (@shift_masked [$t:ident $n:tt ($($l:tt)+) $k:ident $u:ident]
+    $v:ident ($c:expr) right) => {{
+    const M: [$u; $n] = [$((($l >= $c) as $u).wrapping_neg()),+];
+    $crate::__lane_moves!(@shuffle $k $t $v [$(($l + $n - $c) % $n),+])
+      & $crate::__private::cast::<_, $crate::$t>(M)
+  }};
+}
+
+/// Adds interleaving and deinterleaving of two vectors to a type, given as
+/// `(element, lanes) [feature unpack_low unpack_high]`, and for the 256-bit
+/// types the macro that permutes their 128-bit halves.
+///
+/// The zips are the unpack instructions. On 256-bit types those only unpack
+/// within each 128-bit half, so the two results are then put back together
+/// with a `vperm2f128` or `vperm2i128`.
+///
+/// The unzips are picked by the last ident: `ps` and `pd` are a `shufps` or
+/// `shufpd` of the two inputs, `epi16` shifts each wanted lane into the low
+/// half of its `i32` and then packs, and `epi8` does the same with a mask
+/// and `packuswb`. The 256-bit versions first gather the low and the high
+/// halves of both inputs with a `vperm2f128`, since the shuffles and packs
+/// also stay within each 128-bit half.
+macro_rules! impl_interleave {
+  ($($t:ident ($e:ty, $n:literal) $regs:tt $k:ident),+ $(,)?) => {
+    $(impl $t {
+      impl_interleave!(@zips $e, $n, $regs);
+      impl_interleave!(@unzips $e, $n, $regs, $k);
+    })+
+  };
+  (@zips $e:ty, $n:literal, [$f:literal $lo:ident $hi:ident]) => {
+    /// Interleaves the low halves of `self` and `other`, giving
+    /// `[self[0], other[0], self[1], other[1], ..]`.
+    #[inline]
+    #[must_use]
+    pub fn zip_lo(self, other: Self) -> Self {
+      pick! {
+        if #[cfg(target_feature=$f)] {
+          cast($lo(cast(self), cast(other)))
+        } else {
+          impl_interleave!(@zip $e, $n, self, other, 0)
+        }
+      }
+    }
+    /// Interleaves the high halves of `self` and `other`, giving
+    /// `[self[n/2], other[n/2], self[n/2 + 1], other[n/2 + 1], ..]`.
+    #[inline]
+    #[must_use]
+    pub fn zip_hi(self, other: Self) -> Self {
+      pick! {
+        if #[cfg(target_feature=$f)] {
+          cast($hi(cast(self), cast(other)))
+        } else {
+          impl_interleave!(@zip $e, $n, self, other, $n / 2)
+        }
+      }
+    }
+  };
+  (@zips $e:ty, $n:literal, [$f:literal $lo:ident $hi:ident $perm:ident]) => {
+    /// Interleaves the low halves of `self` and `other`, giving
+    /// `[self[0], other[0], self[1], other[1], ..]`.
+    #[inline]
+    #[must_use]
+    pub fn zip_lo(self, other: Self) -> Self {
+      pick! {
+        if #[cfg(target_feature=$f)] {
+          let (a, b) = (cast(self), cast(other));
+          cast($perm!($lo(a, b), $hi(a, b), [A_Low, B_Low]))
+        } else {
+          impl_interleave!(@zip $e, $n, self, other, 0)
+        }
+      }
+    }
+    /// Interleaves the high halves of `self` and `other`, giving
+    /// `[self[n/2], other[n/2], self[n/2 + 1], other[n/2 + 1], ..]`.
+    #[inline]
+    #[must_use]
+    pub fn zip_hi(self, other: Self) -> Self {
+      pick! {
+        if #[cfg(target_feature=$f)] {
+          let (a, b) = (cast(self), cast(other));
+          cast($perm!($lo(a, b), $hi(a, b), [A_High, B_High]))
+        } else {
+          impl_interleave!(@zip $e, $n, self, other, $n / 2)
+        }
+      }
+    }
+  };
+  (@unzips $e:ty, $n:literal, [$f:literal $($regs:ident)+], $k:ident) => {
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    ///
+    /// This undoes `zip_lo` and `zip_hi`, so
+    /// `a.zip_lo(b).unzip_even(a.zip_hi(b))` is `a`.
+    #[inline]
+    #[must_use]
+    pub fn unzip_even(self, other: Self) -> Self {
+      pick! {
+        if #[cfg(target_feature=$f)] {
+          cast(impl_interleave!(@$k even, cast(self), cast(other)))
+        } else {
+          impl_interleave!(@unzip $e, $n, self, other, 0)
+        }
+      }
+    }
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    #[must_use]
+    pub fn unzip_odd(self, other: Self) -> Self {
+      pick! {
+        if #[cfg(target_feature=$f)] {
+          cast(impl_interleave!(@$k odd, cast(self), cast(other)))
+        } else {
+          impl_interleave!(@unzip $e, $n, self, other, 1)
+        }
+      }
+    }
+  };
+  (@ps even, $a:expr, $b:expr) => {
+    shuffle_abi_f32_all_m128!($a, $b, [a:0, a:2, b:0, b:2])
+  };
+  (@ps odd, $a:expr, $b:expr) => {
+    shuffle_abi_f32_all_m128!($a, $b, [a:1, a:3, b:1, b:3])
+  };
+  (@pd even, $a:expr, $b:expr) => {
+    shuffle_abi_f64_all_m128d!($a, $b, [a:0, b:0])
+  };
+  (@pd odd, $a:expr, $b:expr) => {
+    shuffle_abi_f64_all_m128d!($a, $b, [a:1, b:1])
+  };
+  (@epi16 even, $a:expr, $b:expr) => {{
+    let (a, b): (m128i, m128i) = ($a, $b);
+    pack_i32_to_i16_m128i(
+      shr_imm_i32_m128i!(shl_imm_u32_m128i!(a, 16), 16),
+      shr_imm_i32_m128i!(shl_imm_u32_m128i!(b, 16), 16),
+    )
+  }};
+  (@epi16 odd, $a:expr, $b:expr) => {
+    pack_i32_to_i16_m128i(
+      shr_imm_i32_m128i!($a, 16),
+      shr_imm_i32_m128i!($b, 16),
+    )
+  };
+  (@epi8 even, $a:expr, $b:expr) => {{
+    let mask = set_splat_i16_m128i(0xFF);
+    pack_i16_to_u8_m128i(bitand_m128i($a, mask), bitand_m128i($b, mask))
+  }};
+  (@epi8 odd, $a:expr, $b:expr) => {
+    pack_i16_to_u8_m128i(shr_imm_u16_m128i!($a, 8), shr_imm_u16_m128i!($b, 8))
+  };
+  (@ps256 even, $a:expr, $b:expr) => {{
+    let (a, b): (m256, m256) = ($a, $b);
+    let lo = shuffle_abi_f128z_all_m256!(a, b, [A_Low, B_Low]);
+    let hi = shuffle_abi_f128z_all_m256!(a, b, [A_High, B_High]);
+    shuffle_abi_f32_half_m256!(lo, hi, [a:0, a:2, b:0, b:2])
+  }};
+  (@ps256 odd, $a:expr, $b:expr) => {{
+    let (a, b): (m256, m256) = ($a, $b);
+    let lo = shuffle_abi_f128z_all_m256!(a, b, [A_Low, B_Low]);
+    let hi = shuffle_abi_f128z_all_m256!(a, b, [A_High, B_High]);
+    shuffle_abi_f32_half_m256!(lo, hi, [a:1, a:3, b:1, b:3])
+  }};
+  (@pd256 even, $a:expr, $b:expr) => {{
+    let (a, b): (m256d, m256d) = ($a, $b);
+    let lo = shuffle_abi_f128z_all_m256d!(a, b, [A_Low, B_Low]);
+    let hi = shuffle_abi_f128z_all_m256d!(a, b, [A_High, B_High]);
+    unpack_lo_m256d(lo, hi)
+  }};
+  (@pd256 odd, $a:expr, $b:expr) => {{
+    let (a, b): (m256d, m256d) = ($a, $b);
+    let lo = shuffle_abi_f128z_all_m256d!(a, b, [A_Low, B_Low]);
+    let hi = shuffle_abi_f128z_all_m256d!(a, b, [A_High, B_High]);
+    unpack_hi_m256d(lo, hi)
+  }};
+  (@epi8_256 even, $a:expr, $b:expr) => {{
+    let mask = set_splat_i16_m256i(0xFF);
+    let packed =
+      pack_i16_to_u8_m256i(bitand_m256i($a, mask), bitand_m256i($b, mask));
+    shuffle_ai_i64_all_m256i!(packed, [0, 2, 1, 3])
+  }};
+  (@epi8_256 odd, $a:expr, $b:expr) => {{
+    let packed = pack_i16_to_u8_m256i(
+      shr_imm_u16_m256i!($a, 8),
+      shr_imm_u16_m256i!($b, 8),
+    );
+    shuffle_ai_i64_all_m256i!(packed, [0, 2, 1, 3])
+  }};
+  (@zip $e:ty, $n:literal, $a:expr, $b:expr, $start:expr) => {{
+    let a: [$e; $n] = cast($a);
+    let b: [$e; $n] = cast($b);
+    let mut out = a;
+    for (i, pair) in out.chunks_exact_mut(2).enumerate() {
+      pair[0] = a[$start + i];
+      pair[1] = b[$start + i];
+    }
+    cast(out)
+  }};
+  (@unzip $e:ty, $n:literal, $a:expr, $b:expr, $odd:expr) => {{
+    // each half of the output is one or the other lanes of one input
+    let both: [[$e; $n]; 2] = [cast($a), cast($b)];
+    let mut out = both[0];
+    for (half, src) in out.chunks_exact_mut($n / 2).zip(both.iter()) {
+      for (lane, pair) in half.iter_mut().zip(src.chunks_exact(2)) {
+        *lane = pair[$odd];
+      }
+    }
+    cast(out)
+  }};
+}
+
+impl_interleave! {
+  f32x4 (f32, 4) ["sse" unpack_low_m128 unpack_high_m128] ps,
+  f64x2 (f64, 2) ["sse2" unpack_low_m128d unpack_high_m128d] pd,
+  i8x16 (i8, 16) ["sse2" unpack_low_i8_m128i unpack_high_i8_m128i] epi8,
+  i16x8 (i16, 8) ["sse2" unpack_low_i16_m128i unpack_high_i16_m128i] epi16,
+  i32x4 (i32, 4) ["sse2" unpack_low_i32_m128i unpack_high_i32_m128i] ps,
+  i64x2 (i64, 2) ["sse2" unpack_low_i64_m128i unpack_high_i64_m128i] pd,
+  u8x16 (u8, 16) ["sse2" unpack_low_i8_m128i unpack_high_i8_m128i] epi8,
+  u16x8 (u16, 8) ["sse2" unpack_low_i16_m128i unpack_high_i16_m128i] epi16,
+  u32x4 (u32, 4) ["sse2" unpack_low_i32_m128i unpack_high_i32_m128i] ps,
+  u64x2 (u64, 2) ["sse2" unpack_low_i64_m128i unpack_high_i64_m128i] pd,
+  f32x8 (f32, 8)
+    ["avx" unpack_lo_m256 unpack_hi_m256 shuffle_abi_f128z_all_m256] ps256,
+  f64x4 (f64, 4)
+    ["avx" unpack_lo_m256d unpack_hi_m256d shuffle_abi_f128z_all_m256d] pd256,
+  i8x32 (i8, 32) ["avx2" unpack_low_i8_m256i unpack_high_i8_m256i
+    shuffle_abi_i128z_all_m256i] epi8_256,
+  i32x8 (i32, 8) ["avx2" unpack_low_i32_m256i unpack_high_i32_m256i
+    shuffle_abi_i128z_all_m256i] ps256,
+  i64x4 (i64, 4) ["avx2" unpack_low_i64_m256i unpack_high_i64_m256i
+    shuffle_abi_i128z_all_m256i] pd256,
+  u32x8 (u32, 8) ["avx2" unpack_low_i32_m256i unpack_high_i32_m256i
+    shuffle_abi_i128z_all_m256i] ps256,
+  u64x4 (u64, 4) ["avx2" unpack_low_i64_m256i unpack_high_i64_m256i
+    shuffle_abi_i128z_all_m256i] pd256,
+}
+
+/// Adds splitting into and joining from 128-bit halves to a 256-bit type,
+/// given as `wide (half) [feature zero_extend extract insert]`.
+///
+/// This is a `vextractf128` and `vinsertf128` with AVX, or a `vextracti128`
+/// and `vinserti128` with AVX2 for the integer types, and just picks a field
+/// without them.
+macro_rules! impl_halves {
+  ($($t:ident ($h:ident)
+    [$f:literal $zext:ident $ext:ident $ins:ident]),+ $(,)?) => {
+    $(impl $t {
+      /// The low half of the lanes.
+      #[inline]
+      #[must_use]
+      pub fn low(self) -> $h {
+        pick! {
+          if #[cfg(target_feature=$f)] {
+            cast($ext!(cast(self), 0))
+          } else {
+            let [lo, _]: [$h; 2] = cast(self);
+            lo
+          }
+        }
+      }
+      /// The high half of the lanes.
+      #[inline]
+      #[must_use]
+      pub fn high(self) -> $h {
+        pick! {
+          if #[cfg(target_feature=$f)] {
+            cast($ext!(cast(self), 1))
+          } else {
+            let [_, hi]: [$h; 2] = cast(self);
+            hi
+          }
+        }
+      }
+      /// Joins two halves, with `lo` in the low lanes and `hi` in the high
+      /// lanes.
+      #[inline]
+      #[must_use]
+      pub fn from_halves(lo: $h, hi: $h) -> Self {
+        pick! {
+          if #[cfg(target_feature=$f)] {
+            cast($ins!($zext(cast(lo)), cast(hi), 1))
+          } else {
+            cast([lo, hi])
+          }
+        }
+      }
+      /// The low and high halves of the lanes.
+      #[inline]
+      #[must_use]
+      pub fn split(self) -> ($h, $h) {
+        (self.low(), self.high())
+      }
+    })+
+  };
+}
+
+impl_halves! {
+  f32x8 (f32x4)
+    ["avx" zero_extend_m128 extract_m128_from_m256 insert_m128_to_m256],
+  f64x4 (f64x2)
+    ["avx" zero_extend_m128d extract_m128d_from_m256d insert_m128d_to_m256d],
+  i8x32 (i8x16)
+    ["avx2" zero_extend_m128i extract_m128i_m256i insert_m128i_to_m256i],
+  i32x8 (i32x4)
+    ["avx2" zero_extend_m128i extract_m128i_m256i insert_m128i_to_m256i],
+  i64x4 (i64x2)
+    ["avx2" zero_extend_m128i extract_m128i_m256i insert_m128i_to_m256i],
+  u32x8 (u32x4)
+    ["avx2" zero_extend_m128i extract_m128i_m256i insert_m128i_to_m256i],
+  u64x4 (u64x2)
+    ["avx2" zero_extend_m128i extract_m128i_m256i insert_m128i_to_m256i],
 }
 
 /// impls `From<a> for b` by just calling `cast`
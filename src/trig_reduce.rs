@@ -0,0 +1,98 @@
+//! Payne-Hanek argument reduction, for the lanes that are too big for the
+//! Cody-Waite reduction in `sin_cos`.
+//!
+//! This works one lane at a time, but it only runs for huge arguments.
+
+/// The bits of `2/pi` after the binary point, most significant first.
+const TWO_OVER_PI: [u64; 19] = [
+  0xA2F9_836E_4E44_1529,
+  0xFC27_57D1_F534_DDC0,
+  0xDB62_9599_3C43_9041,
+  0xFE51_63AB_DEBB_C561,
+  0xB724_6E3A_424D_D2E0,
+  0x0649_2EEA_09D1_921C,
+  0xFE1D_EB1C_B129_A73E,
+  0xE882_35F5_2EBB_4484,
+  0xE99C_7026_B45F_7E41,
+  0x3991_D639_8353_39F4,
+  0x9C84_5F8B_BDF9_283B,
+  0x1FF8_97FF_DE05_980F,
+  0xEF2F_118B_5A0A_6D1F,
+  0x6D36_7ECF_27CB_09B7,
+  0x4F46_3F66_9E5F_EA2D,
+  0x7527_BAC7_EBE5_F17B,
+  0x3D07_39F7_8A52_92EA,
+  0x6BFB_5FB1_1F8D_5D08,
+  0x5603_3046_FC7B_6BAB,
+];
+
+const PIO2_HI: f64 = core::f64::consts::FRAC_PI_2;
+const PIO2_LO: f64 = 6.123233995736766e-17;
+
+/// 64 bits of `2/pi` starting at bit `start`, where bit 1 is the first one
+/// after the binary point. Bits before the point are all 0.
+#[inline]
+fn two_over_pi_bits(start: i32) -> u64 {
+  let i = start - 1;
+  if i <= -64 {
+    0
+  } else if i < 0 {
+    TWO_OVER_PI[0] >> -i
+  } else {
+    let (w, b) = ((i / 64) as usize, (i % 64) as u32);
+    if b == 0 {
+      TWO_OVER_PI[w]
+    } else {
+      (TWO_OVER_PI[w] << b) | (TWO_OVER_PI[w + 1] >> (64 - b))
+    }
+  }
+}
+
+/// Splits `a` into two halves whose products are exact (Veltkamp).
+#[inline]
+fn split(a: f64) -> (f64, f64) {
+  let t = a * 134217729.0;
+  let hi = t - (t - a);
+  (hi, a - hi)
+}
+
+/// Reduces a finite `x >= 0` to `r + q * pi/2` with `|r| <= pi/4`, giving
+/// back `r` and `q % 4`.
+///
+/// `x = m * 2^e` for an integer `m`, and bits of `2/pi` worth 4 or more
+/// once multiplied by `2^e` can't change the answer, so only a 192 bit
+/// window of `2/pi` is needed whatever the size of `x`.
+pub(crate) fn rem_pio2(x: f64) -> (f64, i64) {
+  let bits = x.to_bits();
+  let biased = ((bits >> 52) & 0x7FF) as i32;
+  let m = (bits & 0xF_FFFF_FFFF_FFFF) | if biased == 0 { 0 } else { 1 << 52 };
+  let e = biased.max(1) - 1075;
+
+  // m * window * 2^-190 = x * 2/pi, less a multiple of 4
+  let m = m as u128;
+  let a0 = m * two_over_pi_bits(e - 1) as u128;
+  let a1 = m * two_over_pi_bits(e + 63) as u128;
+  let a2 = m * two_over_pi_bits(e + 127) as u128;
+  let lo = a2 as u64;
+  let t1 = a1 + (a2 >> 64);
+  let mid = t1 as u64;
+  let t0 = a0 + (t1 >> 64);
+
+  // the integer part of x * 2/pi, and 128 bits of fraction
+  let mut q = ((t0 >> 62) & 3) as i64;
+  let f = (t0 << 66) | ((mid as u128) << 2) | (lo >> 62) as u128;
+  // round to the nearest quadrant, which makes the fraction negative
+  q = (q + (f >> 127) as i64) & 3;
+  let f = f as i128;
+
+  // r = f * 2^-128 * pi/2, in double-double so that nothing's lost
+  let f_hi = f as f64;
+  let f_lo = f.wrapping_sub(f_hi as i128) as f64;
+  const SCALE: f64 = 2.938735877055719e-39;
+  let (f_hi, f_lo) = (f_hi * SCALE, f_lo * SCALE);
+  let p = f_hi * PIO2_HI;
+  let (ah, al) = split(f_hi);
+  let (bh, bl) = split(PIO2_HI);
+  let err = ((ah * bh - p) + ah * bl + al * bh) + al * bl;
+  (p + (err + f_hi * PIO2_LO + f_lo * PIO2_HI), q)
+}
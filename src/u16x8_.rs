@@ -63,6 +63,114 @@ impl Sub for u16x8 {
   }
 }
 
+impl Mul for u16x8 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: mul_i16_keep_low_m128i(self.sse, rhs.sse) }
+      } else {
+        let arr: [u16; 8] = cast(self);
+        let rhs: [u16; 8] = cast(rhs);
+        cast([
+          arr[0].wrapping_mul(rhs[0]),
+          arr[1].wrapping_mul(rhs[1]),
+          arr[2].wrapping_mul(rhs[2]),
+          arr[3].wrapping_mul(rhs[3]),
+          arr[4].wrapping_mul(rhs[4]),
+          arr[5].wrapping_mul(rhs[5]),
+          arr[6].wrapping_mul(rhs[6]),
+          arr[7].wrapping_mul(rhs[7]),
+        ])
+      }
+    }
+  }
+}
+
+impl Mul<u16> for u16x8 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: u16) -> Self::Output {
+    self.mul(Self::splat(rhs))
+  }
+}
+
+impl Mul<u16x8> for u16 {
+  type Output = u16x8;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: u16x8) -> Self::Output {
+    u16x8::splat(self) * rhs
+  }
+}
+
+impl Div for u16x8 {
+  type Output = Self;
+  /// Divides lane by lane, which is done one lane at a time because there's no
+  /// SIMD integer divide. Panics if any lane of `rhs` is 0.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: Self) -> Self::Output {
+    let arr: [u16; 8] = cast(self);
+    let rhs: [u16; 8] = cast(rhs);
+    cast([
+      arr[0].div(rhs[0]),
+      arr[1].div(rhs[1]),
+      arr[2].div(rhs[2]),
+      arr[3].div(rhs[3]),
+      arr[4].div(rhs[4]),
+      arr[5].div(rhs[5]),
+      arr[6].div(rhs[6]),
+      arr[7].div(rhs[7]),
+    ])
+  }
+}
+
+impl Rem for u16x8 {
+  type Output = Self;
+  /// Takes the remainder lane by lane. Panics if any lane of `rhs` is 0.
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: Self) -> Self::Output {
+    let arr: [u16; 8] = cast(self);
+    let rhs: [u16; 8] = cast(rhs);
+    cast([
+      arr[0].rem(rhs[0]),
+      arr[1].rem(rhs[1]),
+      arr[2].rem(rhs[2]),
+      arr[3].rem(rhs[3]),
+      arr[4].rem(rhs[4]),
+      arr[5].rem(rhs[5]),
+      arr[6].rem(rhs[6]),
+      arr[7].rem(rhs[7]),
+    ])
+  }
+}
+
+impl Div<u16> for u16x8 {
+  type Output = Self;
+  /// Divides every lane by `rhs` through a [`Divisor`]. If you divide by the
+  /// same value more than once, make the `Divisor` yourself and reuse it.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: u16) -> Self::Output {
+    self / Divisor::<Self>::new(rhs)
+  }
+}
+
+impl Rem<u16> for u16x8 {
+  type Output = Self;
+  /// Takes the remainder of every lane by `rhs` through a [`Divisor`].
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: u16) -> Self::Output {
+    self % Divisor::<Self>::new(rhs)
+  }
+}
+
 impl BitAnd for u16x8 {
   type Output = Self;
   #[inline]
@@ -199,10 +307,11 @@ macro_rules! impl_shr_t_for_u16x8 {
 }
 impl_shr_t_for_u16x8!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
 
-impl u16x8 {
+impl CmpEq for u16x8 {
+  type Output = Self;
   #[inline]
   #[must_use]
-  pub fn cmp_eq(self, rhs: Self) -> Self {
+  fn cmp_eq(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
         Self { sse: cmp_eq_mask_i16_m128i(self.sse, rhs.sse) }
@@ -220,6 +329,118 @@ impl u16x8 {
       }
     }
   }
+}
+
+impl CmpGt for u16x8 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_gt(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // there's no unsigned compare, so flip the sign bits and compare signed
+        let bias = Self::splat(0x8000);
+        Self { sse: cmp_gt_mask_i16_m128i((self ^ bias).sse, (rhs ^ bias).sse) }
+      } else {
+        Self { arr: [
+          if self.arr[0] > rhs.arr[0] { u16::MAX } else { 0 },
+          if self.arr[1] > rhs.arr[1] { u16::MAX } else { 0 },
+          if self.arr[2] > rhs.arr[2] { u16::MAX } else { 0 },
+          if self.arr[3] > rhs.arr[3] { u16::MAX } else { 0 },
+          if self.arr[4] > rhs.arr[4] { u16::MAX } else { 0 },
+          if self.arr[5] > rhs.arr[5] { u16::MAX } else { 0 },
+          if self.arr[6] > rhs.arr[6] { u16::MAX } else { 0 },
+          if self.arr[7] > rhs.arr[7] { u16::MAX } else { 0 },
+        ]}
+      }
+    }
+  }
+}
+
+impl CmpLt for u16x8 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_lt(self, rhs: Self) -> Self::Output {
+    rhs.cmp_gt(self)
+  }
+}
+
+impl CmpGe for u16x8 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_ge(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        Self { sse: cmp_eq_mask_i16_m128i(max_u16_m128i(self.sse, rhs.sse), self.sse) }
+      } else {
+        !self.cmp_lt(rhs)
+      }
+    }
+  }
+}
+
+impl CmpLe for u16x8 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_le(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        Self { sse: cmp_eq_mask_i16_m128i(min_u16_m128i(self.sse, rhs.sse), self.sse) }
+      } else {
+        !self.cmp_gt(rhs)
+      }
+    }
+  }
+}
+
+impl CmpNe for u16x8 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_ne(self, rhs: Self) -> Self::Output {
+    !self.cmp_eq(rhs)
+  }
+}
+
+impl u16x8 {
+  #[inline]
+  #[must_use]
+  pub fn move_mask(self) -> i32 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        move_mask_i8_m128i(pack_i16_to_i8_m128i(self.sse, zeroed_m128i()))
+      }
+      else {
+        ((self.arr[0] >> 15) as i32) << 0 |
+        ((self.arr[1] >> 15) as i32) << 1 |
+        ((self.arr[2] >> 15) as i32) << 2 |
+        ((self.arr[3] >> 15) as i32) << 3 |
+        ((self.arr[4] >> 15) as i32) << 4 |
+        ((self.arr[5] >> 15) as i32) << 5 |
+        ((self.arr[6] >> 15) as i32) << 6 |
+        ((self.arr[7] >> 15) as i32) << 7
+      }
+    }
+  }
+  #[inline]
+  #[must_use]
+  pub fn any(self) -> bool {
+    self.move_mask() != 0
+  }
+  #[inline]
+  #[must_use]
+  pub fn all(self) -> bool {
+    // eight lanes
+    self.move_mask() == 0b11111111
+  }
+  #[inline]
+  #[must_use]
+  pub fn none(self) -> bool {
+    !self.any()
+  }
   #[inline]
   #[must_use]
   pub fn blend(self, t: Self, f: Self) -> Self {
@@ -236,7 +457,7 @@ impl u16x8 {
   pub fn max(self, rhs: Self) -> Self {
     pick! {
       if #[cfg(target_feature="sse4.1")] {
-        Self { sse: max_u8_m128i(self.sse, rhs.sse) }
+        Self { sse: max_u16_m128i(self.sse, rhs.sse) }
       } else {
         let arr: [u16; 8] = cast(self);
         let rhs: [u16; 8] = cast(rhs);
@@ -258,7 +479,7 @@ impl u16x8 {
   pub fn min(self, rhs: Self) -> Self {
     pick! {
       if #[cfg(target_feature="sse4.1")] {
-        Self { sse: min_u8_m128i(self.sse, rhs.sse) }
+        Self { sse: min_u16_m128i(self.sse, rhs.sse) }
       } else {
         let arr: [u16; 8] = cast(self);
         let rhs: [u16; 8] = cast(rhs);
@@ -275,4 +496,250 @@ impl u16x8 {
       }
     }
   }
+  /// Clamps each lane to `[min, max]`.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    self.max(min).min(max)
+  }
+  /// The smallest lane.
+  #[inline]
+  #[must_use]
+  pub fn reduce_min(self) -> u16 {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        get_i32_from_m128i_s(min_position_u16_m128i(self.sse)) as u16
+      } else {
+        let folded: [u16; 8] = cast(fold_lanes_128(self, 16, |a, b| a.min(b)));
+        folded[0]
+      }
+    }
+  }
+  /// The largest lane.
+  #[inline]
+  #[must_use]
+  pub fn reduce_max(self) -> u16 {
+    !(!self).reduce_min()
+  }
+  /// Shifts each lane left by the count in the same lane of `counts`.
+  ///
+  /// Counts of 16 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shl_each(self, counts: u16x8) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // shift by each bit of the count in turn, then zero the lanes with
+        // counts that are too large
+        let mut x = self;
+        for i in 0..4 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step as u16);
+          x = (counts & bit).cmp_eq(bit).blend(x << step, x);
+        }
+        x & (counts & Self::splat(!15)).cmp_eq(Self::default())
+      } else {
+        let arr: [u16; 8] = cast(self);
+        let counts: [u16; 8] = cast(counts);
+        cast([
+          if counts[0] < 16 { arr[0] << counts[0] } else { 0 },
+          if counts[1] < 16 { arr[1] << counts[1] } else { 0 },
+          if counts[2] < 16 { arr[2] << counts[2] } else { 0 },
+          if counts[3] < 16 { arr[3] << counts[3] } else { 0 },
+          if counts[4] < 16 { arr[4] << counts[4] } else { 0 },
+          if counts[5] < 16 { arr[5] << counts[5] } else { 0 },
+          if counts[6] < 16 { arr[6] << counts[6] } else { 0 },
+          if counts[7] < 16 { arr[7] << counts[7] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Shifts each lane right by the count in the same lane of `counts`.
+  ///
+  /// Counts of 16 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shr_each(self, counts: u16x8) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // shift by each bit of the count in turn, then zero the lanes with
+        // counts that are too large
+        let mut x = self;
+        for i in 0..4 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step as u16);
+          x = (counts & bit).cmp_eq(bit).blend(x >> step, x);
+        }
+        x & (counts & Self::splat(!15)).cmp_eq(Self::default())
+      } else {
+        let arr: [u16; 8] = cast(self);
+        let counts: [u16; 8] = cast(counts);
+        cast([
+          if counts[0] < 16 { arr[0] >> counts[0] } else { 0 },
+          if counts[1] < 16 { arr[1] >> counts[1] } else { 0 },
+          if counts[2] < 16 { arr[2] >> counts[2] } else { 0 },
+          if counts[3] < 16 { arr[3] >> counts[3] } else { 0 },
+          if counts[4] < 16 { arr[4] >> counts[4] } else { 0 },
+          if counts[5] < 16 { arr[5] >> counts[5] } else { 0 },
+          if counts[6] < 16 { arr[6] >> counts[6] } else { 0 },
+          if counts[7] < 16 { arr[7] >> counts[7] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Adds each lane, clamping to the range of `u16` instead of wrapping.
+  #[inline]
+  #[must_use]
+  pub fn saturating_add(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: add_saturating_u16_m128i(self.sse, rhs.sse) }
+      } else {
+        let arr: [u16; 8] = cast(self);
+        let rhs: [u16; 8] = cast(rhs);
+        cast([
+          arr[0].saturating_add(rhs[0]),
+          arr[1].saturating_add(rhs[1]),
+          arr[2].saturating_add(rhs[2]),
+          arr[3].saturating_add(rhs[3]),
+          arr[4].saturating_add(rhs[4]),
+          arr[5].saturating_add(rhs[5]),
+          arr[6].saturating_add(rhs[6]),
+          arr[7].saturating_add(rhs[7]),
+        ])
+      }
+    }
+  }
+  /// Subtracts each lane, clamping to the range of `u16` instead of wrapping.
+  #[inline]
+  #[must_use]
+  pub fn saturating_sub(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: sub_saturating_u16_m128i(self.sse, rhs.sse) }
+      } else {
+        let arr: [u16; 8] = cast(self);
+        let rhs: [u16; 8] = cast(rhs);
+        cast([
+          arr[0].saturating_sub(rhs[0]),
+          arr[1].saturating_sub(rhs[1]),
+          arr[2].saturating_sub(rhs[2]),
+          arr[3].saturating_sub(rhs[3]),
+          arr[4].saturating_sub(rhs[4]),
+          arr[5].saturating_sub(rhs[5]),
+          arr[6].saturating_sub(rhs[6]),
+          arr[7].saturating_sub(rhs[7]),
+        ])
+      }
+    }
+  }
+  /// Multiplies each lane, clamping to the range of `u16` instead of wrapping.
+  #[inline]
+  #[must_use]
+  pub fn saturating_mul(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // any bits in the high half of the product mean it overflowed
+        let lo = Self { sse: mul_i16_keep_low_m128i(self.sse, rhs.sse) };
+        let hi = Self { sse: mul_u16_keep_high_m128i(self.sse, rhs.sse) };
+        lo | hi.cmp_ne(Self::default())
+      } else {
+        let arr: [u16; 8] = cast(self);
+        let rhs: [u16; 8] = cast(rhs);
+        cast([
+          arr[0].saturating_mul(rhs[0]),
+          arr[1].saturating_mul(rhs[1]),
+          arr[2].saturating_mul(rhs[2]),
+          arr[3].saturating_mul(rhs[3]),
+          arr[4].saturating_mul(rhs[4]),
+          arr[5].saturating_mul(rhs[5]),
+          arr[6].saturating_mul(rhs[6]),
+          arr[7].saturating_mul(rhs[7]),
+        ])
+      }
+    }
+  }
+  /// Zero extends the low 4 lanes to `u32`.
+  #[inline]
+  #[must_use]
+  pub fn widen_low(self) -> u32x4 {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        cast(convert_to_u32_m128i_from_lower4_u16_m128i(self.sse))
+      } else if #[cfg(target_feature="sse2")] {
+        cast(unpack_low_i16_m128i(self.sse, zeroed_m128i()))
+      } else {
+        let a: [u16; 8] = cast(self);
+        let mut out = [0_u32; 4];
+        for (o, &x) in out.iter_mut().zip(a[..4].iter()) {
+          *o = u32::from(x);
+        }
+        u32x4::from(out)
+      }
+    }
+  }
+  /// Zero extends the high 4 lanes to `u32`.
+  #[inline]
+  #[must_use]
+  pub fn widen_high(self) -> u32x4 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        cast(unpack_high_i16_m128i(self.sse, zeroed_m128i()))
+      } else {
+        let a: [u16; 8] = cast(self);
+        let mut out = [0_u32; 4];
+        for (o, &x) in out.iter_mut().zip(a[4..].iter()) {
+          *o = u32::from(x);
+        }
+        u32x4::from(out)
+      }
+    }
+  }
+  /// Zero extends every lane to `u32`.
+  #[inline]
+  #[must_use]
+  pub fn widen(self) -> u32x8 {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        cast(convert_to_i32_m256i_from_u16_m128i(self.sse))
+      } else {
+        u32x8::from_halves(self.widen_low(), self.widen_high())
+      }
+    }
+  }
+  /// Narrows to `u8` by keeping the low bits, with the lanes of `self`
+  /// in the low half of the output and the lanes of `hi` in the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_wrapping(self, hi: Self) -> u8x16 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // the mask keeps each lane in range, so the pack can't saturate it
+        let mask = set_splat_i16_m128i(0xFF);
+        cast(pack_i16_to_u8_m128i(
+          bitand_m128i(self.sse, mask),
+          bitand_m128i(hi.sse, mask),
+        ))
+      } else {
+        // little endian, so the low bits of each lane are the even narrow lanes
+        cast::<_, u8x16>(self).unzip_even(cast(hi))
+      }
+    }
+  }
+  /// Narrows to `u8`, saturating lanes that are out of range, with the
+  /// lanes of `self` in the low half of the output and the lanes of `hi` in
+  /// the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating(self, hi: Self) -> u8x16 {
+    let max = Self::splat(255);
+    let (lo, hi) = (self.min(max), hi.min(max));
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        cast(pack_i16_to_u8_m128i(lo.sse, hi.sse))
+      } else {
+        lo.narrow_wrapping(hi)
+      }
+    }
+  }
 }
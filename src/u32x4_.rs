@@ -55,6 +55,102 @@ impl Sub for u32x4 {
   }
 }
 
+impl Mul for u32x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        Self { sse: mul_i32_keep_low_m128i(self.sse, rhs.sse) }
+      } else {
+        let arr: [u32; 4] = cast(self);
+        let rhs: [u32; 4] = cast(rhs);
+        cast([
+          arr[0].wrapping_mul(rhs[0]),
+          arr[1].wrapping_mul(rhs[1]),
+          arr[2].wrapping_mul(rhs[2]),
+          arr[3].wrapping_mul(rhs[3]),
+        ])
+      }
+    }
+  }
+}
+
+impl Mul<u32> for u32x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: u32) -> Self::Output {
+    self.mul(Self::splat(rhs))
+  }
+}
+
+impl Mul<u32x4> for u32 {
+  type Output = u32x4;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: u32x4) -> Self::Output {
+    u32x4::splat(self) * rhs
+  }
+}
+
+impl Div for u32x4 {
+  type Output = Self;
+  /// Divides lane by lane, which is done one lane at a time because there's no
+  /// SIMD integer divide. Panics if any lane of `rhs` is 0.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: Self) -> Self::Output {
+    let arr: [u32; 4] = cast(self);
+    let rhs: [u32; 4] = cast(rhs);
+    cast([
+      arr[0].div(rhs[0]),
+      arr[1].div(rhs[1]),
+      arr[2].div(rhs[2]),
+      arr[3].div(rhs[3]),
+    ])
+  }
+}
+
+impl Rem for u32x4 {
+  type Output = Self;
+  /// Takes the remainder lane by lane. Panics if any lane of `rhs` is 0.
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: Self) -> Self::Output {
+    let arr: [u32; 4] = cast(self);
+    let rhs: [u32; 4] = cast(rhs);
+    cast([
+      arr[0].rem(rhs[0]),
+      arr[1].rem(rhs[1]),
+      arr[2].rem(rhs[2]),
+      arr[3].rem(rhs[3]),
+    ])
+  }
+}
+
+impl Div<u32> for u32x4 {
+  type Output = Self;
+  /// Divides every lane by `rhs` through a [`Divisor`]. If you divide by the
+  /// same value more than once, make the `Divisor` yourself and reuse it.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: u32) -> Self::Output {
+    self / Divisor::<Self>::new(rhs)
+  }
+}
+
+impl Rem<u32> for u32x4 {
+  type Output = Self;
+  /// Takes the remainder of every lane by `rhs` through a [`Divisor`].
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: u32) -> Self::Output {
+    self % Divisor::<Self>::new(rhs)
+  }
+}
+
 impl BitAnd for u32x4 {
   type Output = Self;
   #[inline]
@@ -171,10 +267,11 @@ macro_rules! impl_shr_t_for_u32x4 {
 }
 impl_shr_t_for_u32x4!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
 
-impl u32x4 {
+impl CmpEq for u32x4 {
+  type Output = Self;
   #[inline]
   #[must_use]
-  pub fn cmp_eq(self, rhs: Self) -> Self {
+  fn cmp_eq(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
         Self { sse: cmp_eq_mask_i32_m128i(self.sse, rhs.sse) }
@@ -188,6 +285,110 @@ impl u32x4 {
       }
     }
   }
+}
+
+impl CmpGt for u32x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_gt(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // there's no unsigned compare, so flip the sign bits and compare signed
+        let bias = Self::splat(1 << 31);
+        Self { sse: cmp_gt_mask_i32_m128i((self ^ bias).sse, (rhs ^ bias).sse) }
+      } else {
+        Self { arr: [
+          if self.arr[0] > rhs.arr[0] { u32::MAX } else { 0 },
+          if self.arr[1] > rhs.arr[1] { u32::MAX } else { 0 },
+          if self.arr[2] > rhs.arr[2] { u32::MAX } else { 0 },
+          if self.arr[3] > rhs.arr[3] { u32::MAX } else { 0 },
+        ]}
+      }
+    }
+  }
+}
+
+impl CmpLt for u32x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_lt(self, rhs: Self) -> Self::Output {
+    rhs.cmp_gt(self)
+  }
+}
+
+impl CmpGe for u32x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_ge(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        Self { sse: cmp_eq_mask_i32_m128i(max_u32_m128i(self.sse, rhs.sse), self.sse) }
+      } else {
+        !self.cmp_lt(rhs)
+      }
+    }
+  }
+}
+
+impl CmpLe for u32x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_le(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        Self { sse: cmp_eq_mask_i32_m128i(min_u32_m128i(self.sse, rhs.sse), self.sse) }
+      } else {
+        !self.cmp_gt(rhs)
+      }
+    }
+  }
+}
+
+impl CmpNe for u32x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_ne(self, rhs: Self) -> Self::Output {
+    !self.cmp_eq(rhs)
+  }
+}
+
+impl u32x4 {
+  #[inline]
+  #[must_use]
+  pub fn move_mask(self) -> i32 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        move_mask_m128(cast(self.sse))
+      }
+      else {
+        ((self.arr[0] >> 31) as i32) << 0 |
+        ((self.arr[1] >> 31) as i32) << 1 |
+        ((self.arr[2] >> 31) as i32) << 2 |
+        ((self.arr[3] >> 31) as i32) << 3
+      }
+    }
+  }
+  #[inline]
+  #[must_use]
+  pub fn any(self) -> bool {
+    self.move_mask() != 0
+  }
+  #[inline]
+  #[must_use]
+  pub fn all(self) -> bool {
+    // four lanes
+    self.move_mask() == 0b1111
+  }
+  #[inline]
+  #[must_use]
+  pub fn none(self) -> bool {
+    !self.any()
+  }
   #[inline]
   #[must_use]
   pub fn blend(self, t: Self, f: Self) -> Self {
@@ -199,6 +400,14 @@ impl u32x4 {
       }
     }
   }
+
+  /// Transposes a 4x4 matrix held as four rows, so lane `j` of row `i` in the
+  /// output is lane `i` of row `j` in the input.
+  #[inline]
+  #[must_use]
+  pub fn transpose4(data: [Self; 4]) -> [Self; 4] {
+    cast(f32x4::transpose4(cast(data)))
+  }
   #[inline]
   #[must_use]
   pub fn max(self, rhs: Self) -> Self {
@@ -235,4 +444,155 @@ impl u32x4 {
       }
     }
   }
+  /// Clamps each lane to `[min, max]`.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    self.max(min).min(max)
+  }
+  /// Shifts each lane left by the count in the same lane of `counts`.
+  ///
+  /// Counts of 32 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shl_each(self, counts: u32x4) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { sse: shl_each_u32_m128i(self.sse, cast(counts)) }
+      } else if #[cfg(target_feature="sse2")] {
+        // shift by each bit of the count in turn, then zero the lanes with
+        // counts that are too large
+        let mut x = self;
+        for i in 0..5 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step);
+          x = (counts & bit).cmp_eq(bit).blend(x << step, x);
+        }
+        x & (counts & Self::splat(!31)).cmp_eq(Self::default())
+      } else {
+        let arr: [u32; 4] = cast(self);
+        let counts: [u32; 4] = cast(counts);
+        cast([
+          if counts[0] < 32 { arr[0] << counts[0] } else { 0 },
+          if counts[1] < 32 { arr[1] << counts[1] } else { 0 },
+          if counts[2] < 32 { arr[2] << counts[2] } else { 0 },
+          if counts[3] < 32 { arr[3] << counts[3] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Shifts each lane right by the count in the same lane of `counts`.
+  ///
+  /// Counts of 32 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shr_each(self, counts: u32x4) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { sse: shr_each_u32_m128i(self.sse, cast(counts)) }
+      } else if #[cfg(target_feature="sse2")] {
+        // shift by each bit of the count in turn, then zero the lanes with
+        // counts that are too large
+        let mut x = self;
+        for i in 0..5 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step);
+          x = (counts & bit).cmp_eq(bit).blend(x >> step, x);
+        }
+        x & (counts & Self::splat(!31)).cmp_eq(Self::default())
+      } else {
+        let arr: [u32; 4] = cast(self);
+        let counts: [u32; 4] = cast(counts);
+        cast([
+          if counts[0] < 32 { arr[0] >> counts[0] } else { 0 },
+          if counts[1] < 32 { arr[1] >> counts[1] } else { 0 },
+          if counts[2] < 32 { arr[2] >> counts[2] } else { 0 },
+          if counts[3] < 32 { arr[3] >> counts[3] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Zero extends the low 2 lanes to `u64`.
+  #[inline]
+  #[must_use]
+  pub fn widen_low(self) -> u64x2 {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        cast(convert_to_u64_m128i_from_lower2_u32_m128i(self.sse))
+      } else if #[cfg(target_feature="sse2")] {
+        cast(unpack_low_i32_m128i(self.sse, zeroed_m128i()))
+      } else {
+        let a: [u32; 4] = cast(self);
+        let mut out = [0_u64; 2];
+        for (o, &x) in out.iter_mut().zip(a[..2].iter()) {
+          *o = u64::from(x);
+        }
+        u64x2::from(out)
+      }
+    }
+  }
+  /// Zero extends the high 2 lanes to `u64`.
+  #[inline]
+  #[must_use]
+  pub fn widen_high(self) -> u64x2 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        cast(unpack_high_i32_m128i(self.sse, zeroed_m128i()))
+      } else {
+        let a: [u32; 4] = cast(self);
+        let mut out = [0_u64; 2];
+        for (o, &x) in out.iter_mut().zip(a[2..].iter()) {
+          *o = u64::from(x);
+        }
+        u64x2::from(out)
+      }
+    }
+  }
+  /// Zero extends every lane to `u64`.
+  #[inline]
+  #[must_use]
+  pub fn widen(self) -> u64x4 {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        cast(convert_to_i64_m256i_from_u32_m128i(self.sse))
+      } else {
+        u64x4::from_halves(self.widen_low(), self.widen_high())
+      }
+    }
+  }
+  /// Narrows to `u16` by keeping the low bits, with the lanes of `self`
+  /// in the low half of the output and the lanes of `hi` in the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_wrapping(self, hi: Self) -> u16x8 {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        // the mask keeps each lane in range, so the pack can't saturate it
+        let mask = set_splat_i32_m128i(0xFFFF);
+        cast(pack_i32_to_u16_m128i(
+          bitand_m128i(self.sse, mask),
+          bitand_m128i(hi.sse, mask),
+        ))
+      } else {
+        // little endian, so the low bits of each lane are the even narrow lanes
+        cast::<_, u16x8>(self).unzip_even(cast(hi))
+      }
+    }
+  }
+  /// Narrows to `u16`, saturating lanes that are out of range, with the
+  /// lanes of `self` in the low half of the output and the lanes of `hi` in
+  /// the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating(self, hi: Self) -> u16x8 {
+    let max = Self::splat(0xFFFF);
+    let (lo, hi) = (self.min(max), hi.min(max));
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        cast(pack_i32_to_u16_m128i(lo.sse, hi.sse))
+      } else {
+        lo.narrow_wrapping(hi)
+      }
+    }
+  }
 }
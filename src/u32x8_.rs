@@ -99,6 +99,70 @@ impl Mul for u32x8 {
   }
 }
 
+impl Div for u32x8 {
+  type Output = Self;
+  /// Divides lane by lane, which is done one lane at a time because there's no
+  /// SIMD integer divide. Panics if any lane of `rhs` is 0.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: Self) -> Self::Output {
+    let arr: [u32; 8] = cast(self);
+    let rhs: [u32; 8] = cast(rhs);
+    cast([
+      arr[0].div(rhs[0]),
+      arr[1].div(rhs[1]),
+      arr[2].div(rhs[2]),
+      arr[3].div(rhs[3]),
+      arr[4].div(rhs[4]),
+      arr[5].div(rhs[5]),
+      arr[6].div(rhs[6]),
+      arr[7].div(rhs[7]),
+    ])
+  }
+}
+
+impl Rem for u32x8 {
+  type Output = Self;
+  /// Takes the remainder lane by lane. Panics if any lane of `rhs` is 0.
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: Self) -> Self::Output {
+    let arr: [u32; 8] = cast(self);
+    let rhs: [u32; 8] = cast(rhs);
+    cast([
+      arr[0].rem(rhs[0]),
+      arr[1].rem(rhs[1]),
+      arr[2].rem(rhs[2]),
+      arr[3].rem(rhs[3]),
+      arr[4].rem(rhs[4]),
+      arr[5].rem(rhs[5]),
+      arr[6].rem(rhs[6]),
+      arr[7].rem(rhs[7]),
+    ])
+  }
+}
+
+impl Div<u32> for u32x8 {
+  type Output = Self;
+  /// Divides every lane by `rhs` through a [`Divisor`]. If you divide by the
+  /// same value more than once, make the `Divisor` yourself and reuse it.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: u32) -> Self::Output {
+    self / Divisor::<Self>::new(rhs)
+  }
+}
+
+impl Rem<u32> for u32x8 {
+  type Output = Self;
+  /// Takes the remainder of every lane by `rhs` through a [`Divisor`].
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: u32) -> Self::Output {
+    self % Divisor::<Self>::new(rhs)
+  }
+}
+
 impl BitAnd for u32x8 {
   type Output = Self;
   #[inline]
@@ -248,10 +312,11 @@ macro_rules! impl_shr_t_for_u32x8 {
 
 impl_shr_t_for_u32x8!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
 
-impl u32x8 {
+impl CmpEq for u32x8 {
+  type Output = Self;
   #[inline]
   #[must_use]
-  pub fn cmp_eq(self, rhs: Self) -> Self {
+  fn cmp_eq(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
         Self { avx2: cmp_eq_mask_i32_m256i(self.avx2, rhs.avx2 ) }
@@ -271,14 +336,22 @@ impl u32x8 {
       }
     }
   }
+}
+
+impl CmpGt for u32x8 {
+  type Output = Self;
   #[inline]
   #[must_use]
-  pub fn cmp_gt(self, rhs: Self) -> Self {
+  fn cmp_gt(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        Self { avx2: cmp_gt_mask_i32_m256i(self.avx2, rhs.avx2 ) }
+        // there's no unsigned compare, so flip the sign bits and compare signed
+        let bias = Self::splat(1 << 31);
+        Self { avx2: cmp_gt_mask_i32_m256i((self ^ bias).avx2, (rhs ^ bias).avx2) }
       } else if #[cfg(target_feature="sse2")] {
-        Self { sse0: cmp_gt_mask_i32_m128i(self.sse0,rhs.sse0), sse1: cmp_gt_mask_i32_m128i(self.sse1,rhs.sse1), }
+        let bias = Self::splat(1 << 31);
+        let (s, r) = (self ^ bias, rhs ^ bias);
+        Self { sse0: cmp_gt_mask_i32_m128i(s.sse0, r.sse0), sse1: cmp_gt_mask_i32_m128i(s.sse1, r.sse1) }
       } else {
         Self { arr: [
           if self.arr[0] > rhs.arr[0] { u32::MAX } else { 0 },
@@ -293,30 +366,106 @@ impl u32x8 {
       }
     }
   }
+}
+
+impl CmpLt for u32x8 {
+  type Output = Self;
   #[inline]
   #[must_use]
-  pub fn cmp_lt(self, rhs: Self) -> Self {
+  fn cmp_lt(self, rhs: Self) -> Self::Output {
+    rhs.cmp_gt(self)
+  }
+}
+
+impl CmpGe for u32x8 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_ge(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        Self { avx2: cmp_eq_mask_i32_m256i(self.avx2, rhs.avx2 ) }
-      } else if #[cfg(target_feature="sse2")] {
-        Self { sse0: cmp_lt_mask_i32_m128i(self.sse0,rhs.sse0), sse1: cmp_lt_mask_i32_m128i(self.sse1,rhs.sse1), }
+        Self { avx2: cmp_eq_mask_i32_m256i(max_u32_m256i(self.avx2, rhs.avx2), self.avx2) }
+      } else if #[cfg(target_feature="sse4.1")] {
+        Self {
+          sse0: cmp_eq_mask_i32_m128i(max_u32_m128i(self.sse0, rhs.sse0), self.sse0),
+          sse1: cmp_eq_mask_i32_m128i(max_u32_m128i(self.sse1, rhs.sse1), self.sse1),
+        }
       } else {
-        Self { arr: [
-          if self.arr[0] < rhs.arr[0] { u32::MAX } else { 0 },
-          if self.arr[1] < rhs.arr[1] { u32::MAX } else { 0 },
-          if self.arr[2] < rhs.arr[2] { u32::MAX } else { 0 },
-          if self.arr[3] < rhs.arr[3] { u32::MAX } else { 0 },
-          if self.arr[4] < rhs.arr[4] { u32::MAX } else { 0 },
-          if self.arr[5] < rhs.arr[5] { u32::MAX } else { 0 },
-          if self.arr[6] < rhs.arr[6] { u32::MAX } else { 0 },
-          if self.arr[7] < rhs.arr[7] { u32::MAX } else { 0 },
-        ]}
+        !self.cmp_lt(rhs)
+      }
+    }
+  }
+}
+
+impl CmpLe for u32x8 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_le(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx2: cmp_eq_mask_i32_m256i(min_u32_m256i(self.avx2, rhs.avx2), self.avx2) }
+      } else if #[cfg(target_feature="sse4.1")] {
+        Self {
+          sse0: cmp_eq_mask_i32_m128i(min_u32_m128i(self.sse0, rhs.sse0), self.sse0),
+          sse1: cmp_eq_mask_i32_m128i(min_u32_m128i(self.sse1, rhs.sse1), self.sse1),
+        }
+      } else {
+        !self.cmp_gt(rhs)
+      }
+    }
+  }
+}
+
+impl CmpNe for u32x8 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_ne(self, rhs: Self) -> Self::Output {
+    !self.cmp_eq(rhs)
+  }
+}
+
+impl u32x8 {
+  #[inline]
+  #[must_use]
+  pub fn move_mask(self) -> i32 {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        move_mask_m256(cast(self.avx2))
+      } else if #[cfg(target_feature="sse")] {
+        move_mask_m128(cast(self.sse1)) << 4 | move_mask_m128(cast(self.sse0))
+      }
+      else {
+        ((self.arr[0] >> 31) as i32) << 0 |
+        ((self.arr[1] >> 31) as i32) << 1 |
+        ((self.arr[2] >> 31) as i32) << 2 |
+        ((self.arr[3] >> 31) as i32) << 3 |
+        ((self.arr[4] >> 31) as i32) << 4 |
+        ((self.arr[5] >> 31) as i32) << 5 |
+        ((self.arr[6] >> 31) as i32) << 6 |
+        ((self.arr[7] >> 31) as i32) << 7
       }
     }
   }
   #[inline]
   #[must_use]
+  pub fn any(self) -> bool {
+    self.move_mask() != 0
+  }
+  #[inline]
+  #[must_use]
+  pub fn all(self) -> bool {
+    // eight lanes
+    self.move_mask() == 0b11111111
+  }
+  #[inline]
+  #[must_use]
+  pub fn none(self) -> bool {
+    !self.any()
+  }
+  #[inline]
+  #[must_use]
   pub fn blend(self, t: Self, f: Self) -> Self {
     pick! {
       if #[cfg(target_feature="avx2")] {
@@ -329,14 +478,22 @@ impl u32x8 {
     }
   }
 
+  /// Transposes an 8x8 matrix held as eight rows, so lane `j` of row `i` in the
+  /// output is lane `i` of row `j` in the input.
+  #[inline]
+  #[must_use]
+  pub fn transpose8(data: [Self; 8]) -> [Self; 8] {
+    cast(f32x8::transpose8(cast(data)))
+  }
+
   #[inline]
   #[must_use]
   pub fn max(self, rhs: Self) -> Self {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        Self { avx2: max_i32_m256i(self.avx2, rhs.avx2 ) }
+        Self { avx2: max_u32_m256i(self.avx2, rhs.avx2 ) }
       } else if #[cfg(target_feature="sse4.1")] {
-        Self { sse0: max_i32_m128i(self.sse0, rhs.sse0), sse1: max_i32_m128i(self.sse1, rhs.sse1) }
+        Self { sse0: max_u32_m128i(self.sse0, rhs.sse0), sse1: max_u32_m128i(self.sse1, rhs.sse1) }
       } else {
         self.cmp_lt(rhs).blend(rhs, self)
       }
@@ -347,14 +504,116 @@ impl u32x8 {
   pub fn min(self, rhs: Self) -> Self {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        Self { avx2: max_i32_m256i(self.avx2, rhs.avx2 ) }
+        Self { avx2: min_u32_m256i(self.avx2, rhs.avx2 ) }
       } else if #[cfg(target_feature="sse4.1")] {
-        Self { sse0: max_i32_m128i(self.sse0, rhs.sse0), sse1: max_i32_m128i(self.sse1, rhs.sse1) }
+        Self { sse0: min_u32_m128i(self.sse0, rhs.sse0), sse1: min_u32_m128i(self.sse1, rhs.sse1) }
       } else {
         self.cmp_lt(rhs).blend(self, rhs)
       }
     }
   }
+  /// Clamps each lane to `[min, max]`.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    self.max(min).min(max)
+  }
+  /// Shifts each lane left by the count in the same lane of `counts`.
+  ///
+  /// Counts of 32 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shl_each(self, counts: u32x8) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx2: shl_each_u32_m256i(self.avx2, cast(counts)) }
+      } else if #[cfg(target_feature="sse2")] {
+        // shift by each bit of the count in turn, then zero the lanes with
+        // counts that are too large
+        let mut x = self;
+        for i in 0..5 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step);
+          x = (counts & bit).cmp_eq(bit).blend(x << step, x);
+        }
+        x & (counts & Self::splat(!31)).cmp_eq(Self::default())
+      } else {
+        let arr: [u32; 8] = cast(self);
+        let counts: [u32; 8] = cast(counts);
+        cast([
+          if counts[0] < 32 { arr[0] << counts[0] } else { 0 },
+          if counts[1] < 32 { arr[1] << counts[1] } else { 0 },
+          if counts[2] < 32 { arr[2] << counts[2] } else { 0 },
+          if counts[3] < 32 { arr[3] << counts[3] } else { 0 },
+          if counts[4] < 32 { arr[4] << counts[4] } else { 0 },
+          if counts[5] < 32 { arr[5] << counts[5] } else { 0 },
+          if counts[6] < 32 { arr[6] << counts[6] } else { 0 },
+          if counts[7] < 32 { arr[7] << counts[7] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Shifts each lane right by the count in the same lane of `counts`.
+  ///
+  /// Counts of 32 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shr_each(self, counts: u32x8) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx2: shr_each_u32_m256i(self.avx2, cast(counts)) }
+      } else if #[cfg(target_feature="sse2")] {
+        // shift by each bit of the count in turn, then zero the lanes with
+        // counts that are too large
+        let mut x = self;
+        for i in 0..5 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step);
+          x = (counts & bit).cmp_eq(bit).blend(x >> step, x);
+        }
+        x & (counts & Self::splat(!31)).cmp_eq(Self::default())
+      } else {
+        let arr: [u32; 8] = cast(self);
+        let counts: [u32; 8] = cast(counts);
+        cast([
+          if counts[0] < 32 { arr[0] >> counts[0] } else { 0 },
+          if counts[1] < 32 { arr[1] >> counts[1] } else { 0 },
+          if counts[2] < 32 { arr[2] >> counts[2] } else { 0 },
+          if counts[3] < 32 { arr[3] >> counts[3] } else { 0 },
+          if counts[4] < 32 { arr[4] >> counts[4] } else { 0 },
+          if counts[5] < 32 { arr[5] >> counts[5] } else { 0 },
+          if counts[6] < 32 { arr[6] >> counts[6] } else { 0 },
+          if counts[7] < 32 { arr[7] >> counts[7] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Zero extends the low 4 lanes to `u64`.
+  #[inline]
+  #[must_use]
+  pub fn widen_low(self) -> u64x4 {
+    self.low().widen()
+  }
+  /// Zero extends the high 4 lanes to `u64`.
+  #[inline]
+  #[must_use]
+  pub fn widen_high(self) -> u64x4 {
+    self.high().widen()
+  }
+  /// Narrows to `u16` by keeping the low bits.
+  #[inline]
+  #[must_use]
+  pub fn narrow_wrapping(self) -> u16x8 {
+    let (lo, hi) = self.split();
+    lo.narrow_wrapping(hi)
+  }
+  /// Narrows to `u16`, saturating lanes that are out of range.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating(self) -> u16x8 {
+    let (lo, hi) = self.split();
+    lo.narrow_saturating(hi)
+  }
 }
 
 impl Not for u32x8 {
@@ -51,6 +51,88 @@ impl Sub for u64x2 {
   }
 }
 
+impl Mul for u64x2 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: mul_i64_keep_low_m128i(self.sse, rhs.sse) }
+      } else {
+        Self { arr: [
+          self.arr[0].wrapping_mul(rhs.arr[0]),
+          self.arr[1].wrapping_mul(rhs.arr[1]),
+        ]}
+      }
+    }
+  }
+}
+
+impl Mul<u64> for u64x2 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: u64) -> Self::Output {
+    self.mul(Self::splat(rhs))
+  }
+}
+
+impl Mul<u64x2> for u64 {
+  type Output = u64x2;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: u64x2) -> Self::Output {
+    u64x2::splat(self) * rhs
+  }
+}
+
+impl Div for u64x2 {
+  type Output = Self;
+  /// Divides lane by lane, which is done one lane at a time because there's no
+  /// SIMD integer divide. Panics if any lane of `rhs` is 0.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: Self) -> Self::Output {
+    let arr: [u64; 2] = cast(self);
+    let rhs: [u64; 2] = cast(rhs);
+    cast([arr[0].div(rhs[0]), arr[1].div(rhs[1])])
+  }
+}
+
+impl Rem for u64x2 {
+  type Output = Self;
+  /// Takes the remainder lane by lane. Panics if any lane of `rhs` is 0.
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: Self) -> Self::Output {
+    let arr: [u64; 2] = cast(self);
+    let rhs: [u64; 2] = cast(rhs);
+    cast([arr[0].rem(rhs[0]), arr[1].rem(rhs[1])])
+  }
+}
+
+impl Div<u64> for u64x2 {
+  type Output = Self;
+  /// Divides every lane by `rhs` through a [`Divisor`]. If you divide by the
+  /// same value more than once, make the `Divisor` yourself and reuse it.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: u64) -> Self::Output {
+    self / Divisor::<Self>::new(rhs)
+  }
+}
+
+impl Rem<u64> for u64x2 {
+  type Output = Self;
+  /// Takes the remainder of every lane by `rhs` through a [`Divisor`].
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: u64) -> Self::Output {
+    self % Divisor::<Self>::new(rhs)
+  }
+}
+
 impl BitAnd for u64x2 {
   type Output = Self;
   #[inline]
@@ -157,40 +239,114 @@ macro_rules! impl_shr_t_for_u64x2 {
 }
 impl_shr_t_for_u64x2!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
 
-impl u64x2 {
+impl CmpEq for u64x2 {
+  type Output = Self;
   #[inline]
   #[must_use]
-  pub fn cmp_eq(self, rhs: Self) -> Self {
+  fn cmp_eq(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse4.1")] {
         Self { sse: cmp_eq_mask_i64_m128i(self.sse, rhs.sse) }
       } else {
-        let s: [i64;2] = cast(self);
-        let r: [i64;2] = cast(rhs);
+        let s: [u64;2] = cast(self);
+        let r: [u64;2] = cast(rhs);
         cast([
-          if s[0] == r[0] { -1_i64 } else { 0 },
-          if s[1] == r[1] { -1_i64 } else { 0 },
+          if s[0] == r[0] { u64::MAX } else { 0 },
+          if s[1] == r[1] { u64::MAX } else { 0 },
         ])
       }
     }
   }
+}
+
+impl CmpGt for u64x2 {
+  type Output = Self;
   #[inline]
   #[must_use]
-  pub fn cmp_gt(self, rhs: Self) -> Self {
+  fn cmp_gt(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse4.2")] {
-        Self { sse: cmp_gt_mask_i64_m128i(self.sse, rhs.sse) }
+        // there's no unsigned compare, so flip the sign bits and compare signed
+        let bias = Self::splat(1 << 63);
+        Self { sse: cmp_gt_mask_i64_m128i((self ^ bias).sse, (rhs ^ bias).sse) }
       } else {
-        let s: [i64;2] = cast(self);
-        let r: [i64;2] = cast(rhs);
+        let s: [u64;2] = cast(self);
+        let r: [u64;2] = cast(rhs);
         cast([
-          if s[0] > r[0] { -1_i64 } else { 0 },
-          if s[1] > r[1] { -1_i64 } else { 0 },
+          if s[0] > r[0] { u64::MAX } else { 0 },
+          if s[1] > r[1] { u64::MAX } else { 0 },
         ])
       }
     }
   }
+}
+
+impl CmpLt for u64x2 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_lt(self, rhs: Self) -> Self::Output {
+    rhs.cmp_gt(self)
+  }
+}
+
+impl CmpGe for u64x2 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_ge(self, rhs: Self) -> Self::Output {
+    !self.cmp_lt(rhs)
+  }
+}
+
+impl CmpLe for u64x2 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_le(self, rhs: Self) -> Self::Output {
+    !self.cmp_gt(rhs)
+  }
+}
+
+impl CmpNe for u64x2 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_ne(self, rhs: Self) -> Self::Output {
+    !self.cmp_eq(rhs)
+  }
+}
 
+impl u64x2 {
+  #[inline]
+  #[must_use]
+  pub fn move_mask(self) -> i32 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        move_mask_m128d(cast(self.sse))
+      }
+      else {
+        ((self.arr[0] >> 63) as i32) << 0 |
+        ((self.arr[1] >> 63) as i32) << 1
+      }
+    }
+  }
+  #[inline]
+  #[must_use]
+  pub fn any(self) -> bool {
+    self.move_mask() != 0
+  }
+  #[inline]
+  #[must_use]
+  pub fn all(self) -> bool {
+    // two lanes
+    self.move_mask() == 0b11
+  }
+  #[inline]
+  #[must_use]
+  pub fn none(self) -> bool {
+    !self.any()
+  }
   #[inline]
   #[must_use]
   pub fn blend(self, t: Self, f: Self) -> Self {
@@ -202,4 +358,76 @@ impl u64x2 {
       }
     }
   }
+  /// Clamps each lane to `[min, max]`.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    let x = min.cmp_gt(self).blend(min, self);
+    x.cmp_gt(max).blend(max, x)
+  }
+  /// Shifts each lane left by the count in the same lane of `counts`.
+  ///
+  /// Counts of 64 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shl_each(self, counts: u64x2) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { sse: shl_each_u64_m128i(self.sse, cast(counts)) }
+      } else {
+        let arr: [u64; 2] = cast(self);
+        let counts: [u64; 2] = cast(counts);
+        cast([
+          if counts[0] < 64 { arr[0] << counts[0] } else { 0 },
+          if counts[1] < 64 { arr[1] << counts[1] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Shifts each lane right by the count in the same lane of `counts`.
+  ///
+  /// Counts of 64 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shr_each(self, counts: u64x2) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { sse: shr_each_u64_m128i(self.sse, cast(counts)) }
+      } else {
+        let arr: [u64; 2] = cast(self);
+        let counts: [u64; 2] = cast(counts);
+        cast([
+          if counts[0] < 64 { arr[0] >> counts[0] } else { 0 },
+          if counts[1] < 64 { arr[1] >> counts[1] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Narrows to `u32` by keeping the low bits, with the lanes of `self`
+  /// in the low half of the output and the lanes of `hi` in the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_wrapping(self, hi: Self) -> u32x4 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // little endian, so the low 32 bits of each lane are the even `f32`
+        let (lo, hi): (m128, m128) = (cast(self.sse), cast(hi.sse));
+        cast(shuffle_abi_f32_all_m128!(lo, hi, [a:0, a:2, b:0, b:2]))
+      } else {
+        // little endian, so the low bits of each lane are the even narrow lanes
+        cast::<_, u32x4>(self).unzip_even(cast(hi))
+      }
+    }
+  }
+  /// Narrows to `u32`, saturating lanes that are out of range, with the
+  /// lanes of `self` in the low half of the output and the lanes of `hi` in
+  /// the high half.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating(self, hi: Self) -> u32x4 {
+    let max = Self::splat(u32::MAX.into());
+    let (lo, hi) =
+      (self.cmp_gt(max).blend(max, self), hi.cmp_gt(max).blend(max, hi));
+    lo.narrow_wrapping(hi)
+  }
 }
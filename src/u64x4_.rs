@@ -63,6 +63,102 @@ impl Sub for u64x4 {
   }
 }
 
+impl Mul for u64x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx2: mul_i64_keep_low_m256i(self.avx2, rhs.avx2) }
+      } else if #[cfg(target_feature="sse2")] {
+        Self { sse0: mul_i64_keep_low_m128i(self.sse0, rhs.sse0), sse1: mul_i64_keep_low_m128i(self.sse1, rhs.sse1) }
+      } else {
+        Self { arr: [
+          self.arr[0].wrapping_mul(rhs.arr[0]),
+          self.arr[1].wrapping_mul(rhs.arr[1]),
+          self.arr[2].wrapping_mul(rhs.arr[2]),
+          self.arr[3].wrapping_mul(rhs.arr[3]),
+        ]}
+      }
+    }
+  }
+}
+
+impl Mul<u64> for u64x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: u64) -> Self::Output {
+    self.mul(Self::splat(rhs))
+  }
+}
+
+impl Mul<u64x4> for u64 {
+  type Output = u64x4;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: u64x4) -> Self::Output {
+    u64x4::splat(self) * rhs
+  }
+}
+
+impl Div for u64x4 {
+  type Output = Self;
+  /// Divides lane by lane, which is done one lane at a time because there's no
+  /// SIMD integer divide. Panics if any lane of `rhs` is 0.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: Self) -> Self::Output {
+    let arr: [u64; 4] = cast(self);
+    let rhs: [u64; 4] = cast(rhs);
+    cast([
+      arr[0].div(rhs[0]),
+      arr[1].div(rhs[1]),
+      arr[2].div(rhs[2]),
+      arr[3].div(rhs[3]),
+    ])
+  }
+}
+
+impl Rem for u64x4 {
+  type Output = Self;
+  /// Takes the remainder lane by lane. Panics if any lane of `rhs` is 0.
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: Self) -> Self::Output {
+    let arr: [u64; 4] = cast(self);
+    let rhs: [u64; 4] = cast(rhs);
+    cast([
+      arr[0].rem(rhs[0]),
+      arr[1].rem(rhs[1]),
+      arr[2].rem(rhs[2]),
+      arr[3].rem(rhs[3]),
+    ])
+  }
+}
+
+impl Div<u64> for u64x4 {
+  type Output = Self;
+  /// Divides every lane by `rhs` through a [`Divisor`]. If you divide by the
+  /// same value more than once, make the `Divisor` yourself and reuse it.
+  #[inline]
+  #[must_use]
+  fn div(self, rhs: u64) -> Self::Output {
+    self / Divisor::<Self>::new(rhs)
+  }
+}
+
+impl Rem<u64> for u64x4 {
+  type Output = Self;
+  /// Takes the remainder of every lane by `rhs` through a [`Divisor`].
+  #[inline]
+  #[must_use]
+  fn rem(self, rhs: u64) -> Self::Output {
+    self % Divisor::<Self>::new(rhs)
+  }
+}
+
 impl BitAnd for u64x4 {
   type Output = Self;
   #[inline]
@@ -191,48 +287,128 @@ macro_rules! impl_shr_t_for_u64x4 {
 }
 impl_shr_t_for_u64x4!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
 
-impl u64x4 {
+impl CmpEq for u64x4 {
+  type Output = Self;
   #[inline]
   #[must_use]
-  pub fn cmp_eq(self, rhs: Self) -> Self {
+  fn cmp_eq(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
         Self { avx2: cmp_eq_mask_i64_m256i(self.avx2, rhs.avx2) }
       } else if #[cfg(target_feature="sse4.1")] {
         Self { sse0: cmp_eq_mask_i64_m128i(self.sse0, rhs.sse0),sse1: cmp_eq_mask_i64_m128i(self.sse1, rhs.sse1) }
       } else {
-        let s: [i64;4] = cast(self);
-        let r: [i64;4] = cast(rhs);
+        let s: [u64;4] = cast(self);
+        let r: [u64;4] = cast(rhs);
         cast([
-          if s[0] == r[0] { -1_i64 } else { 0 },
-          if s[1] == r[1] { -1_i64 } else { 0 },
-          if s[2] == r[2] { -1_i64 } else { 0 },
-          if s[3] == r[3] { -1_i64 } else { 0 },
+          if s[0] == r[0] { u64::MAX } else { 0 },
+          if s[1] == r[1] { u64::MAX } else { 0 },
+          if s[2] == r[2] { u64::MAX } else { 0 },
+          if s[3] == r[3] { u64::MAX } else { 0 },
         ])
       }
     }
   }
+}
+
+impl CmpGt for u64x4 {
+  type Output = Self;
   #[inline]
   #[must_use]
-  pub fn cmp_gt(self, rhs: Self) -> Self {
+  fn cmp_gt(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        Self { avx2: cmp_gt_mask_i64_m256i(self.avx2, rhs.avx2) }
+        // there's no unsigned compare, so flip the sign bits and compare signed
+        let bias = Self::splat(1 << 63);
+        Self { avx2: cmp_gt_mask_i64_m256i((self ^ bias).avx2, (rhs ^ bias).avx2) }
       } else if #[cfg(target_feature="sse4.2")] {
-        Self { sse0: cmp_gt_mask_i64_m128i(self.sse0, rhs.sse0), sse1: cmp_gt_mask_i64_m128i(self.sse1, rhs.sse1) }
+        let bias = Self::splat(1 << 63);
+        let (s, r) = (self ^ bias, rhs ^ bias);
+        Self { sse0: cmp_gt_mask_i64_m128i(s.sse0, r.sse0), sse1: cmp_gt_mask_i64_m128i(s.sse1, r.sse1) }
       } else {
-        let s: [i64;4] = cast(self);
-        let r: [i64;4] = cast(rhs);
+        let s: [u64;4] = cast(self);
+        let r: [u64;4] = cast(rhs);
         cast([
-          if s[0] > r[0] { -1_i64 } else { 0 },
-          if s[1] > r[1] { -1_i64 } else { 0 },
-          if s[2] > r[2] { -1_i64 } else { 0 },
-          if s[3] > r[3] { -1_i64 } else { 0 },
+          if s[0] > r[0] { u64::MAX } else { 0 },
+          if s[1] > r[1] { u64::MAX } else { 0 },
+          if s[2] > r[2] { u64::MAX } else { 0 },
+          if s[3] > r[3] { u64::MAX } else { 0 },
         ])
       }
     }
   }
+}
+
+impl CmpLt for u64x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_lt(self, rhs: Self) -> Self::Output {
+    rhs.cmp_gt(self)
+  }
+}
 
+impl CmpGe for u64x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_ge(self, rhs: Self) -> Self::Output {
+    !self.cmp_lt(rhs)
+  }
+}
+
+impl CmpLe for u64x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_le(self, rhs: Self) -> Self::Output {
+    !self.cmp_gt(rhs)
+  }
+}
+
+impl CmpNe for u64x4 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_ne(self, rhs: Self) -> Self::Output {
+    !self.cmp_eq(rhs)
+  }
+}
+
+impl u64x4 {
+  #[inline]
+  #[must_use]
+  pub fn move_mask(self) -> i32 {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        move_mask_m256d(cast(self.avx2))
+      } else if #[cfg(target_feature="sse2")] {
+        move_mask_m128d(cast(self.sse1)) << 2 | move_mask_m128d(cast(self.sse0))
+      }
+      else {
+        ((self.arr[0] >> 63) as i32) << 0 |
+        ((self.arr[1] >> 63) as i32) << 1 |
+        ((self.arr[2] >> 63) as i32) << 2 |
+        ((self.arr[3] >> 63) as i32) << 3
+      }
+    }
+  }
+  #[inline]
+  #[must_use]
+  pub fn any(self) -> bool {
+    self.move_mask() != 0
+  }
+  #[inline]
+  #[must_use]
+  pub fn all(self) -> bool {
+    // four lanes
+    self.move_mask() == 0b1111
+  }
+  #[inline]
+  #[must_use]
+  pub fn none(self) -> bool {
+    !self.any()
+  }
   #[inline]
   #[must_use]
   pub fn blend(self, t: Self, f: Self) -> Self {
@@ -246,6 +422,77 @@ impl u64x4 {
       }
     }
   }
+
+  /// Transposes a 4x4 matrix held as four rows, so lane `j` of row `i` in the
+  /// output is lane `i` of row `j` in the input.
+  #[inline]
+  #[must_use]
+  pub fn transpose4(data: [Self; 4]) -> [Self; 4] {
+    cast(f64x4::transpose4(cast(data)))
+  }
+  /// Clamps each lane to `[min, max]`.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    let x = min.cmp_gt(self).blend(min, self);
+    x.cmp_gt(max).blend(max, x)
+  }
+  /// Shifts each lane left by the count in the same lane of `counts`.
+  ///
+  /// Counts of 64 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shl_each(self, counts: u64x4) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx2: shl_each_u64_m256i(self.avx2, cast(counts)) }
+      } else {
+        let arr: [u64; 4] = cast(self);
+        let counts: [u64; 4] = cast(counts);
+        cast([
+          if counts[0] < 64 { arr[0] << counts[0] } else { 0 },
+          if counts[1] < 64 { arr[1] << counts[1] } else { 0 },
+          if counts[2] < 64 { arr[2] << counts[2] } else { 0 },
+          if counts[3] < 64 { arr[3] << counts[3] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Shifts each lane right by the count in the same lane of `counts`.
+  ///
+  /// Counts of 64 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shr_each(self, counts: u64x4) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        Self { avx2: shr_each_u64_m256i(self.avx2, cast(counts)) }
+      } else {
+        let arr: [u64; 4] = cast(self);
+        let counts: [u64; 4] = cast(counts);
+        cast([
+          if counts[0] < 64 { arr[0] >> counts[0] } else { 0 },
+          if counts[1] < 64 { arr[1] >> counts[1] } else { 0 },
+          if counts[2] < 64 { arr[2] >> counts[2] } else { 0 },
+          if counts[3] < 64 { arr[3] >> counts[3] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Narrows to `u32` by keeping the low bits.
+  #[inline]
+  #[must_use]
+  pub fn narrow_wrapping(self) -> u32x4 {
+    let (lo, hi) = self.split();
+    lo.narrow_wrapping(hi)
+  }
+  /// Narrows to `u32`, saturating lanes that are out of range.
+  #[inline]
+  #[must_use]
+  pub fn narrow_saturating(self) -> u32x4 {
+    let (lo, hi) = self.split();
+    lo.narrow_saturating(hi)
+  }
 }
 
 impl Not for u64x4 {
@@ -79,6 +79,56 @@ impl Sub for u8x16 {
   }
 }
 
+impl Mul for u8x16 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: mul_i8_keep_low_m128i(self.sse, rhs.sse) }
+      } else {
+        Self { arr: [
+          self.arr[0].wrapping_mul(rhs.arr[0]),
+          self.arr[1].wrapping_mul(rhs.arr[1]),
+          self.arr[2].wrapping_mul(rhs.arr[2]),
+          self.arr[3].wrapping_mul(rhs.arr[3]),
+          self.arr[4].wrapping_mul(rhs.arr[4]),
+          self.arr[5].wrapping_mul(rhs.arr[5]),
+          self.arr[6].wrapping_mul(rhs.arr[6]),
+          self.arr[7].wrapping_mul(rhs.arr[7]),
+          self.arr[8].wrapping_mul(rhs.arr[8]),
+          self.arr[9].wrapping_mul(rhs.arr[9]),
+          self.arr[10].wrapping_mul(rhs.arr[10]),
+          self.arr[11].wrapping_mul(rhs.arr[11]),
+          self.arr[12].wrapping_mul(rhs.arr[12]),
+          self.arr[13].wrapping_mul(rhs.arr[13]),
+          self.arr[14].wrapping_mul(rhs.arr[14]),
+          self.arr[15].wrapping_mul(rhs.arr[15]),
+        ]}
+      }
+    }
+  }
+}
+
+impl Mul<u8> for u8x16 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: u8) -> Self::Output {
+    self.mul(Self::splat(rhs))
+  }
+}
+
+impl Mul<u8x16> for u8 {
+  type Output = u8x16;
+  #[inline]
+  #[must_use]
+  fn mul(self, rhs: u8x16) -> Self::Output {
+    u8x16::splat(self) * rhs
+  }
+}
+
 impl BitAnd for u8x16 {
   type Output = Self;
   #[inline]
@@ -175,10 +225,95 @@ impl BitXor for u8x16 {
   }
 }
 
-impl u8x16 {
+macro_rules! impl_shl_t_for_u8x16 {
+  ($($shift_type:ty),+ $(,)?) => {
+    $(impl Shl<$shift_type> for u8x16 {
+      type Output = Self;
+      /// Shifts all lanes by the value given.
+      #[inline]
+      #[must_use]
+      fn shl(self, rhs: $shift_type) -> Self::Output {
+        let u = rhs as u64;
+        pick! {
+          if #[cfg(target_feature="sse2")] {
+            // shift as u16 lanes, then clear the bits that crossed a byte
+            let shift = cast([u, 0]);
+            let mask = Self::splat((0xFF_u64 << u.min(8)) as u8);
+            Self { sse: shl_all_u16_m128i(self.sse, shift) } & mask
+          } else {
+            Self { arr: [
+              self.arr[0] << u,
+              self.arr[1] << u,
+              self.arr[2] << u,
+              self.arr[3] << u,
+              self.arr[4] << u,
+              self.arr[5] << u,
+              self.arr[6] << u,
+              self.arr[7] << u,
+              self.arr[8] << u,
+              self.arr[9] << u,
+              self.arr[10] << u,
+              self.arr[11] << u,
+              self.arr[12] << u,
+              self.arr[13] << u,
+              self.arr[14] << u,
+              self.arr[15] << u,
+            ]}
+          }
+        }
+      }
+    })+
+  };
+}
+impl_shl_t_for_u8x16!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+
+macro_rules! impl_shr_t_for_u8x16 {
+  ($($shift_type:ty),+ $(,)?) => {
+    $(impl Shr<$shift_type> for u8x16 {
+      type Output = Self;
+      /// Shifts all lanes by the value given.
+      #[inline]
+      #[must_use]
+      fn shr(self, rhs: $shift_type) -> Self::Output {
+        let u = rhs as u64;
+        pick! {
+          if #[cfg(target_feature="sse2")] {
+            // shift as u16 lanes, then clear the bits that crossed a byte
+            let shift = cast([u, 0]);
+            let mask = Self::splat((0xFF_u64 >> u.min(8)) as u8);
+            Self { sse: shr_all_u16_m128i(self.sse, shift) } & mask
+          } else {
+            Self { arr: [
+              self.arr[0] >> u,
+              self.arr[1] >> u,
+              self.arr[2] >> u,
+              self.arr[3] >> u,
+              self.arr[4] >> u,
+              self.arr[5] >> u,
+              self.arr[6] >> u,
+              self.arr[7] >> u,
+              self.arr[8] >> u,
+              self.arr[9] >> u,
+              self.arr[10] >> u,
+              self.arr[11] >> u,
+              self.arr[12] >> u,
+              self.arr[13] >> u,
+              self.arr[14] >> u,
+              self.arr[15] >> u,
+            ]}
+          }
+        }
+      }
+    })+
+  };
+}
+impl_shr_t_for_u8x16!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+
+impl CmpEq for u8x16 {
+  type Output = Self;
   #[inline]
   #[must_use]
-  pub fn cmp_eq(self, rhs: Self) -> Self {
+  fn cmp_eq(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
         Self { sse: cmp_eq_mask_i8_m128i(self.sse, rhs.sse) }
@@ -204,6 +339,134 @@ impl u8x16 {
       }
     }
   }
+}
+
+impl CmpGt for u8x16 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_gt(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // there's no unsigned compare, so flip the sign bits and compare signed
+        let bias = Self::splat(0x80);
+        Self { sse: cmp_gt_mask_i8_m128i((self ^ bias).sse, (rhs ^ bias).sse) }
+      } else {
+        Self { arr: [
+          if self.arr[0] > rhs.arr[0] { u8::MAX } else { 0 },
+          if self.arr[1] > rhs.arr[1] { u8::MAX } else { 0 },
+          if self.arr[2] > rhs.arr[2] { u8::MAX } else { 0 },
+          if self.arr[3] > rhs.arr[3] { u8::MAX } else { 0 },
+          if self.arr[4] > rhs.arr[4] { u8::MAX } else { 0 },
+          if self.arr[5] > rhs.arr[5] { u8::MAX } else { 0 },
+          if self.arr[6] > rhs.arr[6] { u8::MAX } else { 0 },
+          if self.arr[7] > rhs.arr[7] { u8::MAX } else { 0 },
+          if self.arr[8] > rhs.arr[8] { u8::MAX } else { 0 },
+          if self.arr[9] > rhs.arr[9] { u8::MAX } else { 0 },
+          if self.arr[10] > rhs.arr[10] { u8::MAX } else { 0 },
+          if self.arr[11] > rhs.arr[11] { u8::MAX } else { 0 },
+          if self.arr[12] > rhs.arr[12] { u8::MAX } else { 0 },
+          if self.arr[13] > rhs.arr[13] { u8::MAX } else { 0 },
+          if self.arr[14] > rhs.arr[14] { u8::MAX } else { 0 },
+          if self.arr[15] > rhs.arr[15] { u8::MAX } else { 0 },
+        ]}
+      }
+    }
+  }
+}
+
+impl CmpLt for u8x16 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_lt(self, rhs: Self) -> Self::Output {
+    rhs.cmp_gt(self)
+  }
+}
+
+impl CmpGe for u8x16 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_ge(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: cmp_eq_mask_i8_m128i(max_u8_m128i(self.sse, rhs.sse), self.sse) }
+      } else {
+        !self.cmp_lt(rhs)
+      }
+    }
+  }
+}
+
+impl CmpLe for u8x16 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_le(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: cmp_eq_mask_i8_m128i(min_u8_m128i(self.sse, rhs.sse), self.sse) }
+      } else {
+        !self.cmp_gt(rhs)
+      }
+    }
+  }
+}
+
+impl CmpNe for u8x16 {
+  type Output = Self;
+  #[inline]
+  #[must_use]
+  fn cmp_ne(self, rhs: Self) -> Self::Output {
+    !self.cmp_eq(rhs)
+  }
+}
+
+impl u8x16 {
+  #[inline]
+  #[must_use]
+  pub fn move_mask(self) -> i32 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        move_mask_i8_m128i(self.sse)
+      }
+      else {
+        ((self.arr[0] >> 7) as i32) << 0 |
+        ((self.arr[1] >> 7) as i32) << 1 |
+        ((self.arr[2] >> 7) as i32) << 2 |
+        ((self.arr[3] >> 7) as i32) << 3 |
+        ((self.arr[4] >> 7) as i32) << 4 |
+        ((self.arr[5] >> 7) as i32) << 5 |
+        ((self.arr[6] >> 7) as i32) << 6 |
+        ((self.arr[7] >> 7) as i32) << 7 |
+        ((self.arr[8] >> 7) as i32) << 8 |
+        ((self.arr[9] >> 7) as i32) << 9 |
+        ((self.arr[10] >> 7) as i32) << 10 |
+        ((self.arr[11] >> 7) as i32) << 11 |
+        ((self.arr[12] >> 7) as i32) << 12 |
+        ((self.arr[13] >> 7) as i32) << 13 |
+        ((self.arr[14] >> 7) as i32) << 14 |
+        ((self.arr[15] >> 7) as i32) << 15
+      }
+    }
+  }
+  #[inline]
+  #[must_use]
+  pub fn any(self) -> bool {
+    self.move_mask() != 0
+  }
+  #[inline]
+  #[must_use]
+  pub fn all(self) -> bool {
+    // sixteen lanes
+    self.move_mask() == 0b1111111111111111
+  }
+  #[inline]
+  #[must_use]
+  pub fn none(self) -> bool {
+    !self.any()
+  }
   #[inline]
   #[must_use]
   pub fn blend(self, t: Self, f: Self) -> Self {
@@ -271,4 +534,263 @@ impl u8x16 {
       }
     }
   }
+  /// Clamps each lane to `[min, max]`.
+  #[inline]
+  #[must_use]
+  pub fn clamp(self, min: Self, max: Self) -> Self {
+    self.max(min).min(max)
+  }
+  /// The smallest lane.
+  #[inline]
+  #[must_use]
+  pub fn reduce_min(self) -> u8 {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        // min of each pair of bytes, which also zeroes the high byte of each
+        // u16 so that `phminposuw` can finish it off
+        let pairs = min_u8_m128i(self.sse, shr_imm_u16_m128i!(self.sse, 8));
+        get_i32_from_m128i_s(min_position_u16_m128i(pairs)) as u8
+      } else {
+        let folded: [u8; 16] = cast(fold_lanes_128(self, 8, |a, b| a.min(b)));
+        folded[0]
+      }
+    }
+  }
+  /// The largest lane.
+  #[inline]
+  #[must_use]
+  pub fn reduce_max(self) -> u8 {
+    !(!self).reduce_min()
+  }
+  /// Shifts each lane left by the count in the same lane of `counts`.
+  ///
+  /// Counts of 8 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shl_each(self, counts: u8x16) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // shift by each bit of the count in turn, then zero the lanes with
+        // counts that are too large
+        let mut x = self;
+        for i in 0..3 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step as u8);
+          x = (counts & bit).cmp_eq(bit).blend(x << step, x);
+        }
+        x & (counts & Self::splat(!7)).cmp_eq(Self::default())
+      } else {
+        let arr: [u8; 16] = cast(self);
+        let counts: [u8; 16] = cast(counts);
+        cast([
+          if counts[0] < 8 { arr[0] << counts[0] } else { 0 },
+          if counts[1] < 8 { arr[1] << counts[1] } else { 0 },
+          if counts[2] < 8 { arr[2] << counts[2] } else { 0 },
+          if counts[3] < 8 { arr[3] << counts[3] } else { 0 },
+          if counts[4] < 8 { arr[4] << counts[4] } else { 0 },
+          if counts[5] < 8 { arr[5] << counts[5] } else { 0 },
+          if counts[6] < 8 { arr[6] << counts[6] } else { 0 },
+          if counts[7] < 8 { arr[7] << counts[7] } else { 0 },
+          if counts[8] < 8 { arr[8] << counts[8] } else { 0 },
+          if counts[9] < 8 { arr[9] << counts[9] } else { 0 },
+          if counts[10] < 8 { arr[10] << counts[10] } else { 0 },
+          if counts[11] < 8 { arr[11] << counts[11] } else { 0 },
+          if counts[12] < 8 { arr[12] << counts[12] } else { 0 },
+          if counts[13] < 8 { arr[13] << counts[13] } else { 0 },
+          if counts[14] < 8 { arr[14] << counts[14] } else { 0 },
+          if counts[15] < 8 { arr[15] << counts[15] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Shifts each lane right by the count in the same lane of `counts`.
+  ///
+  /// Counts of 8 or more give 0.
+  #[inline]
+  #[must_use]
+  pub fn shr_each(self, counts: u8x16) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // shift by each bit of the count in turn, then zero the lanes with
+        // counts that are too large
+        let mut x = self;
+        for i in 0..3 {
+          let step = 1_u32 << i;
+          let bit = Self::splat(step as u8);
+          x = (counts & bit).cmp_eq(bit).blend(x >> step, x);
+        }
+        x & (counts & Self::splat(!7)).cmp_eq(Self::default())
+      } else {
+        let arr: [u8; 16] = cast(self);
+        let counts: [u8; 16] = cast(counts);
+        cast([
+          if counts[0] < 8 { arr[0] >> counts[0] } else { 0 },
+          if counts[1] < 8 { arr[1] >> counts[1] } else { 0 },
+          if counts[2] < 8 { arr[2] >> counts[2] } else { 0 },
+          if counts[3] < 8 { arr[3] >> counts[3] } else { 0 },
+          if counts[4] < 8 { arr[4] >> counts[4] } else { 0 },
+          if counts[5] < 8 { arr[5] >> counts[5] } else { 0 },
+          if counts[6] < 8 { arr[6] >> counts[6] } else { 0 },
+          if counts[7] < 8 { arr[7] >> counts[7] } else { 0 },
+          if counts[8] < 8 { arr[8] >> counts[8] } else { 0 },
+          if counts[9] < 8 { arr[9] >> counts[9] } else { 0 },
+          if counts[10] < 8 { arr[10] >> counts[10] } else { 0 },
+          if counts[11] < 8 { arr[11] >> counts[11] } else { 0 },
+          if counts[12] < 8 { arr[12] >> counts[12] } else { 0 },
+          if counts[13] < 8 { arr[13] >> counts[13] } else { 0 },
+          if counts[14] < 8 { arr[14] >> counts[14] } else { 0 },
+          if counts[15] < 8 { arr[15] >> counts[15] } else { 0 },
+        ])
+      }
+    }
+  }
+  /// Adds each lane, clamping to the range of `u8` instead of wrapping.
+  #[inline]
+  #[must_use]
+  pub fn saturating_add(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: add_saturating_u8_m128i(self.sse, rhs.sse) }
+      } else {
+        let arr: [u8; 16] = cast(self);
+        let rhs: [u8; 16] = cast(rhs);
+        cast([
+          arr[0].saturating_add(rhs[0]),
+          arr[1].saturating_add(rhs[1]),
+          arr[2].saturating_add(rhs[2]),
+          arr[3].saturating_add(rhs[3]),
+          arr[4].saturating_add(rhs[4]),
+          arr[5].saturating_add(rhs[5]),
+          arr[6].saturating_add(rhs[6]),
+          arr[7].saturating_add(rhs[7]),
+          arr[8].saturating_add(rhs[8]),
+          arr[9].saturating_add(rhs[9]),
+          arr[10].saturating_add(rhs[10]),
+          arr[11].saturating_add(rhs[11]),
+          arr[12].saturating_add(rhs[12]),
+          arr[13].saturating_add(rhs[13]),
+          arr[14].saturating_add(rhs[14]),
+          arr[15].saturating_add(rhs[15]),
+        ])
+      }
+    }
+  }
+  /// Subtracts each lane, clamping to the range of `u8` instead of wrapping.
+  #[inline]
+  #[must_use]
+  pub fn saturating_sub(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        Self { sse: sub_saturating_u8_m128i(self.sse, rhs.sse) }
+      } else {
+        let arr: [u8; 16] = cast(self);
+        let rhs: [u8; 16] = cast(rhs);
+        cast([
+          arr[0].saturating_sub(rhs[0]),
+          arr[1].saturating_sub(rhs[1]),
+          arr[2].saturating_sub(rhs[2]),
+          arr[3].saturating_sub(rhs[3]),
+          arr[4].saturating_sub(rhs[4]),
+          arr[5].saturating_sub(rhs[5]),
+          arr[6].saturating_sub(rhs[6]),
+          arr[7].saturating_sub(rhs[7]),
+          arr[8].saturating_sub(rhs[8]),
+          arr[9].saturating_sub(rhs[9]),
+          arr[10].saturating_sub(rhs[10]),
+          arr[11].saturating_sub(rhs[11]),
+          arr[12].saturating_sub(rhs[12]),
+          arr[13].saturating_sub(rhs[13]),
+          arr[14].saturating_sub(rhs[14]),
+          arr[15].saturating_sub(rhs[15]),
+        ])
+      }
+    }
+  }
+  /// Looks up each lane of `idx` in `self` as a 16 entry table, so lane `i`
+  /// is `self[idx[i]]`, or 0 if `idx[i]` is 16 or more.
+  ///
+  /// This is a `pshufb` with SSSE3. SSE2 has no byte shuffle with indices
+  /// from a register, so without SSSE3 each lane is looked up in scalar code.
+  #[inline]
+  #[must_use]
+  pub fn swizzle(self, idx: u8x16) -> Self {
+    pick! {
+      if #[cfg(target_feature="ssse3")] {
+        // push indices of 16 or more up to the high bit, which zeroes the lane
+        let idx = add_saturating_u8_m128i(idx.sse, set_splat_i8_m128i(0x70));
+        Self { sse: shuffle_av_i8z_all_m128i(self.sse, idx) }
+      } else {
+        let arr: [u8; 16] = cast(self);
+        let idx: [u8; 16] = cast(idx);
+        let lookup = |i: u8| if i < 16 { arr[usize::from(i)] } else { 0 };
+        cast([
+          lookup(idx[0]),
+          lookup(idx[1]),
+          lookup(idx[2]),
+          lookup(idx[3]),
+          lookup(idx[4]),
+          lookup(idx[5]),
+          lookup(idx[6]),
+          lookup(idx[7]),
+          lookup(idx[8]),
+          lookup(idx[9]),
+          lookup(idx[10]),
+          lookup(idx[11]),
+          lookup(idx[12]),
+          lookup(idx[13]),
+          lookup(idx[14]),
+          lookup(idx[15]),
+        ])
+      }
+    }
+  }
+  /// Like [`swizzle`](Self::swizzle), but lanes with an index of 16 or more
+  /// are unspecified. This is a single `pshufb` with SSSE3.
+  #[inline]
+  #[must_use]
+  pub fn swizzle_relaxed(self, idx: u8x16) -> Self {
+    pick! {
+      if #[cfg(target_feature="ssse3")] {
+        Self { sse: shuffle_av_i8z_all_m128i(self.sse, idx.sse) }
+      } else {
+        self.swizzle(idx)
+      }
+    }
+  }
+  /// Zero extends the low 8 lanes to `u16`.
+  #[inline]
+  #[must_use]
+  pub fn widen_low(self) -> u16x8 {
+    pick! {
+      if #[cfg(target_feature="sse4.1")] {
+        cast(convert_to_u16_m128i_from_lower8_u8_m128i(self.sse))
+      } else if #[cfg(target_feature="sse2")] {
+        cast(unpack_low_i8_m128i(self.sse, zeroed_m128i()))
+      } else {
+        let a: [u8; 16] = cast(self);
+        let mut out = [0_u16; 8];
+        for (o, &x) in out.iter_mut().zip(a[..8].iter()) {
+          *o = u16::from(x);
+        }
+        u16x8::from(out)
+      }
+    }
+  }
+  /// Zero extends the high 8 lanes to `u16`.
+  #[inline]
+  #[must_use]
+  pub fn widen_high(self) -> u16x8 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        cast(unpack_high_i8_m128i(self.sse, zeroed_m128i()))
+      } else {
+        let a: [u8; 16] = cast(self);
+        let mut out = [0_u16; 8];
+        for (o, &x) in out.iter_mut().zip(a[8..].iter()) {
+          *o = u16::from(x);
+        }
+        u16x8::from(out)
+      }
+    }
+  }
 }
@@ -752,3 +752,1187 @@ fn impl_f32x4_sum() {
   let duration = now.elapsed().as_micros();
   println!("Time take {} {}us", sum2, duration);
 }
+
+fn check_rounding_f32x4(op: fn(f32x4) -> f32x4, reference: fn(f32) -> f32) {
+  let values = [
+    0.0,
+    -0.0,
+    0.5,
+    -0.5,
+    1.5,
+    -1.5,
+    2.0,
+    -2.7,
+    0.99999,
+    -0.99999,
+    f32::MIN_POSITIVE,
+    -f32::MIN_POSITIVE,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+    -1.0,
+    8388607.5,
+    -8388607.5,
+    8388608.0,
+    16777215.0,
+    3e9,
+    -3e9,
+    1e30,
+    -1e30,
+  ];
+  for chunk in values.chunks(4) {
+    let mut a = [0.0; 4];
+    a.copy_from_slice(chunk);
+    let actual: [f32; 4] = cast(op(f32x4::from(a)));
+    for (x, y) in a.iter().zip(actual.iter()) {
+      let expected = reference(*x);
+      if expected.is_nan() {
+        assert!(y.is_nan(), "{}", x);
+      } else {
+        assert_eq!(expected.to_bits(), y.to_bits(), "{}", x);
+      }
+    }
+  }
+}
+
+#[test]
+fn impl_f32x4_trunc() {
+  check_rounding_f32x4(f32x4::trunc, f32::trunc);
+}
+
+#[test]
+fn impl_f32x4_floor() {
+  check_rounding_f32x4(f32x4::floor, f32::floor);
+}
+
+#[test]
+fn impl_f32x4_ceil() {
+  check_rounding_f32x4(f32x4::ceil, f32::ceil);
+}
+
+#[test]
+fn impl_f32x4_fract() {
+  check_rounding_f32x4(f32x4::fract, f32::fract);
+}
+
+/// Distance between `a` and `b` in units in the last place, with NaN only
+/// matching NaN.
+fn ulps_f32(a: f32, b: f32) -> u64 {
+  if a.is_nan() || b.is_nan() {
+    return if a.is_nan() && b.is_nan() { 0 } else { u64::MAX };
+  }
+  let ordered = |x: f32| {
+    let i = x.to_bits() as i32;
+    if i < 0 {
+      i32::MIN as i64 - i as i64
+    } else {
+      i as i64
+    }
+  };
+  (ordered(a) - ordered(b)).abs() as u64
+}
+
+/// Checks `op` against `reference` (in f64) over `lo..=hi` and at the special
+/// values, allowing 4 ulps of error.
+fn check_ulps_f32x4(
+  op: fn(f32x4) -> f32x4,
+  reference: fn(f64) -> f64,
+  lo: f32,
+  hi: f32,
+) {
+  const MAX_ULPS: u64 = 4;
+  let mut values = vec![
+    0.0,
+    -0.0,
+    f32::MIN_POSITIVE,
+    -f32::MIN_POSITIVE,
+    1.0,
+    -1.0,
+    f32::MAX,
+    f32::MIN,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+  ];
+  let steps = 4000;
+  for i in 0..=steps {
+    values.push(lo + (hi - lo) * i as f32 / steps as f32);
+  }
+  while values.len() % 4 != 0 {
+    values.push(0.0);
+  }
+  for chunk in values.chunks(4) {
+    let mut a = [0.0; 4];
+    a.copy_from_slice(chunk);
+    let actual: [f32; 4] = cast(op(f32x4::from(a)));
+    for (x, y) in a.iter().zip(actual.iter()) {
+      let expected = reference(*x as f64) as f32;
+      let ulps = ulps_f32(expected, *y);
+      assert!(ulps <= MAX_ULPS, "{}: {} vs {}", x, y, expected);
+    }
+  }
+}
+
+#[test]
+fn impl_f32x4_sinh() {
+  check_ulps_f32x4(f32x4::sinh, f64::sinh, -2.0, 2.0);
+  check_ulps_f32x4(f32x4::sinh, f64::sinh, -95.0, 95.0);
+}
+
+#[test]
+fn impl_f32x4_cosh() {
+  check_ulps_f32x4(f32x4::cosh, f64::cosh, -2.0, 2.0);
+  check_ulps_f32x4(f32x4::cosh, f64::cosh, -95.0, 95.0);
+}
+
+#[test]
+fn impl_f32x4_tanh() {
+  check_ulps_f32x4(f32x4::tanh, f64::tanh, -1.0, 1.0);
+  check_ulps_f32x4(f32x4::tanh, f64::tanh, -12.0, 12.0);
+}
+
+#[test]
+fn impl_f32x4_asinh() {
+  // std's asinh overflows early, and older versions lose precision near 0
+  fn asinh(x: f64) -> f64 {
+    let a = x.abs();
+    let r = if a > 1e100 {
+      a.ln() + core::f64::consts::LN_2
+    } else {
+      (a + a * a / (1.0 + (1.0 + a * a).sqrt())).ln_1p()
+    };
+    r.copysign(x)
+  }
+  check_ulps_f32x4(f32x4::asinh, asinh, -2.0, 2.0);
+  check_ulps_f32x4(f32x4::asinh, asinh, -1e12, 1e12);
+}
+
+#[test]
+fn impl_f32x4_acosh() {
+  // std's acosh loses precision just above 1
+  fn acosh(x: f64) -> f64 {
+    let t = x - 1.0;
+    if x < 1.0 {
+      f64::NAN
+    } else if x > 1e100 {
+      x.ln() + core::f64::consts::LN_2
+    } else {
+      (t + (2.0 * t + t * t).sqrt()).ln_1p()
+    }
+  }
+  check_ulps_f32x4(f32x4::acosh, acosh, 0.5, 3.0);
+  check_ulps_f32x4(f32x4::acosh, acosh, 1.0, 1e12);
+}
+
+#[test]
+fn impl_f32x4_atanh() {
+  // std's atanh loses precision close to 1
+  fn atanh(x: f64) -> f64 {
+    let a = x.abs();
+    (0.5 * (2.0 * a / (1.0 - a)).ln_1p()).copysign(x)
+  }
+  check_ulps_f32x4(f32x4::atanh, atanh, -1.5, 1.5);
+  check_ulps_f32x4(f32x4::atanh, atanh, 0.999, 1.0);
+}
+
+#[test]
+fn impl_f32x4_exp2() {
+  check_ulps_f32x4(f32x4::exp2, f64::exp2, -1.0, 1.0);
+  check_ulps_f32x4(f32x4::exp2, f64::exp2, -160.0, 130.0);
+  // whole powers are exact, subnormals included
+  for i in -149..128 {
+    let actual: [f32; 4] = cast(f32x4::splat(i as f32).exp2());
+    assert_eq!(actual[0], (i as f64).exp2() as f32);
+  }
+}
+
+#[test]
+fn impl_f32x4_exp10() {
+  fn exp10(x: f64) -> f64 {
+    10f64.powf(x)
+  }
+  check_ulps_f32x4(f32x4::exp10, exp10, -1.0, 1.0);
+  check_ulps_f32x4(f32x4::exp10, exp10, -47.0, 40.0);
+}
+
+#[test]
+fn impl_f32x4_expm1() {
+  check_ulps_f32x4(f32x4::expm1, f64::exp_m1, -1e-5, 1e-5);
+  check_ulps_f32x4(f32x4::expm1, f64::exp_m1, -1.0, 1.0);
+  check_ulps_f32x4(f32x4::expm1, f64::exp_m1, -40.0, 90.0);
+  let actual: [f32; 4] = cast(f32x4::splat(-0.0).expm1());
+  assert!(actual[0].is_sign_negative());
+}
+
+#[test]
+fn impl_f32x4_ln_1p() {
+  check_ulps_f32x4(f32x4::ln_1p, f64::ln_1p, -1e-5, 1e-5);
+  check_ulps_f32x4(f32x4::ln_1p, f64::ln_1p, -1.0, 1.0);
+  check_ulps_f32x4(f32x4::ln_1p, f64::ln_1p, -1.0, 1e6);
+  let actual: [f32; 4] = cast(f32x4::splat(-0.0).ln_1p());
+  assert!(actual[0].is_sign_negative());
+}
+
+#[test]
+fn impl_f32x4_cbrt() {
+  check_ulps_f32x4(f32x4::cbrt, f64::cbrt, -10.0, 10.0);
+  check_ulps_f32x4(f32x4::cbrt, f64::cbrt, -1e30, 1e30);
+  check_ulps_f32x4(
+    f32x4::cbrt,
+    f64::cbrt,
+    -f32::MIN_POSITIVE,
+    f32::MIN_POSITIVE,
+  );
+  // perfect cubes come out exact
+  for i in -100..=100 {
+    let actual: [f32; 4] = cast(f32x4::splat((i * i * i) as f32).cbrt());
+    assert_eq!(actual[0], i as f32);
+  }
+}
+
+#[test]
+fn impl_f32x4_hypot() {
+  let values = [
+    0.0,
+    -0.0,
+    3.0,
+    -4.0,
+    1.5e-3,
+    f32::MIN_POSITIVE,
+    f32::MIN_POSITIVE / 1024.0,
+    1e30,
+    -1e30,
+    f32::MAX,
+  ];
+  for x in values.iter() {
+    for y in values.iter() {
+      let actual: [f32; 4] = cast(f32x4::splat(*x).hypot(f32x4::splat(*y)));
+      let expected = (*x as f64).hypot(*y as f64) as f32;
+      assert!(ulps_f32(expected, actual[0]) <= 1, "{} {}", x, y);
+    }
+  }
+  let inf = f32x4::splat(f32::INFINITY);
+  let nan = f32x4::splat(f32::NAN);
+  let actual: [f32; 4] = cast(inf.hypot(nan));
+  assert_eq!(actual[0], f32::INFINITY);
+  let actual: [f32; 4] = cast(f32x4::ONE.hypot(nan));
+  assert!(actual[0].is_nan());
+}
+
+#[test]
+fn impl_f32x4_powi() {
+  // powers that fit come out exact
+  for k in 0..=10 {
+    let actual: [f32; 4] = cast(f32x4::splat(2.5).powi(k));
+    assert_eq!(actual[0], (5.0 as f32).powi(k) / (2.0 as f32).powi(k));
+    let actual: [f32; 4] = cast(f32x4::splat(-3.0).powi(k));
+    assert_eq!(actual[0], (-3i64).pow(k as u32) as f32);
+    let actual: [f32; 4] = cast(f32x4::splat(2.0).powi(-k));
+    assert_eq!(actual[0], 1.0 / (1u64 << k) as f32);
+  }
+  let actual: [f32; 4] = cast(f32x4::splat(f32::NAN).powi(0));
+  assert_eq!(actual[0], 1.0);
+  let actual: [f32; 4] = cast(f32x4::splat(-0.0).powi(-3));
+  assert_eq!(actual[0], f32::NEG_INFINITY);
+  let actual: [f32; 4] = cast(f32x4::splat(10.0).powi(400));
+  assert_eq!(actual[0], f32::INFINITY);
+}
+
+fn check_table_f32x4(
+  op: fn(f32x4) -> f32x4,
+  table: &[(f64, f64)],
+  max_ulps: u64,
+) {
+  for &(x, expected) in table.iter() {
+    let actual: [f32; 4] = cast(op(f32x4::splat(x as f32)));
+    let ulps = ulps_f32(expected as f32, actual[0]);
+    assert!(ulps <= max_ulps, "{}: {} vs {}", x, actual[0], expected);
+  }
+}
+
+#[test]
+fn impl_f32x4_erf() {
+  let table = [
+    (-6.0, -1.0, 2.0),
+    (-3.5, -0.9999992569016276, 1.9999992569016276),
+    (-1.375, -0.9481700727820903, 1.9481700727820903),
+    (-0.875, -0.7840750610598597, 1.7840750610598597),
+    (-0.5, -0.5204998778130465, 1.5204998778130465),
+    (-0.0078125, -0.008815282895179188, 1.0088152828951793),
+    (0.125, 0.1403162048013338, 0.8596837951986662),
+    (0.625, 0.623240882188418, 0.376759117811582),
+    (0.8644400238990784, 0.7784824564532417, 0.22151754354675834),
+    (0.875, 0.7840750610598597, 0.21592493894014034),
+    (0.949999988079071, 0.8208908018180313, 0.17910919818196874),
+    (1.25, 0.9229001282564583, 0.07709987174354177),
+    (2.0, 0.9953222650189527, 0.004677734981047266),
+    (3.75, 0.9999998862727434, 1.1372725656979665e-07),
+    (5.875, 0.9999999999999999, 9.691555645277176e-17),
+    (9.5, 1.0, 3.7692144856548797e-41),
+  ];
+  let erf: Vec<(f64, f64)> = table.iter().map(|&(x, y, _)| (x, y)).collect();
+  let erfc: Vec<(f64, f64)> = table.iter().map(|&(x, _, y)| (x, y)).collect();
+  check_table_f32x4(f32x4::erf, &erf, 2);
+  check_table_f32x4(f32x4::erfc, &erfc, 4);
+  let special = [
+    (f32::INFINITY, 1.0, 0.0),
+    (f32::NEG_INFINITY, -1.0, 2.0),
+    (0.0, 0.0, 1.0),
+  ];
+  for &(x, erf, erfc) in special.iter() {
+    let a: [f32; 4] = cast(f32x4::splat(x).erf());
+    let b: [f32; 4] = cast(f32x4::splat(x).erfc());
+    assert_eq!((a[0], b[0]), (erf, erfc));
+  }
+  let a: [f32; 4] = cast(f32x4::splat(-0.0).erf());
+  assert!(a[0] == 0.0 && a[0].is_sign_negative());
+  let a: [f32; 4] = cast(f32x4::splat(f32::NAN).erf());
+  let b: [f32; 4] = cast(f32x4::splat(f32::NAN).erfc());
+  assert!(a[0].is_nan() && b[0].is_nan());
+}
+
+#[test]
+fn impl_f32x4_norm_cdf() {
+  let table = [
+    (-37.5, 4.605353009581955e-308),
+    (-20.0, 2.7536241186062337e-89),
+    (-8.5, 9.479534822203318e-18),
+    (-1.375, 0.08456572235133572),
+    (-0.5, 0.3085375387259869),
+    (0.0, 0.5),
+    (0.25, 0.5987063256829237),
+    (1.5, 0.9331927987311419),
+    (3.0, 0.9986501019683699),
+    (6.0, 0.9999999990134123),
+  ];
+  check_table_f32x4(f32x4::norm_cdf, &table, 4);
+  let a: [f32; 4] = cast(f32x4::splat(f32::NEG_INFINITY).norm_cdf());
+  let b: [f32; 4] = cast(f32x4::splat(f32::INFINITY).norm_cdf());
+  let c: [f32; 4] = cast(f32x4::splat(f32::NAN).norm_cdf());
+  assert!(a[0] == 0.0 && b[0] == 1.0 && c[0].is_nan());
+}
+
+#[test]
+fn impl_f32x4_norm_inv_cdf() {
+  let table = [
+    (7.174648137343064e-43, -13.674855035788477),
+    (1.1754943508222875e-38, -12.949952701187204),
+    (8.673617379884035e-19, -8.773321169027552),
+    (9.313225746154785e-10, -6.009353565530744),
+    (0.0009765625, -3.0972690781987846),
+    (0.015625, -2.1538746940614564),
+    (0.0625, -1.5341205443525463),
+    (0.25, -0.6744897501960817),
+    (0.5, 0.0),
+    (0.625, 0.31863936396437514),
+    (0.875, 1.150349380376008),
+    (0.96875, 1.8627318674216515),
+  ];
+  check_table_f32x4(f32x4::norm_inv_cdf, &table, 6);
+  let a: [f32; 4] = cast(f32x4::splat(0.0).norm_inv_cdf());
+  let b: [f32; 4] = cast(f32x4::splat(1.0).norm_inv_cdf());
+  assert_eq!((a[0], b[0]), (f32::NEG_INFINITY, f32::INFINITY));
+  for &p in [-0.5, 1.5, f32::NAN].iter() {
+    let a: [f32; 4] = cast(f32x4::splat(p).norm_inv_cdf());
+    assert!(a[0].is_nan());
+  }
+}
+
+#[test]
+fn impl_f32x4_gamma() {
+  let table = [
+    (-30.375, -3.550239849503311e-33),
+    (-4.5, -0.060019601300504245),
+    (-2.75, -1.0044979832303123),
+    (-1.5, 2.363271801207355),
+    (-0.375, -3.8253835949081516),
+    (-0.0078125, -128.584998524804),
+    (0.0078125, 127.43045641142966),
+    (0.25, 3.625609908221908),
+    (0.5, 1.772453850905516),
+    (1.5, 0.886226925452758),
+    (2.5, 1.329340388179137),
+    (3.375, 2.9028583742757985),
+    (7.75, 3057.8226711926072),
+    (13.125, 657257524.5501475),
+    (29.5, 1.6348125198274267e+30),
+    (34.875, 1.8968534323108322e+38),
+  ];
+  for &(x, expected) in table.iter() {
+    let actual: [f32; 4] = cast(f32x4::splat(x).gamma());
+    // large arguments are only as accurate as `pow_f32x4`, which needs FMA
+    // to be exact
+    let err = ((actual[0] - expected) / expected).abs();
+    assert!(err < 2e-5, "{}: {} vs {}", x, actual[0], expected);
+  }
+  // the small factorials come out exact
+  let mut factorial = 1.0;
+  for n in 1..=10 {
+    let actual: [f32; 4] = cast(f32x4::splat(n as f32).gamma());
+    assert_eq!(actual[0], factorial);
+    factorial *= n as f32;
+  }
+  let special = [
+    (0.0, f32::INFINITY),
+    (-0.0, f32::NEG_INFINITY),
+    (f32::INFINITY, f32::INFINITY),
+    (36.0, f32::INFINITY),
+  ];
+  for &(x, expected) in special.iter() {
+    let actual: [f32; 4] = cast(f32x4::splat(x).gamma());
+    assert_eq!(actual[0], expected);
+  }
+  for &x in [-1.0, -2.0, -100.0, f32::NEG_INFINITY, f32::NAN].iter() {
+    let actual: [f32; 4] = cast(f32x4::splat(x).gamma());
+    assert!(actual[0].is_nan());
+  }
+}
+
+#[test]
+fn impl_f32x4_ln_gamma() {
+  let table: &[(f32, f32, f32)] = &[
+    (-170.625, -1.0, -708.562013351724),
+    (-30.375, -1.0, -74.71829290435811),
+    (-4.5, -1.0, -2.813084081769316),
+    (-2.75, -1.0, 0.004487897535955773),
+    (-2.457025, -1.0, -4.74226e-07),
+    (-1.5, 1.0, 0.860047015376481),
+    (-0.375, -1.0, 1.3416587485006664),
+    (-0.0078125, -1.0, 4.856590152781422),
+    (0.0078125, 1.0, 4.847570775881665),
+    (0.25, 1.0, 1.2880225246980774),
+    (0.5, 1.0, 0.5723649429247001),
+    (1.5, 1.0, -0.12078223763524522),
+    (2.5, 1.0, 0.2846828704729192),
+    (3.375, 1.0, 1.0656958978640603),
+    (7.75, 1.0, 8.025458396315983),
+    (13.125, 1.0, 20.30358647004452),
+    (29.5, 1.0, 69.56908092082364),
+    (34.875, 1.0, 88.13842995885862),
+    (100.25, 1.0, 360.28455963776423),
+    (170.5, 1.0, 704.0044277342047),
+  ];
+  for &(x, expected_sign, expected) in table.iter() {
+    let (y, sign) = f32x4::splat(x).ln_gamma();
+    let y: [f32; 4] = cast(y);
+    let sign: [f32; 4] = cast(sign);
+    assert_eq!(sign[0], expected_sign);
+    // near its zeros ln_gamma can only be close in absolute terms
+    if expected.abs() > 1.0 {
+      assert!(ulps_f32(y[0], expected) <= 8, "{}: {} vs {}", x, y[0], expected);
+    } else {
+      assert!((y[0] - expected).abs() <= 8.0 * f32::EPSILON);
+    }
+  }
+  let table = [
+    (1.2676506002282294e+30, 8.659919334810373e+31),
+    (1.401298464324817e-45, 103.27892990343184),
+  ];
+  check_table_f32x4(|x| x.ln_gamma().0, &table, 8);
+  for &x in [1.0, 2.0].iter() {
+    let (y, _) = f32x4::splat(x).ln_gamma();
+    let y: [f32; 4] = cast(y);
+    assert_eq!(y[0], 0.0);
+  }
+  let special = [
+    (0.0, f32::INFINITY, 1.0),
+    (-0.0, f32::INFINITY, -1.0),
+    (-3.0, f32::INFINITY, 1.0),
+    (f32::INFINITY, f32::INFINITY, 1.0),
+    (f32::NEG_INFINITY, f32::INFINITY, 1.0),
+  ];
+  for &(x, expected, expected_sign) in special.iter() {
+    let (y, sign) = f32x4::splat(x).ln_gamma();
+    let y: [f32; 4] = cast(y);
+    let sign: [f32; 4] = cast(sign);
+    assert_eq!((y[0], sign[0]), (expected, expected_sign));
+  }
+  let (y, _) = f32x4::splat(f32::NAN).ln_gamma();
+  let y: [f32; 4] = cast(y);
+  assert!(y[0].is_nan());
+}
+
+#[test]
+fn impl_f32x4_ln_gamma_near_1_and_2() {
+  let table = [
+    (0.9999990463256836, 5.504765026737053e-07),
+    (1.0000011920928955, -6.880935245096623e-07),
+    (0.875, 0.08585870722533433),
+    (1.0625, -0.03295710029357782),
+    (1.125, -0.06002318412603958),
+    (1.7347999811172485, -0.08807410705095996),
+    (1.875, -0.0476726853991883),
+    (1.9999995231628418, -2.015992075606289e-07),
+    (2.0000009536743164, 4.0319885504430156e-07),
+    (2.125, 0.057759851530343874),
+    (2.216900110244751, 0.10622764785498551),
+  ];
+  check_table_f32x4(|x| x.ln_gamma().0, &table, 4);
+}
+
+fn check_pairs_f32x4(
+  op: fn(f32x4, f32x4) -> f32x4,
+  reference: fn(f32, f32) -> f32,
+) {
+  let values = [
+    0.0,
+    -0.0,
+    1.0,
+    -1.5,
+    7.25,
+    0.1,
+    core::f32::consts::PI,
+    -1e30,
+    3e-39,
+    f32::MIN_POSITIVE,
+    f32::MAX,
+    -f32::MAX,
+    f32::INFINITY,
+    f32::NAN,
+  ];
+  for x in values.iter() {
+    for y in values.iter() {
+      let actual: [f32; 4] = cast(op(f32x4::splat(*x), f32x4::splat(*y)));
+      let expected = reference(*x, *y);
+      assert!(
+        actual[0].to_bits() == expected.to_bits()
+          || (actual[0].is_nan() && expected.is_nan()),
+        "{}, {}: {} vs {}",
+        x,
+        y,
+        actual[0],
+        expected
+      );
+    }
+  }
+}
+
+#[test]
+fn impl_rem_for_f32x4() {
+  check_pairs_f32x4(|a, b| a % b, |a, b| a % b);
+}
+
+#[test]
+fn impl_f32x4_rem_euclid() {
+  check_pairs_f32x4(f32x4::rem_euclid, f32::rem_euclid);
+}
+
+#[test]
+fn impl_f32x4_div_euclid() {
+  check_pairs_f32x4(f32x4::div_euclid, f32::div_euclid);
+}
+
+#[test]
+fn impl_f32x4_frexp() {
+  let table = [
+    (1.0, 0.5, 1),
+    (-3.0, -0.75, 2),
+    (0.10000000149011612, 0.800000011920929, -3),
+    (1.0000000150474662e+30, 0.7888609170913696, 100),
+    (3.4028234663852886e+38, 0.9999999403953552, 128),
+    (1.1754943508222875e-38, 0.5, -125),
+    (9.99994610111476e-41, 0.5444488525390625, -132),
+    (-1.401298464324817e-45, -0.5, -148),
+  ];
+  for &(x, m, exp) in table.iter() {
+    let (actual_m, actual_exp) = f32x4::splat(x).frexp();
+    assert_eq!(actual_m, f32x4::splat(m));
+    assert_eq!(actual_exp, i32x4::from(exp));
+  }
+  for &x in [0.0, -0.0, f32::INFINITY, f32::NEG_INFINITY].iter() {
+    let (m, exp) = f32x4::splat(x).frexp();
+    let m: [f32; 4] = cast(m);
+    assert_eq!(m[0].to_bits(), x.to_bits());
+    assert_eq!(exp, i32x4::from(0));
+  }
+  let (m, exp) = f32x4::splat(f32::NAN).frexp();
+  assert!(m.is_nan().all());
+  assert_eq!(exp, i32x4::from(0));
+}
+
+#[test]
+fn impl_f32x4_ldexp() {
+  let table = [
+    (1.0, 127, 1.7014118346046923e+38),
+    (1.0, 128, f32::INFINITY),
+    (1.5, -149, 2.802596928649634e-45),
+    (1.0, -150, 0.0),
+    (1.5, -150, 1.401298464324817e-45),
+    (3.0, -151, 1.401298464324817e-45),
+    (1.0000001192092896, -140, 7.174648137343064e-43),
+    (9.99994610111476e-41, 200, 1.6069293830420667e+20),
+    (3.4028234663852886e+38, -277, 1.401298464324817e-45),
+    (1.0, -1000, 0.0),
+    (-1.0, 1000, f32::NEG_INFINITY),
+    (9.99994610111476e-41, 30, 1.073736036651265e-31),
+  ];
+  for &(x, exp, expected) in table.iter() {
+    let actual: [f32; 4] = cast(f32x4::splat(x).ldexp(i32x4::from(exp)));
+    assert_eq!(actual[0], expected, "{} * 2^{}", x, exp);
+    let actual: [f32; 4] = cast(f32x4::splat(x).scalbn(exp as i32));
+    assert_eq!(actual[0], expected, "{} * 2^{}", x, exp);
+  }
+  for &x in [0.0, -0.0, f32::INFINITY, f32::NEG_INFINITY].iter() {
+    let actual: [f32; 4] = cast(f32x4::splat(x).ldexp(i32x4::from(-7)));
+    assert_eq!(actual[0].to_bits(), x.to_bits());
+  }
+  assert!(f32x4::splat(f32::NAN).ldexp(i32x4::from(-7)).is_nan().all());
+}
+
+#[test]
+fn impl_f32x4_ilogb() {
+  let table = [
+    (1.0, 0),
+    (-3.0, 1),
+    (0.10000000149011612, -4),
+    (1.0000000150474662e+30, 99),
+    (3.4028234663852886e+38, 127),
+    (1.1754943508222875e-38, -126),
+    (9.99994610111476e-41, -133),
+    (-1.401298464324817e-45, -149),
+  ];
+  for &(x, expected) in table.iter() {
+    assert_eq!(f32x4::splat(x).ilogb(), i32x4::from(expected));
+  }
+  assert_eq!(f32x4::splat(0.0).ilogb(), i32x4::from(i32::MIN));
+  assert_eq!(f32x4::splat(f32::NAN).ilogb(), i32x4::from(i32::MIN));
+  assert_eq!(f32x4::splat(f32::NEG_INFINITY).ilogb(), i32x4::from(i32::MAX));
+}
+
+// `f32::next_up` and `f32::total_cmp` are newer than our minimum Rust version
+fn next_up_f32(x: f32) -> f32 {
+  if x.is_nan() || x == f32::INFINITY {
+    x
+  } else if x == 0.0 {
+    f32::from_bits(1)
+  } else if x > 0.0 {
+    f32::from_bits(x.to_bits() + 1)
+  } else {
+    f32::from_bits(x.to_bits() - 1)
+  }
+}
+
+fn next_down_f32(x: f32) -> f32 {
+  -next_up_f32(-x)
+}
+
+fn total_lt_f32(x: f32, y: f32) -> bool {
+  let key = |x: f32| {
+    let i = x.to_bits() as i32;
+    i ^ (((i >> 31) as u32) >> 1) as i32
+  };
+  key(x) < key(y)
+}
+
+#[test]
+fn impl_f32x4_next_up() {
+  let values = [
+    0.0,
+    -0.0,
+    1.0,
+    -1.0,
+    f32::from_bits(1),
+    -f32::from_bits(1),
+    f32::MIN_POSITIVE,
+    -f32::MIN_POSITIVE,
+    f32::MAX,
+    -f32::MAX,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+  ];
+  for x in values.iter() {
+    let up: [f32; 4] = cast(f32x4::splat(*x).next_up());
+    let down: [f32; 4] = cast(f32x4::splat(*x).next_down());
+    assert!(
+      up[0].to_bits() == next_up_f32(*x).to_bits() || x.is_nan(),
+      "{}",
+      x
+    );
+    assert!(
+      down[0].to_bits() == next_down_f32(*x).to_bits() || x.is_nan(),
+      "{}",
+      x
+    );
+    assert_eq!(up[0].is_nan(), x.is_nan());
+    assert_eq!(down[0].is_nan(), x.is_nan());
+  }
+}
+
+#[test]
+fn impl_f32x4_next_after() {
+  check_pairs_f32x4(
+    |x, y| x.next_after(y),
+    |x, y| {
+      if x.is_nan() || y.is_nan() {
+        x + y
+      } else if x == y {
+        y
+      } else if y > x {
+        next_up_f32(x)
+      } else {
+        next_down_f32(x)
+      }
+    },
+  );
+}
+
+#[test]
+fn impl_f32x4_ulp() {
+  check_pairs_f32x4(
+    |x, _| x.ulp(),
+    |x, _| {
+      let a = x.abs();
+      if a == f32::MAX {
+        a - next_down_f32(a)
+      } else if a.is_infinite() {
+        a
+      } else {
+        next_up_f32(a) - a
+      }
+    },
+  );
+  let expected: [f32; 4] = cast(f32x4::splat(f32::EPSILON));
+  assert_eq!(cast::<_, [f32; 4]>(f32x4::ONE.ulp()), expected);
+}
+
+#[test]
+fn impl_f32x4_total_cmp() {
+  check_pairs_f32x4(
+    |x, y| x.total_lt(y),
+    |x, y| if total_lt_f32(x, y) { f32::from_bits(u32::MAX) } else { 0.0 },
+  );
+  check_pairs_f32x4(
+    |x, y| x.total_max(y),
+    |x, y| if total_lt_f32(x, y) { y } else { x },
+  );
+  check_pairs_f32x4(
+    |x, y| x.total_min(y),
+    |x, y| if total_lt_f32(y, x) { y } else { x },
+  );
+  let neg_nan = f32x4::splat(-f32::NAN);
+  let lt: [u32; 4] = cast(neg_nan.total_lt(f32x4::splat(f32::NEG_INFINITY)));
+  assert_eq!(lt, [u32::MAX; 4]);
+  let min: [u32; 4] = cast(neg_nan.total_min(f32x4::splat(f32::NAN)));
+  assert_eq!(min, [(-f32::NAN).to_bits(); 4]);
+}
+
+#[test]
+fn impl_f32x4_sin_cos_big() {
+  // the lanes of each vector need different reductions, and the expected
+  // values are correctly rounded
+  let table: [(f32, f32, f32); 8] = [
+    (8192.5, -0.69874424, 0.7153716),
+    (-1e5, -0.0357488, -0.9993608),
+    (3e7, 0.9641303, -0.2654294),
+    (-3.0, -0.14112, -0.9899925),
+    (1e10, -0.48750603, 0.87311965),
+    (-6.2e15, 0.9009643, 0.43389314),
+    (1e30, -0.79116344, -0.6116048),
+    (f32::MAX, -0.5218765, 0.853021),
+  ];
+  for rows in table.chunks(4) {
+    let mut x = [0.0; 4];
+    for (lane, row) in x.iter_mut().zip(rows.iter()) {
+      *lane = row.0;
+    }
+    let (s, c) = f32x4::from(x).sin_cos();
+    let (s, c): ([f32; 4], [f32; 4]) = (cast(s), cast(c));
+    for (i, &(x, sin, cos)) in rows.iter().enumerate() {
+      assert!(ulps_f32(s[i], sin) <= 2, "{}: {} vs {}", x, s[i], sin);
+      assert!(ulps_f32(c[i], cos) <= 2, "{}: {} vs {}", x, c[i], cos);
+    }
+  }
+}
+
+#[test]
+fn impl_f32x4_sin_cos_pi() {
+  let table: &[(f32, f64, f64, f64)] = &[
+    (0.125, 0.3826834323650898, 0.9238795325112867, 0.41421356237309503),
+    (0.3125, 0.8314696123025452, 0.5555702330196022, 1.496605762665489),
+    (0.71875, 0.773010453362737, -0.6343932841636455, -1.2185035255879764),
+    (-1.328125, 0.8577286100002721, -0.5141027441932218, -1.668399205583507),
+    (2.75, 0.7071067811865476, -0.7071067811865476, -1.0),
+    (10.203125, 0.5956993044924334, 0.8032075314806449, 0.7416505462720354),
+    (-1000.125, -0.3826834323650898, 0.9238795325112867, -0.41421356237309503),
+    (123456.75, 0.7071067811865476, -0.7071067811865476, -1.0),
+    (
+      0.0009765625,
+      0.003067956762965976,
+      0.9999952938095762,
+      0.003067971201422665,
+    ),
+    (
+      3.000000238418579,
+      -7.490140565847157e-07,
+      -0.9999999999997194,
+      7.490140565849258e-07,
+    ),
+    (
+      -0.4999999701976776,
+      -0.9999999999999957,
+      9.362675707309808e-08,
+      -10680707.430881713,
+    ),
+  ];
+  for &(x, s, c, t) in table.iter() {
+    check_table_f32x4(f32x4::sin_pi, &[(x.into(), s)], 2);
+    check_table_f32x4(f32x4::cos_pi, &[(x.into(), c)], 2);
+    check_table_f32x4(f32x4::tan_pi, &[(x.into(), t)], 4);
+  }
+  // exact at the integers and half-integers, with the zero signs IEEE 754
+  // asks for
+  let exact = [
+    (0.0, 0.0, 1.0),
+    (-0.0, -0.0, 1.0),
+    (0.5, 1.0, 0.0),
+    (-0.5, -1.0, 0.0),
+    (1.0, 0.0, -1.0),
+    (-1.0, -0.0, -1.0),
+    (1.5, -1.0, 0.0),
+    (-7.5, 1.0, 0.0),
+    (8388609.0, 0.0, -1.0),
+    (1e30, 0.0, 1.0),
+    (-f32::MAX, -0.0, 1.0),
+  ];
+  for &(x, s, c) in exact.iter() {
+    let (sa, ca) = f32x4::splat(x).sin_cos_pi();
+    assert_eq!(cast::<_, [u32; 4]>(sa), [f32::to_bits(s); 4], "{}", x);
+    assert_eq!(cast::<_, [u32; 4]>(ca), [f32::to_bits(c); 4], "{}", x);
+  }
+  let inf = f32x4::splat(f32::INFINITY);
+  assert_eq!(f32x4::splat(0.5).tan_pi(), inf);
+  assert_eq!(f32x4::splat(1.5).tan_pi(), -inf);
+  assert!(f32x4::splat(f32::NAN).sin_pi().is_nan().all());
+  assert!(inf.cos_pi().is_nan().all());
+}
+
+#[test]
+fn impl_f32x4_fast_max_min() {
+  check_pairs_f32x4(|x, y| x.fast_max(y), |x, y| if x > y { x } else { y });
+  check_pairs_f32x4(|x, y| x.fast_min(y), |x, y| if x < y { x } else { y });
+}
+
+#[test]
+fn impl_f32x4_max_num_min_num() {
+  check_pairs_f32x4(
+    |x, y| x.max_num(y),
+    |x, y| {
+      if x == y {
+        f32::from_bits(x.to_bits() & y.to_bits())
+      } else {
+        x.max(y)
+      }
+    },
+  );
+  check_pairs_f32x4(
+    |x, y| x.min_num(y),
+    |x, y| {
+      if x == y {
+        f32::from_bits(x.to_bits() | y.to_bits())
+      } else {
+        x.min(y)
+      }
+    },
+  );
+}
+
+#[test]
+fn impl_f32x4_maximum_minimum() {
+  check_pairs_f32x4(
+    |x, y| x.maximum(y),
+    |x, y| {
+      if x.is_nan() || y.is_nan() {
+        f32::NAN
+      } else if x == y {
+        f32::from_bits(x.to_bits() & y.to_bits())
+      } else {
+        x.max(y)
+      }
+    },
+  );
+  check_pairs_f32x4(
+    |x, y| x.minimum(y),
+    |x, y| {
+      if x.is_nan() || y.is_nan() {
+        f32::NAN
+      } else if x == y {
+        f32::from_bits(x.to_bits() | y.to_bits())
+      } else {
+        x.min(y)
+      }
+    },
+  );
+}
+
+#[test]
+fn impl_f32x4_clamp() {
+  let values = [
+    -3.0,
+    -1.5,
+    -0.0,
+    0.0,
+    0.5,
+    2.0,
+    7.0,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+  ];
+  // `f32::clamp` is newer than our minimum Rust version
+  let clamp = |x: f32, min: f32, max: f32| {
+    if x < min {
+      min
+    } else if x > max {
+      max
+    } else {
+      x
+    }
+  };
+  for &x in values.iter() {
+    let v = f32x4::splat(x);
+    let clamped: [f32; 4] =
+      cast(v.clamp(f32x4::splat(-1.5), f32x4::splat(2.0)));
+    let saturated: [f32; 4] = cast(v.saturate());
+    for (actual, expected) in
+      [(clamped[0], clamp(x, -1.5, 2.0)), (saturated[0], clamp(x, 0.0, 1.0))]
+        .iter()
+    {
+      assert!(
+        actual.to_bits() == expected.to_bits()
+          || (actual.is_nan() && expected.is_nan()),
+        "{}: {} vs {}",
+        x,
+        actual,
+        expected
+      );
+    }
+  }
+  // NaN bounds are ignored
+  let nan = f32x4::splat(f32::NAN);
+  assert_eq!(
+    f32x4::splat(3.0).clamp(nan, f32x4::splat(2.0)),
+    f32x4::splat(2.0)
+  );
+  assert_eq!(
+    f32x4::splat(-3.0).clamp(f32x4::splat(-1.0), nan),
+    f32x4::splat(-1.0)
+  );
+}
+
+#[test]
+fn impl_f32x4_lerp() {
+  let a = f32x4::splat(2.0);
+  let b = f32x4::splat(10.0);
+  let table = [
+    (0.0, 2.0),
+    (0.25, 4.0),
+    (0.5, 6.0),
+    (1.0, 10.0),
+    (2.0, 18.0),
+    (-0.5, -2.0),
+  ];
+  for &(t, expected) in table.iter() {
+    assert_eq!(a.lerp(b, f32x4::splat(t)), f32x4::splat(expected));
+    assert_eq!(
+      f32x4::inverse_lerp(a, b, f32x4::splat(expected)),
+      f32x4::splat(t)
+    );
+  }
+  // the ends are exact even when b - a rounds
+  let a = f32x4::splat(0.1);
+  let b = f32x4::splat(1e10);
+  assert_eq!(a.lerp(b, f32x4::ZERO), a);
+  assert_eq!(a.lerp(b, f32x4::ONE), b);
+  let x = f32x4::splat(-5.0);
+  let remapped = x.remap(
+    f32x4::ZERO,
+    f32x4::splat(10.0),
+    f32x4::splat(100.0),
+    f32x4::splat(200.0),
+  );
+  assert_eq!(remapped, f32x4::splat(50.0));
+}
+
+#[test]
+fn impl_f32x4_smoothstep() {
+  let table = [
+    (0.0, 0.0, 0.0),
+    (1.0, 0.0, 0.0),
+    (1.5, 0.15625, 0.103515625),
+    (2.0, 0.5, 0.5),
+    (2.5, 0.84375, 0.896484375),
+    (3.0, 1.0, 1.0),
+    (10.0, 1.0, 1.0),
+  ];
+  let (edge0, edge1) = (f32x4::ONE, f32x4::splat(3.0));
+  for &(x, smooth, smoother) in table.iter() {
+    let x = f32x4::splat(x);
+    assert_eq!(x.smoothstep(edge0, edge1), f32x4::splat(smooth));
+    assert_eq!(x.smootherstep(edge0, edge1), f32x4::splat(smoother));
+  }
+  let nan = f32x4::splat(f32::NAN);
+  assert!(nan.smoothstep(edge0, edge1).is_nan().all());
+  assert!(nan.smootherstep(edge0, edge1).is_nan().all());
+}
+
+#[test]
+fn impl_f32x4_reductions() {
+  // small enough that every product is exact, whatever the order
+  let a = f32x4::from([-3.0, 2.0, 0.5, 1.25]);
+  assert_eq!(a.reduce_min(), -3.0);
+  assert_eq!(a.reduce_max(), 2.0);
+  assert_eq!(a.reduce_mul(), -3.75);
+  let a = f32x4::from([1.25, -1.0, 4.0, 0.75]);
+  assert_eq!(a.reduce_min(), -1.0);
+  assert_eq!(a.reduce_max(), 4.0);
+  assert_eq!(a.reduce_mul(), -3.75);
+  let a = f32x4::from([4.0, 0.75, 1.5, 6.0]);
+  assert_eq!(a.reduce_min(), 0.75);
+  assert_eq!(a.reduce_max(), 6.0);
+  assert_eq!(a.reduce_mul(), 27.0);
+  // NaN lanes are skipped unless they're all NaN, and -0.0 is below 0.0
+  let nan = f32::NAN;
+  let a = f32x4::from([nan, nan, nan, 2.0]);
+  assert_eq!(a.reduce_min(), 2.0);
+  assert_eq!(a.reduce_max(), 2.0);
+  assert!(f32x4::splat(nan).reduce_min().is_nan());
+  assert!(f32x4::splat(nan).reduce_max().is_nan());
+  let a = f32x4::from([0.0, 0.0, 0.0, -0.0]);
+  assert_eq!(a.reduce_min().to_bits(), (-0.0 as f32).to_bits());
+  assert_eq!(a.reduce_max().to_bits(), (0.0 as f32).to_bits());
+}
+
+#[test]
+fn impl_f32x4_lane_queries() {
+  let a = f32x4::from([2.0, 2.0, -1.0, 2.0]);
+  assert_eq!(a.argmin(), (-1.0, 2));
+  assert_eq!(a.argmax(), (2.0, 0));
+  let mask = a.cmp_eq(f32x4::splat(2.0));
+  assert_eq!(mask.first_set(), Some(0));
+  assert_eq!(mask.last_set(), Some(3));
+  assert_eq!(mask.count_set(), 3);
+  let a = f32x4::from([1.25, 0.75, -3.0, 1.25]);
+  assert_eq!(a.argmin(), (-3.0, 2));
+  assert_eq!(a.argmax(), (1.25, 0));
+  let mask = a.cmp_eq(f32x4::splat(2.0));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  let a = f32x4::from([-1.0, 2.0, 2.0, -1.0]);
+  assert_eq!(a.argmin(), (-1.0, 0));
+  assert_eq!(a.argmax(), (2.0, 1));
+  let mask = a.cmp_eq(f32x4::splat(2.0));
+  assert_eq!(mask.first_set(), Some(1));
+  assert_eq!(mask.last_set(), Some(2));
+  assert_eq!(mask.count_set(), 2);
+  // NaN lanes are skipped unless they're all NaN
+  let nan = f32::NAN;
+  let a = f32x4::from([nan, nan, nan, 2.0]);
+  assert_eq!(a.argmin(), (2.0, 3));
+  assert_eq!(a.argmax(), (2.0, 3));
+  let (min, i) = f32x4::splat(nan).argmin();
+  assert!(min.is_nan() && i == 0);
+  assert_eq!(f32x4::splat(0.0).cmp_lt(f32x4::splat(1.0)).count_set(), 4);
+  assert_eq!(f32x4::splat(1.0).cmp_lt(f32x4::splat(0.0)).first_set(), None);
+}
+
+#[test]
+fn impl_f32x4_shuffles() {
+  let v = f32x4::from([1.0, 2.0, 3.0, 4.0]);
+  let w = f32x4::from([5.0, 6.0, 7.0, 8.0]);
+  assert_eq!(
+    shuffle!(f32x4, v, [1, 0, 3, 2]),
+    f32x4::from([2.0, 1.0, 4.0, 3.0])
+  );
+  assert_eq!(shuffle!(f32x4, v, [3, 3, 3, 3]), f32x4::splat(4.0));
+  assert_eq!(
+    shuffle2!(f32x4, v, w, [2, 7, 4, 1]),
+    f32x4::from([3.0, 8.0, 5.0, 2.0])
+  );
+  assert_eq!(v.reverse(), f32x4::from([4.0, 3.0, 2.0, 1.0]));
+  assert_eq!(
+    rotate_lanes_left!(f32x4, v, 0),
+    f32x4::from([1.0, 2.0, 3.0, 4.0])
+  );
+  assert_eq!(
+    rotate_lanes_right!(f32x4, v, 0),
+    f32x4::from([1.0, 2.0, 3.0, 4.0])
+  );
+  assert_eq!(shift_lanes_left!(f32x4, v, 0), f32x4::from([1.0, 2.0, 3.0, 4.0]));
+  assert_eq!(
+    shift_lanes_right!(f32x4, v, 0),
+    f32x4::from([1.0, 2.0, 3.0, 4.0])
+  );
+  assert_eq!(
+    rotate_lanes_left!(f32x4, v, 1),
+    f32x4::from([2.0, 3.0, 4.0, 1.0])
+  );
+  assert_eq!(
+    rotate_lanes_right!(f32x4, v, 1),
+    f32x4::from([4.0, 1.0, 2.0, 3.0])
+  );
+  assert_eq!(shift_lanes_left!(f32x4, v, 1), f32x4::from([2.0, 3.0, 4.0, 0.0]));
+  assert_eq!(
+    shift_lanes_right!(f32x4, v, 1),
+    f32x4::from([0.0, 1.0, 2.0, 3.0])
+  );
+  assert_eq!(
+    rotate_lanes_left!(f32x4, v, 3),
+    f32x4::from([4.0, 1.0, 2.0, 3.0])
+  );
+  assert_eq!(
+    rotate_lanes_right!(f32x4, v, 3),
+    f32x4::from([2.0, 3.0, 4.0, 1.0])
+  );
+  assert_eq!(shift_lanes_left!(f32x4, v, 3), f32x4::from([4.0, 0.0, 0.0, 0.0]));
+  assert_eq!(
+    shift_lanes_right!(f32x4, v, 3),
+    f32x4::from([0.0, 0.0, 0.0, 1.0])
+  );
+  assert_eq!(
+    rotate_lanes_left!(f32x4, v, 4),
+    f32x4::from([1.0, 2.0, 3.0, 4.0])
+  );
+  assert_eq!(
+    rotate_lanes_right!(f32x4, v, 4),
+    f32x4::from([1.0, 2.0, 3.0, 4.0])
+  );
+  assert_eq!(shift_lanes_left!(f32x4, v, 4), f32x4::from([0.0, 0.0, 0.0, 0.0]));
+  assert_eq!(
+    shift_lanes_right!(f32x4, v, 4),
+    f32x4::from([0.0, 0.0, 0.0, 0.0])
+  );
+}
+
+#[test]
+fn impl_f32x4_zip_unzip() {
+  let a = f32x4::from([1.0, 2.0, 3.0, 4.0]);
+  let b = f32x4::from([5.0, 6.0, 7.0, 8.0]);
+  assert_eq!(a.zip_lo(b), f32x4::from([1.0, 5.0, 2.0, 6.0]));
+  assert_eq!(a.zip_hi(b), f32x4::from([3.0, 7.0, 4.0, 8.0]));
+  assert_eq!(a.unzip_even(b), f32x4::from([1.0, 3.0, 5.0, 7.0]));
+  assert_eq!(a.unzip_odd(b), f32x4::from([2.0, 4.0, 6.0, 8.0]));
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+}
+
+#[test]
+fn impl_f32x4_transpose4() {
+  let mut m = [[f32::default(); 4]; 4];
+  let mut mt = [[f32::default(); 4]; 4];
+  for i in 0..4 {
+    for j in 0..4 {
+      m[i][j] = (i * 4 + j) as f32;
+      mt[j][i] = m[i][j];
+    }
+  }
+  let mut rows = [f32x4::default(); 4];
+  let mut expected = [f32x4::default(); 4];
+  for i in 0..4 {
+    rows[i] = f32x4::from(m[i]);
+    expected[i] = f32x4::from(mt[i]);
+  }
+  assert_eq!(f32x4::transpose4(rows), expected);
+  assert_eq!(f32x4::transpose4(expected), rows);
+}
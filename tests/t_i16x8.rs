@@ -160,3 +160,365 @@ fn impl_i16x8_min() {
   let actual = a.min(b);
   assert_eq!(expected, actual);
 }
+
+#[test]
+fn impl_i16x8_shl_each() {
+  let a: [i16; 8] = [0x1234, -0x1234, i16::MAX, -2, i16::MIN, 1024, -37, 3];
+  let counts: [u16; 8] = [0, 5, 9, 15, 16, 17, 200, u16::MAX];
+  let expected: [i16; 8] = [0x1234, -0x4680, -512, 0, 0, 0, 0, 0];
+  let actual = i16x8::from(a).shl_each(u16x8::from(counts));
+  assert_eq!(i16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_i16x8_shr_each() {
+  let a: [i16; 8] = [0x1234, -0x1234, i16::MAX, -2, i16::MIN, 1024, -37, 3];
+  let counts: [u16; 8] = [0, 5, 9, 15, 16, 17, 200, u16::MAX];
+  let expected: [i16; 8] = [0x1234, -146, 63, -1, -1, 0, -1, 0];
+  let actual = i16x8::from(a).shr_each(u16x8::from(counts));
+  assert_eq!(i16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_i16x8_saturating_add() {
+  let a: [i16; 8] = [i16::MAX, i16::MIN, 300, -300, 1000, -2, 0, 181];
+  let b: [i16; 8] = [1, -1, 200, 200, -1000, i16::MAX, 7, 181];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.saturating_add(*y);
+  }
+  let actual = i16x8::from(a).saturating_add(i16x8::from(b));
+  assert_eq!(i16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_i16x8_saturating_sub() {
+  let a: [i16; 8] = [i16::MAX, i16::MIN, 300, -300, 1000, -2, 0, 181];
+  let b: [i16; 8] = [1, -1, 200, 200, -1000, i16::MAX, 7, 181];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.saturating_sub(*y);
+  }
+  let actual = i16x8::from(a).saturating_sub(i16x8::from(b));
+  assert_eq!(i16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_i16x8_saturating_mul() {
+  let a: [i16; 8] = [i16::MAX, i16::MIN, 300, -300, 1000, -2, 0, 181];
+  let b: [i16; 8] = [1, -1, 200, 200, -1000, i16::MAX, 7, 181];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.saturating_mul(*y);
+  }
+  let actual = i16x8::from(a).saturating_mul(i16x8::from(b));
+  assert_eq!(i16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_div_for_i16x8() {
+  let a: [i16; 8] = [i16::MIN, -7, 7, 100, -100, i16::MAX, 0, 13];
+  let b: [i16; 8] = [-1, 2, -3, 7, 9, -1, 5, 13];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_div(*y);
+  }
+  let actual = i16x8::from(a) / i16x8::from(b);
+  assert_eq!(i16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_rem_for_i16x8() {
+  let a: [i16; 8] = [i16::MIN, -7, 7, 100, -100, i16::MAX, 0, 13];
+  let b: [i16; 8] = [-1, 2, -3, 7, 9, -1, 5, 13];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_rem(*y);
+  }
+  let actual = i16x8::from(a) % i16x8::from(b);
+  assert_eq!(i16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_i16x8_divisor() {
+  let nums: [i16; 16] =
+    [i16::MIN, -1, 0, 1, 6, 7, 8, -8, i16::MAX, -13, 99, 100, 2, 3, 50, -50];
+  // numerators next to the extremes and at or near powers of two
+  let edges: [i16; 8] =
+    [i16::MIN + 1, i16::MAX - 1, -2, -4, 1024, -1024, 16, -16];
+  let divisors = [7, -7, 3, i16::MIN, 1, -1, 2, -2, 16, -16, 1 << 14, i16::MAX];
+  for d in divisors.iter().copied() {
+    let divisor = Divisor::<i16x8>::new(d);
+    for chunk in nums.chunks(8).chain(edges.chunks(8)) {
+      let mut a = [0; 8];
+      a.copy_from_slice(chunk);
+      let mut q = a;
+      let mut r = a;
+      for ((q, r), x) in q.iter_mut().zip(r.iter_mut()).zip(a.iter()) {
+        *q = x.wrapping_div(d);
+        *r = x.wrapping_rem(d);
+      }
+      assert_eq!(i16x8::from(a) / divisor, i16x8::from(q));
+      assert_eq!(i16x8::from(a) % divisor, i16x8::from(r));
+      assert_eq!(i16x8::from(a) / d, i16x8::from(q));
+      assert_eq!(i16x8::from(a) % d, i16x8::from(r));
+    }
+  }
+}
+
+#[test]
+fn impl_i16x8_clamp() {
+  let min = i16x8::from(-3);
+  let max = i16x8::from(5);
+  let a = i16x8::from([i16::MIN, -5, -4, -3, 0, 5, 6, i16::MAX]);
+  let expected = i16x8::from([-3, -3, -3, -3, 0, 5, 5, 5]);
+  assert_eq!(expected, a.clamp(min, max));
+}
+
+#[test]
+fn impl_i16x8_reductions() {
+  let a = i16x8::from([i16::MIN, 1, 100, -3, -7, 3, i16::MAX, 2]);
+  assert_eq!(a.reduce_min(), i16::MIN);
+  assert_eq!(a.reduce_max(), i16::MAX);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), -97);
+  let a = i16x8::from([3, 5, -7, 9, 11, -13, 15, 17]);
+  assert_eq!(a.reduce_min(), -13);
+  assert_eq!(a.reduce_max(), 17);
+  assert_eq!(a.reduce_mul(), -12511);
+  assert_eq!(a.reduce_and(), 1);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), 16);
+  let a = i16x8::from([6, 6, 6, 6, 6, 6, i16::MAX, i16::MIN]);
+  assert_eq!(a.reduce_min(), i16::MIN);
+  assert_eq!(a.reduce_max(), i16::MAX);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), -1);
+}
+
+#[test]
+fn impl_i16x8_move_mask() {
+  let a = i16x8::from([-5, 5, 5, -5, 5, 5, -5, 5]);
+  assert_eq!(a.move_mask(), 0b01001001);
+  assert!(a.any());
+  assert!(!a.all());
+  assert!(!a.none());
+  assert!(i16x8::splat(-1).all());
+  assert!(i16x8::splat(1).none());
+}
+
+#[test]
+fn impl_i16x8_lane_queries() {
+  let a = i16x8::from([-7, 100, 3, 3, -3, -1, i16::MAX, i16::MIN]);
+  assert_eq!(a.argmin(), (i16::MIN, 7));
+  assert_eq!(a.argmax(), (i16::MAX, 6));
+  let mask = a.cmp_eq(i16x8::splat(3));
+  assert_eq!(mask.first_set(), Some(2));
+  assert_eq!(mask.last_set(), Some(3));
+  assert_eq!(mask.count_set(), 2);
+  let a = i16x8::from([100, -7, 5, 1, 2, 29, i16::MIN, i16::MAX]);
+  assert_eq!(a.argmin(), (i16::MIN, 6));
+  assert_eq!(a.argmax(), (i16::MAX, 7));
+  let mask = a.cmp_eq(i16x8::splat(3));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  let a = i16x8::from([5, -7, 100, 3, 3, -3, -1, i16::MAX]);
+  assert_eq!(a.argmin(), (-7, 1));
+  assert_eq!(a.argmax(), (i16::MAX, 7));
+  let mask = a.cmp_eq(i16x8::splat(3));
+  assert_eq!(mask.first_set(), Some(3));
+  assert_eq!(mask.last_set(), Some(4));
+  assert_eq!(mask.count_set(), 2);
+  assert_eq!(i16x8::splat(3).argmin(), (3, 0));
+  assert_eq!(i16x8::splat(3).argmax(), (3, 0));
+  assert_eq!(i16x8::splat(3).cmp_eq(i16x8::splat(3)).last_set(), Some(7));
+}
+
+#[test]
+fn impl_i16x8_shuffles() {
+  let v = i16x8::from([1, 2, 3, 4, 5, 6, 7, 8]);
+  let w = i16x8::from([9, 10, 11, 12, 13, 14, 15, 16]);
+  assert_eq!(
+    shuffle!(i16x8, v, [1, 4, 7, 2, 5, 0, 3, 6]),
+    i16x8::from([2, 5, 8, 3, 6, 1, 4, 7])
+  );
+  assert_eq!(shuffle!(i16x8, v, [7, 7, 7, 7, 7, 7, 7, 7]), i16x8::splat(8));
+  assert_eq!(
+    shuffle2!(i16x8, v, w, [2, 7, 12, 1, 6, 11, 0, 5]),
+    i16x8::from([3, 8, 13, 2, 7, 12, 1, 6])
+  );
+  assert_eq!(v.reverse(), i16x8::from([8, 7, 6, 5, 4, 3, 2, 1]));
+  assert_eq!(
+    rotate_lanes_left!(i16x8, v, 0),
+    i16x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i16x8, v, 0),
+    i16x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    shift_lanes_left!(i16x8, v, 0),
+    i16x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    shift_lanes_right!(i16x8, v, 0),
+    i16x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i16x8, v, 1),
+    i16x8::from([2, 3, 4, 5, 6, 7, 8, 1])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i16x8, v, 1),
+    i16x8::from([8, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    shift_lanes_left!(i16x8, v, 1),
+    i16x8::from([2, 3, 4, 5, 6, 7, 8, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(i16x8, v, 1),
+    i16x8::from([0, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i16x8, v, 5),
+    i16x8::from([6, 7, 8, 1, 2, 3, 4, 5])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i16x8, v, 5),
+    i16x8::from([4, 5, 6, 7, 8, 1, 2, 3])
+  );
+  assert_eq!(
+    shift_lanes_left!(i16x8, v, 5),
+    i16x8::from([6, 7, 8, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(i16x8, v, 5),
+    i16x8::from([0, 0, 0, 0, 0, 1, 2, 3])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i16x8, v, 7),
+    i16x8::from([8, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i16x8, v, 7),
+    i16x8::from([2, 3, 4, 5, 6, 7, 8, 1])
+  );
+  assert_eq!(
+    shift_lanes_left!(i16x8, v, 7),
+    i16x8::from([8, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(i16x8, v, 7),
+    i16x8::from([0, 0, 0, 0, 0, 0, 0, 1])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i16x8, v, 8),
+    i16x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i16x8, v, 8),
+    i16x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    shift_lanes_left!(i16x8, v, 8),
+    i16x8::from([0, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(i16x8, v, 8),
+    i16x8::from([0, 0, 0, 0, 0, 0, 0, 0])
+  );
+}
+
+#[test]
+fn impl_i16x8_zip_unzip() {
+  let a = i16x8::from([1, 2, 3, 4, 5, 6, 7, 8]);
+  let b = i16x8::from([9, 10, 11, 12, 13, 14, 15, 16]);
+  assert_eq!(a.zip_lo(b), i16x8::from([1, 9, 2, 10, 3, 11, 4, 12]));
+  assert_eq!(a.zip_hi(b), i16x8::from([5, 13, 6, 14, 7, 15, 8, 16]));
+  assert_eq!(a.unzip_even(b), i16x8::from([1, 3, 5, 7, 9, 11, 13, 15]));
+  assert_eq!(a.unzip_odd(b), i16x8::from([2, 4, 6, 8, 10, 12, 14, 16]));
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+  // lanes with the top bit set, which the packs must not saturate
+  let c = i16x8::from([i16::MIN, -1, i16::MAX, -2, 256, -256, 1, 0]);
+  let d = i16x8::from([-3, i16::MAX, i16::MIN, 255, -255, -1, 7, -7]);
+  assert_eq!(
+    c.unzip_even(d),
+    i16x8::from([i16::MIN, i16::MAX, 256, 1, -3, i16::MIN, -255, 7])
+  );
+  assert_eq!(
+    c.unzip_odd(d),
+    i16x8::from([-1, -2, -256, 0, i16::MAX, 255, -1, -7])
+  );
+}
+
+#[test]
+fn impl_i16x8_widen() {
+  let a = i16x8::from([i16::MIN, i16::MAX, 0, 1, 2, 100, i16::MAX - 1, 16384]);
+  assert_eq!(a.widen_low(), i32x4::from([-32768, 32767, 0, 1]));
+  assert_eq!(a.widen_high(), i32x4::from([2, 100, 32766, 16384]));
+  assert_eq!(
+    a.widen(),
+    i32x8::from([-32768, 32767, 0, 1, 2, 100, 32766, 16384])
+  );
+  let a =
+    i16x8::from([-1, -2, -100, i16::MIN + 1, -16385, i16::MIN, i16::MAX, 0]);
+  assert_eq!(a.widen_low(), i32x4::from([-1, -2, -100, -32767]));
+  assert_eq!(a.widen_high(), i32x4::from([-16385, -32768, 32767, 0]));
+  assert_eq!(
+    a.widen(),
+    i32x8::from([-1, -2, -100, -32767, -16385, -32768, 32767, 0])
+  );
+}
+
+#[test]
+fn impl_i16x8_narrow() {
+  let a = i16x8::from([i16::MIN, i16::MAX, 0, 1, 2, 100, i16::MAX - 1, 16384]);
+  let b = i16x8::from([-1, -2, -100, i16::MIN + 1, -16385, 127, 128, 64]);
+  assert_eq!(
+    a.narrow_wrapping(b),
+    i8x16::from([
+      0, -1, 0, 1, 2, 100, -2, 0, -1, -2, -100, 1, -1, 127, -128, 64
+    ])
+  );
+  assert_eq!(
+    a.narrow_saturating(b),
+    i8x16::from([
+      -128, 127, 0, 1, 2, 100, 127, 127, -1, -2, -100, -128, -128, 127, 127, 64
+    ])
+  );
+  let a = i16x8::from([-128, -129, i16::MIN, i16::MAX, 0, 1, 2, 100]);
+  let b =
+    i16x8::from([i16::MAX - 1, 16384, -1, -2, -100, i16::MIN + 1, -16385, 127]);
+  assert_eq!(
+    a.narrow_wrapping(b),
+    i8x16::from([
+      -128, 127, 0, -1, 0, 1, 2, 100, -2, 0, -1, -2, -100, 1, -1, 127
+    ])
+  );
+  assert_eq!(
+    a.narrow_saturating(b),
+    i8x16::from([
+      -128, -128, -128, 127, 0, 1, 2, 100, 127, 127, -1, -2, -100, -128, -128,
+      127
+    ])
+  );
+}
+
+#[test]
+fn impl_i16x8_narrow_saturating_unsigned() {
+  let lo = i16x8::from([i16::MIN, -1, 0, 1, 254, 255, 256, i16::MAX]);
+  let hi = i16x8::from([-256, 127, 128, 200, -2, 300, 255, 0]);
+  assert_eq!(
+    lo.narrow_saturating_unsigned(hi),
+    u8x16::from([
+      0, 0, 0, 1, 254, 255, 255, 255, 0, 127, 128, 200, 0, 255, 255, 0
+    ])
+  );
+}
@@ -151,3 +151,281 @@ fn impl_i32x4_round_float() {
   let actual = a.round_float();
   assert_eq!(expected, actual);
 }
+
+#[test]
+fn impl_i32x4_shl_each() {
+  let table: [([i32; 4], [u32; 4], [i32; 4]); 2] = [
+    (
+      [0x1234_5678, -0x1234_5678, i32::MIN, i32::MAX],
+      [0, 7, 19, 31],
+      [0x1234_5678, -0x1A2B_3C00, 0, i32::MIN],
+    ),
+    ([-37, 0x10_0000, -2, 3], [32, 33, 200, u32::MAX], [0, 0, 0, 0]),
+  ];
+  for &(a, counts, expected) in table.iter() {
+    let actual = i32x4::from(a).shl_each(u32x4::from(counts));
+    assert_eq!(i32x4::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_i32x4_shr_each() {
+  let table: [([i32; 4], [u32; 4], [i32; 4]); 2] = [
+    (
+      [0x1234_5678, -0x1234_5678, i32::MIN, i32::MAX],
+      [0, 7, 19, 31],
+      [0x1234_5678, -0x24_68AD, -4096, 0],
+    ),
+    ([-37, 0x10_0000, -2, 3], [32, 33, 200, u32::MAX], [-1, 0, -1, 0]),
+  ];
+  for &(a, counts, expected) in table.iter() {
+    let actual = i32x4::from(a).shr_each(u32x4::from(counts));
+    assert_eq!(i32x4::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_div_for_i32x4() {
+  let a: [i32; 4] = [i32::MIN, -7, 7, 100];
+  let b: [i32; 4] = [-1, 2, -3, 7];
+  let mut expected = [0; 4];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_div(*y);
+  }
+  let actual = i32x4::from(a) / i32x4::from(b);
+  assert_eq!(i32x4::from(expected), actual);
+}
+
+#[test]
+fn impl_rem_for_i32x4() {
+  let a: [i32; 4] = [i32::MIN, -7, 7, 100];
+  let b: [i32; 4] = [-1, 2, -3, 7];
+  let mut expected = [0; 4];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_rem(*y);
+  }
+  let actual = i32x4::from(a) % i32x4::from(b);
+  assert_eq!(i32x4::from(expected), actual);
+}
+
+#[test]
+fn impl_i32x4_divisor() {
+  let nums: [i32; 16] =
+    [i32::MIN, -1, 0, 1, 6, 7, 8, -8, i32::MAX, -13, 99, 100, 2, 3, 50, -50];
+  // numerators next to the extremes and at or near powers of two
+  let edges: [i32; 8] =
+    [i32::MIN + 1, i32::MAX - 1, -2, -4, 1024, -1024, 16, -16];
+  let divisors = [7, -7, 3, i32::MIN, 1, -1, 2, -2, 16, -16, 1 << 30, i32::MAX];
+  for d in divisors.iter().copied() {
+    let divisor = Divisor::<i32x4>::new(d);
+    for chunk in nums.chunks(4).chain(edges.chunks(4)) {
+      let mut a = [0; 4];
+      a.copy_from_slice(chunk);
+      let mut q = a;
+      let mut r = a;
+      for ((q, r), x) in q.iter_mut().zip(r.iter_mut()).zip(a.iter()) {
+        *q = x.wrapping_div(d);
+        *r = x.wrapping_rem(d);
+      }
+      assert_eq!(i32x4::from(a) / divisor, i32x4::from(q));
+      assert_eq!(i32x4::from(a) % divisor, i32x4::from(r));
+      assert_eq!(i32x4::from(a) / d, i32x4::from(q));
+      assert_eq!(i32x4::from(a) % d, i32x4::from(r));
+    }
+  }
+}
+
+#[test]
+fn impl_i32x4_clamp() {
+  let min = i32x4::from(-3);
+  let max = i32x4::from(5);
+  let a = i32x4::from([i32::MIN, -5, -4, -3]);
+  let expected = i32x4::from([-3, -3, -3, -3]);
+  assert_eq!(expected, a.clamp(min, max));
+  let a = i32x4::from([0, 5, 6, i32::MAX]);
+  let expected = i32x4::from([0, 5, 5, 5]);
+  assert_eq!(expected, a.clamp(min, max));
+}
+
+#[test]
+fn impl_i32x4_reductions() {
+  let a = i32x4::from([i32::MIN, 1, 100, -3]);
+  assert_eq!(a.reduce_min(), i32::MIN);
+  assert_eq!(a.reduce_max(), 100);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), -3);
+  assert_eq!(a.reduce_xor(), i32::MAX - 103);
+  let a = i32x4::from([3, 5, -7, 9]);
+  assert_eq!(a.reduce_min(), -7);
+  assert_eq!(a.reduce_max(), 9);
+  assert_eq!(a.reduce_mul(), -945);
+  assert_eq!(a.reduce_and(), 1);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), -10);
+  let a = i32x4::from([6, 6, i32::MAX, i32::MIN]);
+  assert_eq!(a.reduce_min(), i32::MIN);
+  assert_eq!(a.reduce_max(), i32::MAX);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), -1);
+}
+
+#[test]
+fn impl_i32x4_move_mask() {
+  let a = i32x4::from([-5, 5, 5, -5]);
+  assert_eq!(a.move_mask(), 0b1001);
+  assert!(a.any());
+  assert!(!a.all());
+  assert!(!a.none());
+  assert!(i32x4::splat(-1).all());
+  assert!(i32x4::splat(1).none());
+}
+
+#[test]
+fn impl_i32x4_lane_queries() {
+  let a = i32x4::from([3, 3, 100, -7]);
+  assert_eq!(a.argmin(), (-7, 3));
+  assert_eq!(a.argmax(), (100, 2));
+  let mask = a.cmp_eq(i32x4::splat(3));
+  assert_eq!(mask.first_set(), Some(0));
+  assert_eq!(mask.last_set(), Some(1));
+  assert_eq!(mask.count_set(), 2);
+  let a = i32x4::from([-3, -1, i32::MAX, i32::MIN]);
+  assert_eq!(a.argmin(), (i32::MIN, 3));
+  assert_eq!(a.argmax(), (i32::MAX, 2));
+  let mask = a.cmp_eq(i32x4::splat(3));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  let a = i32x4::from([-1, -3, 3, 3]);
+  assert_eq!(a.argmin(), (-3, 1));
+  assert_eq!(a.argmax(), (3, 2));
+  let mask = a.cmp_eq(i32x4::splat(3));
+  assert_eq!(mask.first_set(), Some(2));
+  assert_eq!(mask.last_set(), Some(3));
+  assert_eq!(mask.count_set(), 2);
+  assert_eq!(i32x4::splat(3).argmin(), (3, 0));
+  assert_eq!(i32x4::splat(3).argmax(), (3, 0));
+  assert_eq!(i32x4::splat(3).cmp_eq(i32x4::splat(3)).last_set(), Some(3));
+}
+
+#[test]
+fn impl_i32x4_shuffles() {
+  let v = i32x4::from([1, 2, 3, 4]);
+  let w = i32x4::from([5, 6, 7, 8]);
+  assert_eq!(shuffle!(i32x4, v, [1, 0, 3, 2]), i32x4::from([2, 1, 4, 3]));
+  assert_eq!(shuffle!(i32x4, v, [3, 3, 3, 3]), i32x4::splat(4));
+  assert_eq!(shuffle2!(i32x4, v, w, [2, 7, 4, 1]), i32x4::from([3, 8, 5, 2]));
+  assert_eq!(v.reverse(), i32x4::from([4, 3, 2, 1]));
+  assert_eq!(rotate_lanes_left!(i32x4, v, 0), i32x4::from([1, 2, 3, 4]));
+  assert_eq!(rotate_lanes_right!(i32x4, v, 0), i32x4::from([1, 2, 3, 4]));
+  assert_eq!(shift_lanes_left!(i32x4, v, 0), i32x4::from([1, 2, 3, 4]));
+  assert_eq!(shift_lanes_right!(i32x4, v, 0), i32x4::from([1, 2, 3, 4]));
+  assert_eq!(rotate_lanes_left!(i32x4, v, 1), i32x4::from([2, 3, 4, 1]));
+  assert_eq!(rotate_lanes_right!(i32x4, v, 1), i32x4::from([4, 1, 2, 3]));
+  assert_eq!(shift_lanes_left!(i32x4, v, 1), i32x4::from([2, 3, 4, 0]));
+  assert_eq!(shift_lanes_right!(i32x4, v, 1), i32x4::from([0, 1, 2, 3]));
+  assert_eq!(rotate_lanes_left!(i32x4, v, 3), i32x4::from([4, 1, 2, 3]));
+  assert_eq!(rotate_lanes_right!(i32x4, v, 3), i32x4::from([2, 3, 4, 1]));
+  assert_eq!(shift_lanes_left!(i32x4, v, 3), i32x4::from([4, 0, 0, 0]));
+  assert_eq!(shift_lanes_right!(i32x4, v, 3), i32x4::from([0, 0, 0, 1]));
+  assert_eq!(rotate_lanes_left!(i32x4, v, 4), i32x4::from([1, 2, 3, 4]));
+  assert_eq!(rotate_lanes_right!(i32x4, v, 4), i32x4::from([1, 2, 3, 4]));
+  assert_eq!(shift_lanes_left!(i32x4, v, 4), i32x4::from([0, 0, 0, 0]));
+  assert_eq!(shift_lanes_right!(i32x4, v, 4), i32x4::from([0, 0, 0, 0]));
+}
+
+#[test]
+fn impl_i32x4_zip_unzip() {
+  let a = i32x4::from([1, 2, 3, 4]);
+  let b = i32x4::from([5, 6, 7, 8]);
+  assert_eq!(a.zip_lo(b), i32x4::from([1, 5, 2, 6]));
+  assert_eq!(a.zip_hi(b), i32x4::from([3, 7, 4, 8]));
+  assert_eq!(a.unzip_even(b), i32x4::from([1, 3, 5, 7]));
+  assert_eq!(a.unzip_odd(b), i32x4::from([2, 4, 6, 8]));
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+}
+
+#[test]
+fn impl_i32x4_transpose4() {
+  let mut m = [[i32::default(); 4]; 4];
+  let mut mt = [[i32::default(); 4]; 4];
+  for i in 0..4 {
+    for j in 0..4 {
+      m[i][j] = (i * 4 + j) as i32;
+      mt[j][i] = m[i][j];
+    }
+  }
+  let mut rows = [i32x4::default(); 4];
+  let mut expected = [i32x4::default(); 4];
+  for i in 0..4 {
+    rows[i] = i32x4::from(m[i]);
+    expected[i] = i32x4::from(mt[i]);
+  }
+  assert_eq!(i32x4::transpose4(rows), expected);
+  assert_eq!(i32x4::transpose4(expected), rows);
+}
+
+#[test]
+fn impl_i32x4_widen() {
+  let a = i32x4::from([i32::MIN, i32::MAX, 0, 1]);
+  assert_eq!(a.widen_low(), i64x2::from([-2147483648, 2147483647]));
+  assert_eq!(a.widen_high(), i64x2::from([0, 1]));
+  assert_eq!(a.widen(), i64x4::from([-2147483648, 2147483647, 0, 1]));
+  let a = i32x4::from([2, 100, i32::MAX - 1, 1073741824]);
+  assert_eq!(a.widen_low(), i64x2::from([2, 100]));
+  assert_eq!(a.widen_high(), i64x2::from([2147483646, 1073741824]));
+  assert_eq!(a.widen(), i64x4::from([2, 100, 2147483646, 1073741824]));
+  let a = i32x4::from([-1, -2, -100, i32::MIN + 1]);
+  assert_eq!(a.widen_low(), i64x2::from([-1, -2]));
+  assert_eq!(a.widen_high(), i64x2::from([-100, -2147483647]));
+  assert_eq!(a.widen(), i64x4::from([-1, -2, -100, -2147483647]));
+  let a = i32x4::from([-1073741825, i32::MIN, i32::MAX, 0]);
+  assert_eq!(a.widen_low(), i64x2::from([-1073741825, -2147483648]));
+  assert_eq!(a.widen_high(), i64x2::from([2147483647, 0]));
+  assert_eq!(a.widen(), i64x4::from([-1073741825, -2147483648, 2147483647, 0]));
+}
+
+#[test]
+fn impl_i32x4_narrow() {
+  let a = i32x4::from([i32::MIN, i32::MAX, 0, 1]);
+  let b = i32x4::from([2, 100, i32::MAX - 1, 1073741824]);
+  assert_eq!(a.narrow_wrapping(b), i16x8::from([0, -1, 0, 1, 2, 100, -2, 0]));
+  assert_eq!(
+    a.narrow_saturating(b),
+    i16x8::from([i16::MIN, i16::MAX, 0, 1, 2, 100, i16::MAX, i16::MAX])
+  );
+  let a = i32x4::from([-1, -2, -100, i32::MIN + 1]);
+  let b = i32x4::from([-1073741825, 32767, 32768, 16384]);
+  assert_eq!(
+    a.narrow_wrapping(b),
+    i16x8::from([-1, -2, -100, 1, -1, i16::MAX, i16::MIN, 16384])
+  );
+  assert_eq!(
+    a.narrow_saturating(b),
+    i16x8::from([-1, -2, -100, i16::MIN, i16::MIN, i16::MAX, i16::MAX, 16384])
+  );
+  let a = i32x4::from([-32768, -32769, i32::MIN, i32::MAX]);
+  let b = i32x4::from([0, 1, 2, 100]);
+  assert_eq!(
+    a.narrow_wrapping(b),
+    i16x8::from([i16::MIN, i16::MAX, 0, -1, 0, 1, 2, 100])
+  );
+  assert_eq!(
+    a.narrow_saturating(b),
+    i16x8::from([i16::MIN, i16::MIN, i16::MIN, i16::MAX, 0, 1, 2, 100])
+  );
+}
+
+#[test]
+fn impl_i32x4_narrow_saturating_unsigned() {
+  let lo = i32x4::from([i32::MIN, -1, 0, 65535]);
+  let hi = i32x4::from([65536, 65534, i32::MAX, 32768]);
+  assert_eq!(
+    lo.narrow_saturating_unsigned(hi),
+    u16x8::from([0, 0, 0, 65535, 65535, 65534, 65535, 32768])
+  );
+}
@@ -177,3 +177,349 @@ fn impl_i32x8_round_float() {
   let actual = a.round_float();
   assert_eq!(expected, actual);
 }
+
+#[test]
+fn impl_i32x8_shl_each() {
+  let a: [i32; 8] =
+    [0x1234_5678, -0x1234_5678, i32::MIN, i32::MAX, -37, 0x10_0000, -2, 3];
+  let counts: [u32; 8] = [0, 7, 19, 31, 32, 33, 200, u32::MAX];
+  let expected: [i32; 8] = [0x1234_5678, -0x1A2B_3C00, 0, i32::MIN, 0, 0, 0, 0];
+  let actual = i32x8::from(a).shl_each(u32x8::from(counts));
+  assert_eq!(i32x8::from(expected), actual);
+}
+
+#[test]
+fn impl_i32x8_shr_each() {
+  let a: [i32; 8] =
+    [0x1234_5678, -0x1234_5678, i32::MIN, i32::MAX, -37, 0x10_0000, -2, 3];
+  let counts: [u32; 8] = [0, 7, 19, 31, 32, 33, 200, u32::MAX];
+  let expected: [i32; 8] = [0x1234_5678, -0x24_68AD, -4096, 0, -1, 0, -1, 0];
+  let actual = i32x8::from(a).shr_each(u32x8::from(counts));
+  assert_eq!(i32x8::from(expected), actual);
+}
+
+#[test]
+fn impl_div_for_i32x8() {
+  let a: [i32; 8] = [i32::MIN, -7, 7, 100, -100, i32::MAX, 0, 13];
+  let b: [i32; 8] = [-1, 2, -3, 7, 9, -1, 5, 13];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_div(*y);
+  }
+  let actual = i32x8::from(a) / i32x8::from(b);
+  assert_eq!(i32x8::from(expected), actual);
+}
+
+#[test]
+fn impl_rem_for_i32x8() {
+  let a: [i32; 8] = [i32::MIN, -7, 7, 100, -100, i32::MAX, 0, 13];
+  let b: [i32; 8] = [-1, 2, -3, 7, 9, -1, 5, 13];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_rem(*y);
+  }
+  let actual = i32x8::from(a) % i32x8::from(b);
+  assert_eq!(i32x8::from(expected), actual);
+}
+
+#[test]
+fn impl_i32x8_divisor() {
+  let nums: [i32; 16] =
+    [i32::MIN, -1, 0, 1, 6, 7, 8, -8, i32::MAX, -13, 99, 100, 2, 3, 50, -50];
+  // numerators next to the extremes and at or near powers of two
+  let edges: [i32; 8] =
+    [i32::MIN + 1, i32::MAX - 1, -2, -4, 1024, -1024, 16, -16];
+  let divisors = [7, -7, 3, i32::MIN, 1, -1, 2, -2, 16, -16, 1 << 30, i32::MAX];
+  for d in divisors.iter().copied() {
+    let divisor = Divisor::<i32x8>::new(d);
+    for chunk in nums.chunks(8).chain(edges.chunks(8)) {
+      let mut a = [0; 8];
+      a.copy_from_slice(chunk);
+      let mut q = a;
+      let mut r = a;
+      for ((q, r), x) in q.iter_mut().zip(r.iter_mut()).zip(a.iter()) {
+        *q = x.wrapping_div(d);
+        *r = x.wrapping_rem(d);
+      }
+      assert_eq!(i32x8::from(a) / divisor, i32x8::from(q));
+      assert_eq!(i32x8::from(a) % divisor, i32x8::from(r));
+      assert_eq!(i32x8::from(a) / d, i32x8::from(q));
+      assert_eq!(i32x8::from(a) % d, i32x8::from(r));
+    }
+  }
+}
+
+#[test]
+fn impl_i32x8_clamp() {
+  let min = i32x8::from(-3);
+  let max = i32x8::from(5);
+  let a = i32x8::from([i32::MIN, -5, -4, -3, 0, 5, 6, i32::MAX]);
+  let expected = i32x8::from([-3, -3, -3, -3, 0, 5, 5, 5]);
+  assert_eq!(expected, a.clamp(min, max));
+}
+
+#[test]
+fn impl_i32x8_reductions() {
+  let a = i32x8::from([i32::MIN, 1, 100, -3, -7, 3, i32::MAX, 2]);
+  assert_eq!(a.reduce_min(), i32::MIN);
+  assert_eq!(a.reduce_max(), i32::MAX);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), -97);
+  let a = i32x8::from([3, 5, -7, 9, 11, -13, 15, 17]);
+  assert_eq!(a.reduce_min(), -13);
+  assert_eq!(a.reduce_max(), 17);
+  assert_eq!(a.reduce_mul(), 34459425);
+  assert_eq!(a.reduce_and(), 1);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), 16);
+  let a = i32x8::from([6, 6, 6, 6, 6, 6, i32::MAX, i32::MIN]);
+  assert_eq!(a.reduce_min(), i32::MIN);
+  assert_eq!(a.reduce_max(), i32::MAX);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), -1);
+}
+
+#[test]
+fn impl_i32x8_move_mask() {
+  let a = i32x8::from([-5, 5, 5, -5, 5, 5, -5, 5]);
+  assert_eq!(a.move_mask(), 0b01001001);
+  assert!(a.any());
+  assert!(!a.all());
+  assert!(!a.none());
+  assert!(i32x8::splat(-1).all());
+  assert!(i32x8::splat(1).none());
+}
+
+#[test]
+fn impl_i32x8_lane_queries() {
+  let a = i32x8::from([-7, 100, 3, 3, -3, -1, i32::MAX, i32::MIN]);
+  assert_eq!(a.argmin(), (i32::MIN, 7));
+  assert_eq!(a.argmax(), (i32::MAX, 6));
+  let mask = a.cmp_eq(i32x8::splat(3));
+  assert_eq!(mask.first_set(), Some(2));
+  assert_eq!(mask.last_set(), Some(3));
+  assert_eq!(mask.count_set(), 2);
+  let a = i32x8::from([100, -7, 5, 1, 2, 29, i32::MIN, i32::MAX]);
+  assert_eq!(a.argmin(), (i32::MIN, 6));
+  assert_eq!(a.argmax(), (i32::MAX, 7));
+  let mask = a.cmp_eq(i32x8::splat(3));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  let a = i32x8::from([5, -7, 100, 3, 3, -3, -1, i32::MAX]);
+  assert_eq!(a.argmin(), (-7, 1));
+  assert_eq!(a.argmax(), (i32::MAX, 7));
+  let mask = a.cmp_eq(i32x8::splat(3));
+  assert_eq!(mask.first_set(), Some(3));
+  assert_eq!(mask.last_set(), Some(4));
+  assert_eq!(mask.count_set(), 2);
+  assert_eq!(i32x8::splat(3).argmin(), (3, 0));
+  assert_eq!(i32x8::splat(3).argmax(), (3, 0));
+  assert_eq!(i32x8::splat(3).cmp_eq(i32x8::splat(3)).last_set(), Some(7));
+}
+
+#[test]
+fn impl_i32x8_shuffles() {
+  let v = i32x8::from([1, 2, 3, 4, 5, 6, 7, 8]);
+  let w = i32x8::from([9, 10, 11, 12, 13, 14, 15, 16]);
+  assert_eq!(
+    shuffle!(i32x8, v, [1, 4, 7, 2, 5, 0, 3, 6]),
+    i32x8::from([2, 5, 8, 3, 6, 1, 4, 7])
+  );
+  assert_eq!(shuffle!(i32x8, v, [7, 7, 7, 7, 7, 7, 7, 7]), i32x8::splat(8));
+  assert_eq!(
+    shuffle2!(i32x8, v, w, [2, 7, 12, 1, 6, 11, 0, 5]),
+    i32x8::from([3, 8, 13, 2, 7, 12, 1, 6])
+  );
+  assert_eq!(v.reverse(), i32x8::from([8, 7, 6, 5, 4, 3, 2, 1]));
+  assert_eq!(
+    rotate_lanes_left!(i32x8, v, 0),
+    i32x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i32x8, v, 0),
+    i32x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    shift_lanes_left!(i32x8, v, 0),
+    i32x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    shift_lanes_right!(i32x8, v, 0),
+    i32x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i32x8, v, 1),
+    i32x8::from([2, 3, 4, 5, 6, 7, 8, 1])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i32x8, v, 1),
+    i32x8::from([8, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    shift_lanes_left!(i32x8, v, 1),
+    i32x8::from([2, 3, 4, 5, 6, 7, 8, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(i32x8, v, 1),
+    i32x8::from([0, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i32x8, v, 5),
+    i32x8::from([6, 7, 8, 1, 2, 3, 4, 5])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i32x8, v, 5),
+    i32x8::from([4, 5, 6, 7, 8, 1, 2, 3])
+  );
+  assert_eq!(
+    shift_lanes_left!(i32x8, v, 5),
+    i32x8::from([6, 7, 8, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(i32x8, v, 5),
+    i32x8::from([0, 0, 0, 0, 0, 1, 2, 3])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i32x8, v, 7),
+    i32x8::from([8, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i32x8, v, 7),
+    i32x8::from([2, 3, 4, 5, 6, 7, 8, 1])
+  );
+  assert_eq!(
+    shift_lanes_left!(i32x8, v, 7),
+    i32x8::from([8, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(i32x8, v, 7),
+    i32x8::from([0, 0, 0, 0, 0, 0, 0, 1])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i32x8, v, 8),
+    i32x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i32x8, v, 8),
+    i32x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    shift_lanes_left!(i32x8, v, 8),
+    i32x8::from([0, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(i32x8, v, 8),
+    i32x8::from([0, 0, 0, 0, 0, 0, 0, 0])
+  );
+}
+
+#[test]
+fn impl_i32x8_zip_unzip() {
+  let a = i32x8::from([1, 2, 3, 4, 5, 6, 7, 8]);
+  let b = i32x8::from([9, 10, 11, 12, 13, 14, 15, 16]);
+  assert_eq!(a.zip_lo(b), i32x8::from([1, 9, 2, 10, 3, 11, 4, 12]));
+  assert_eq!(a.zip_hi(b), i32x8::from([5, 13, 6, 14, 7, 15, 8, 16]));
+  assert_eq!(a.unzip_even(b), i32x8::from([1, 3, 5, 7, 9, 11, 13, 15]));
+  assert_eq!(a.unzip_odd(b), i32x8::from([2, 4, 6, 8, 10, 12, 14, 16]));
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+}
+
+#[test]
+fn impl_i32x8_transpose8() {
+  let mut m = [[i32::default(); 8]; 8];
+  let mut mt = [[i32::default(); 8]; 8];
+  for i in 0..8 {
+    for j in 0..8 {
+      m[i][j] = (i * 8 + j) as i32;
+      mt[j][i] = m[i][j];
+    }
+  }
+  let mut rows = [i32x8::default(); 8];
+  let mut expected = [i32x8::default(); 8];
+  for i in 0..8 {
+    rows[i] = i32x8::from(m[i]);
+    expected[i] = i32x8::from(mt[i]);
+  }
+  assert_eq!(i32x8::transpose8(rows), expected);
+  assert_eq!(i32x8::transpose8(expected), rows);
+}
+
+#[test]
+fn impl_i32x8_halves() {
+  let v = i32x8::from([1, 2, 3, 4, 5, 6, 7, 8]);
+  let lo = i32x4::from([1, 2, 3, 4]);
+  let hi = i32x4::from([5, 6, 7, 8]);
+  assert_eq!(v.low(), lo);
+  assert_eq!(v.high(), hi);
+  assert_eq!(v.split(), (lo, hi));
+  assert_eq!(i32x8::from_halves(lo, hi), v);
+  assert_eq!(i32x8::from_halves(hi, lo), i32x8::from([5, 6, 7, 8, 1, 2, 3, 4]));
+}
+
+#[test]
+fn impl_i32x8_widen() {
+  let a =
+    i32x8::from([i32::MIN, i32::MAX, 0, 1, 2, 100, i32::MAX - 1, 1073741824]);
+  assert_eq!(a.widen_low(), i64x4::from([-2147483648, 2147483647, 0, 1]));
+  assert_eq!(a.widen_high(), i64x4::from([2, 100, 2147483646, 1073741824]));
+  let a = i32x8::from([
+    -1,
+    -2,
+    -100,
+    i32::MIN + 1,
+    -1073741825,
+    i32::MIN,
+    i32::MAX,
+    0,
+  ]);
+  assert_eq!(a.widen_low(), i64x4::from([-1, -2, -100, -2147483647]));
+  assert_eq!(
+    a.widen_high(),
+    i64x4::from([-1073741825, -2147483648, 2147483647, 0])
+  );
+}
+
+#[test]
+fn impl_i32x8_narrow() {
+  let a =
+    i32x8::from([i32::MIN, i32::MAX, 0, 1, 2, 100, i32::MAX - 1, 1073741824]);
+  assert_eq!(a.narrow_wrapping(), i16x8::from([0, -1, 0, 1, 2, 100, -2, 0]));
+  assert_eq!(
+    a.narrow_saturating(),
+    i16x8::from([i16::MIN, i16::MAX, 0, 1, 2, 100, i16::MAX, i16::MAX])
+  );
+  let a =
+    i32x8::from([-1, -2, -100, i32::MIN + 1, -1073741825, 32767, 32768, 16384]);
+  assert_eq!(
+    a.narrow_wrapping(),
+    i16x8::from([-1, -2, -100, 1, -1, i16::MAX, i16::MIN, 16384])
+  );
+  assert_eq!(
+    a.narrow_saturating(),
+    i16x8::from([-1, -2, -100, i16::MIN, i16::MIN, i16::MAX, i16::MAX, 16384])
+  );
+  let a = i32x8::from([-32768, -32769, i32::MIN, i32::MAX, 0, 1, 2, 100]);
+  assert_eq!(
+    a.narrow_wrapping(),
+    i16x8::from([i16::MIN, i16::MAX, 0, -1, 0, 1, 2, 100])
+  );
+  assert_eq!(
+    a.narrow_saturating(),
+    i16x8::from([i16::MIN, i16::MIN, i16::MIN, i16::MAX, 0, 1, 2, 100])
+  );
+}
+
+#[test]
+fn impl_i32x8_narrow_saturating_unsigned() {
+  let a = i32x8::from([i32::MIN, -1, 0, 1, 65534, 65535, 65536, i32::MAX]);
+  assert_eq!(
+    a.narrow_saturating_unsigned(),
+    u16x8::from([0, 0, 0, 1, 65534, 65535, 65535, 65535])
+  );
+}
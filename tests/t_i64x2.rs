@@ -24,6 +24,21 @@ fn impl_sub_for_i64x2() {
   assert_eq!(expected, actual);
 }
 
+#[test]
+fn impl_mul_for_i64x2() {
+  let a = i64x2::from([i64::MIN + 1, -(1 << 40) - 3]);
+  let b = i64x2::from([-1, (1 << 33) + 7]);
+  let expected =
+    i64x2::from([i64::MAX, (-(1_i64 << 40) - 3).wrapping_mul((1 << 33) + 7)]);
+  let actual = a * b;
+  assert_eq!(expected, actual);
+  assert_eq!(
+    a * 3,
+    i64x2::from([(i64::MIN + 1).wrapping_mul(3), -(3 << 40) - 9])
+  );
+  assert_eq!(3 * a, a * 3);
+}
+
 #[test]
 fn impl_bitand_for_i64x2() {
   let a = i64x2::from([1, 1]);
@@ -88,3 +103,248 @@ fn impl_i64x2_cmp_gt() {
   let actual = a.cmp_gt(b);
   assert_eq!(expected, actual);
 }
+
+#[test]
+fn impl_i64x2_shl_each() {
+  let table: [([i64; 2], [u64; 2], [i64; 2]); 4] = [
+    (
+      [0x1234_5678_9ABC_DEF0, -0x1234_5678_9ABC_DEF0],
+      [0, 13],
+      [0x1234_5678_9ABC_DEF0, 0x7530_ECA8_6422_0000],
+    ),
+    ([i64::MIN, i64::MAX], [40, 63], [0, i64::MIN]),
+    ([-37, 0x100_0000_0000], [64, 200], [0, 0]),
+    ([-2, 3], [u32::MAX as u64, u64::MAX], [0, 0]),
+  ];
+  for &(a, counts, expected) in table.iter() {
+    let actual = i64x2::from(a).shl_each(u64x2::from(counts));
+    assert_eq!(i64x2::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_i64x2_shr_each() {
+  let table: [([i64; 2], [u64; 2], [i64; 2]); 4] = [
+    (
+      [0x1234_5678_9ABC_DEF0, -0x1234_5678_9ABC_DEF0],
+      [0, 13],
+      [0x1234_5678_9ABC_DEF0, -0x91A2_B3C4_D5E7],
+    ),
+    ([i64::MIN, i64::MAX], [40, 63], [-0x80_0000, 0]),
+    ([-37, 0x100_0000_0000], [64, 200], [-1, 0]),
+    ([-2, 3], [u32::MAX as u64, u64::MAX], [-1, 0]),
+  ];
+  for &(a, counts, expected) in table.iter() {
+    let actual = i64x2::from(a).shr_each(u64x2::from(counts));
+    assert_eq!(i64x2::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_div_for_i64x2() {
+  let a: [i64; 2] = [i64::MIN, -7];
+  let b: [i64; 2] = [-1, 2];
+  let mut expected = [0; 2];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_div(*y);
+  }
+  let actual = i64x2::from(a) / i64x2::from(b);
+  assert_eq!(i64x2::from(expected), actual);
+}
+
+#[test]
+fn impl_rem_for_i64x2() {
+  let a: [i64; 2] = [i64::MIN, -7];
+  let b: [i64; 2] = [-1, 2];
+  let mut expected = [0; 2];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_rem(*y);
+  }
+  let actual = i64x2::from(a) % i64x2::from(b);
+  assert_eq!(i64x2::from(expected), actual);
+}
+
+#[test]
+fn impl_i64x2_divisor() {
+  let nums: [i64; 16] =
+    [i64::MIN, -1, 0, 1, 6, 7, 8, -8, i64::MAX, -13, 99, 100, 2, 3, 50, -50];
+  // numerators next to the extremes and at or near powers of two
+  let edges: [i64; 8] =
+    [i64::MIN + 1, i64::MAX - 1, -2, -4, 1024, -1024, 16, -16];
+  let divisors = [7, -7, 3, i64::MIN, 1, -1, 2, -2, 16, -16, 1 << 62, i64::MAX];
+  for d in divisors.iter().copied() {
+    let divisor = Divisor::<i64x2>::new(d);
+    for chunk in nums.chunks(2).chain(edges.chunks(2)) {
+      let mut a = [0; 2];
+      a.copy_from_slice(chunk);
+      let mut q = a;
+      let mut r = a;
+      for ((q, r), x) in q.iter_mut().zip(r.iter_mut()).zip(a.iter()) {
+        *q = x.wrapping_div(d);
+        *r = x.wrapping_rem(d);
+      }
+      assert_eq!(i64x2::from(a) / divisor, i64x2::from(q));
+      assert_eq!(i64x2::from(a) % divisor, i64x2::from(r));
+      assert_eq!(i64x2::from(a) / d, i64x2::from(q));
+      assert_eq!(i64x2::from(a) % d, i64x2::from(r));
+    }
+  }
+}
+
+#[test]
+fn impl_i64x2_clamp() {
+  let min = i64x2::from(-3);
+  let max = i64x2::from(5);
+  let a = i64x2::from([i64::MIN, -5]);
+  let expected = i64x2::from([-3, -3]);
+  assert_eq!(expected, a.clamp(min, max));
+  let a = i64x2::from([-4, -3]);
+  let expected = i64x2::from([-3, -3]);
+  assert_eq!(expected, a.clamp(min, max));
+  let a = i64x2::from([0, 5]);
+  let expected = i64x2::from([0, 5]);
+  assert_eq!(expected, a.clamp(min, max));
+  let a = i64x2::from([6, i64::MAX]);
+  let expected = i64x2::from([5, 5]);
+  assert_eq!(expected, a.clamp(min, max));
+}
+
+#[test]
+fn impl_i64x2_reductions() {
+  let a = i64x2::from([i64::MAX, 2]);
+  assert_eq!(a.reduce_min(), 2);
+  assert_eq!(a.reduce_max(), i64::MAX);
+  assert_eq!(a.reduce_mul(), -2);
+  assert_eq!(a.reduce_and(), 2);
+  assert_eq!(a.reduce_or(), i64::MAX);
+  assert_eq!(a.reduce_xor(), i64::MAX - 2);
+  let a = i64x2::from([3, 5]);
+  assert_eq!(a.reduce_min(), 3);
+  assert_eq!(a.reduce_max(), 5);
+  assert_eq!(a.reduce_mul(), 15);
+  assert_eq!(a.reduce_and(), 1);
+  assert_eq!(a.reduce_or(), 7);
+  assert_eq!(a.reduce_xor(), 6);
+  let a = i64x2::from([i64::MAX, i64::MIN]);
+  assert_eq!(a.reduce_min(), i64::MIN);
+  assert_eq!(a.reduce_max(), i64::MAX);
+  assert_eq!(a.reduce_mul(), i64::MIN);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), -1);
+}
+
+#[test]
+fn impl_i64x2_move_mask() {
+  let a = i64x2::from([-5, 5]);
+  assert_eq!(a.move_mask(), 0b01);
+  assert!(a.any());
+  assert!(!a.all());
+  assert!(!a.none());
+  assert!(i64x2::splat(-1).all());
+  assert!(i64x2::splat(1).none());
+}
+
+#[test]
+fn impl_i64x2_lane_queries() {
+  let a = i64x2::from([3, 3]);
+  assert_eq!(a.argmin(), (3, 0));
+  assert_eq!(a.argmax(), (3, 0));
+  let mask = a.cmp_eq(i64x2::splat(3));
+  assert_eq!(mask.first_set(), Some(0));
+  assert_eq!(mask.last_set(), Some(1));
+  assert_eq!(mask.count_set(), 2);
+  let a = i64x2::from([29, 2]);
+  assert_eq!(a.argmin(), (2, 1));
+  assert_eq!(a.argmax(), (29, 0));
+  let mask = a.cmp_eq(i64x2::splat(3));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  assert_eq!(i64x2::splat(3).argmin(), (3, 0));
+  assert_eq!(i64x2::splat(3).argmax(), (3, 0));
+  assert_eq!(i64x2::splat(3).cmp_eq(i64x2::splat(3)).last_set(), Some(1));
+}
+
+#[test]
+fn impl_i64x2_shuffles() {
+  let v = i64x2::from([1, 2]);
+  let w = i64x2::from([3, 4]);
+  assert_eq!(shuffle!(i64x2, v, [1, 0]), i64x2::from([2, 1]));
+  assert_eq!(shuffle!(i64x2, v, [1, 1]), i64x2::splat(2));
+  assert_eq!(shuffle2!(i64x2, v, w, [2, 3]), i64x2::from([3, 4]));
+  assert_eq!(v.reverse(), i64x2::from([2, 1]));
+  assert_eq!(rotate_lanes_left!(i64x2, v, 0), i64x2::from([1, 2]));
+  assert_eq!(rotate_lanes_right!(i64x2, v, 0), i64x2::from([1, 2]));
+  assert_eq!(shift_lanes_left!(i64x2, v, 0), i64x2::from([1, 2]));
+  assert_eq!(shift_lanes_right!(i64x2, v, 0), i64x2::from([1, 2]));
+  assert_eq!(rotate_lanes_left!(i64x2, v, 1), i64x2::from([2, 1]));
+  assert_eq!(rotate_lanes_right!(i64x2, v, 1), i64x2::from([2, 1]));
+  assert_eq!(shift_lanes_left!(i64x2, v, 1), i64x2::from([2, 0]));
+  assert_eq!(shift_lanes_right!(i64x2, v, 1), i64x2::from([0, 1]));
+  assert_eq!(rotate_lanes_left!(i64x2, v, 2), i64x2::from([1, 2]));
+  assert_eq!(rotate_lanes_right!(i64x2, v, 2), i64x2::from([1, 2]));
+  assert_eq!(shift_lanes_left!(i64x2, v, 2), i64x2::from([0, 0]));
+  assert_eq!(shift_lanes_right!(i64x2, v, 2), i64x2::from([0, 0]));
+}
+
+#[test]
+fn impl_i64x2_zip_unzip() {
+  let a = i64x2::from([1, 2]);
+  let b = i64x2::from([3, 4]);
+  assert_eq!(a.zip_lo(b), i64x2::from([1, 3]));
+  assert_eq!(a.zip_hi(b), i64x2::from([2, 4]));
+  assert_eq!(a.unzip_even(b), i64x2::from([1, 3]));
+  assert_eq!(a.unzip_odd(b), i64x2::from([2, 4]));
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+}
+
+#[test]
+fn impl_i64x2_narrow() {
+  let a = i64x2::from([i64::MIN, i64::MAX]);
+  let b = i64x2::from([0, 1]);
+  assert_eq!(a.narrow_wrapping(b), i32x4::from([0, -1, 0, 1]));
+  assert_eq!(a.narrow_saturating(b), i32x4::from([i32::MIN, i32::MAX, 0, 1]));
+  let a = i64x2::from([2, 100]);
+  let b = i64x2::from([i64::MAX - 1, 1 << 62]);
+  assert_eq!(a.narrow_wrapping(b), i32x4::from([2, 100, -2, 0]));
+  assert_eq!(a.narrow_saturating(b), i32x4::from([2, 100, i32::MAX, i32::MAX]));
+  let a = i64x2::from([-1, -2]);
+  let b = i64x2::from([-100, i64::MIN + 1]);
+  assert_eq!(a.narrow_wrapping(b), i32x4::from([-1, -2, -100, 1]));
+  assert_eq!(a.narrow_saturating(b), i32x4::from([-1, -2, -100, i32::MIN]));
+  let a = i64x2::from([-(1 << 62) - 1, 2147483647]);
+  let b = i64x2::from([2147483648, 1073741824]);
+  assert_eq!(
+    a.narrow_wrapping(b),
+    i32x4::from([-1, i32::MAX, i32::MIN, 1073741824])
+  );
+  assert_eq!(
+    a.narrow_saturating(b),
+    i32x4::from([i32::MIN, i32::MAX, i32::MAX, 1073741824])
+  );
+  let a = i64x2::from([-2147483648, -2147483649]);
+  let b = i64x2::from([i64::MIN, i64::MAX]);
+  assert_eq!(a.narrow_wrapping(b), i32x4::from([i32::MIN, i32::MAX, 0, -1]));
+  assert_eq!(
+    a.narrow_saturating(b),
+    i32x4::from([i32::MIN, i32::MIN, i32::MIN, i32::MAX])
+  );
+}
+
+#[test]
+fn impl_i64x2_narrow_saturating_unsigned() {
+  let lo = i64x2::from([i64::MIN, -1]);
+  let hi = i64x2::from([0x1_0000_0000, 0xFFFF_FFFF]);
+  assert_eq!(
+    lo.narrow_saturating_unsigned(hi),
+    u32x4::from([0, 0, u32::MAX, u32::MAX])
+  );
+  let lo = i64x2::from([0, 1]);
+  let hi = i64x2::from([i64::MAX, 0xFFFF_FFFE]);
+  assert_eq!(
+    lo.narrow_saturating_unsigned(hi),
+    u32x4::from([0, 1, u32::MAX, 0xFFFF_FFFE])
+  );
+}
@@ -24,6 +24,22 @@ fn impl_sub_for_i64x4() {
   assert_eq!(expected, actual);
 }
 
+#[test]
+fn impl_mul_for_i64x4() {
+  let a = i64x4::from([i64::MIN + 1, -(1 << 40) - 3, 12, i64::MAX]);
+  let b = i64x4::from([-1, (1 << 33) + 7, -5, 2]);
+  let expected = i64x4::from([
+    i64::MAX,
+    (-(1_i64 << 40) - 3).wrapping_mul((1 << 33) + 7),
+    -60,
+    -2,
+  ]);
+  let actual = a * b;
+  assert_eq!(expected, actual);
+  assert_eq!(a * -1, i64x4::from([i64::MAX, (1 << 40) + 3, -12, -i64::MAX]));
+  assert_eq!(-1 * a, a * -1);
+}
+
 #[test]
 fn impl_bitand_for_i64x4() {
   let a = i64x4::from([1, 1, 0, 0]);
@@ -90,3 +106,279 @@ fn impl_i64x4_cmp_eq() {
   let actual = a.cmp_eq(b);
   assert_eq!(expected, actual);
 }
+
+#[test]
+fn impl_i64x4_shl_each() {
+  let table: [([i64; 4], [u64; 4], [i64; 4]); 2] = [
+    (
+      [0x1234_5678_9ABC_DEF0, -0x1234_5678_9ABC_DEF0, i64::MIN, i64::MAX],
+      [0, 13, 40, 63],
+      [0x1234_5678_9ABC_DEF0, 0x7530_ECA8_6422_0000, 0, i64::MIN],
+    ),
+    (
+      [-37, 0x100_0000_0000, -2, 3],
+      [64, 200, u32::MAX as u64, u64::MAX],
+      [0, 0, 0, 0],
+    ),
+  ];
+  for &(a, counts, expected) in table.iter() {
+    let actual = i64x4::from(a).shl_each(u64x4::from(counts));
+    assert_eq!(i64x4::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_i64x4_shr_each() {
+  let table: [([i64; 4], [u64; 4], [i64; 4]); 2] = [
+    (
+      [0x1234_5678_9ABC_DEF0, -0x1234_5678_9ABC_DEF0, i64::MIN, i64::MAX],
+      [0, 13, 40, 63],
+      [0x1234_5678_9ABC_DEF0, -0x91A2_B3C4_D5E7, -0x80_0000, 0],
+    ),
+    (
+      [-37, 0x100_0000_0000, -2, 3],
+      [64, 200, u32::MAX as u64, u64::MAX],
+      [-1, 0, -1, 0],
+    ),
+  ];
+  for &(a, counts, expected) in table.iter() {
+    let actual = i64x4::from(a).shr_each(u64x4::from(counts));
+    assert_eq!(i64x4::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_div_for_i64x4() {
+  let a: [i64; 4] = [i64::MIN, -7, 7, 100];
+  let b: [i64; 4] = [-1, 2, -3, 7];
+  let mut expected = [0; 4];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_div(*y);
+  }
+  let actual = i64x4::from(a) / i64x4::from(b);
+  assert_eq!(i64x4::from(expected), actual);
+}
+
+#[test]
+fn impl_rem_for_i64x4() {
+  let a: [i64; 4] = [i64::MIN, -7, 7, 100];
+  let b: [i64; 4] = [-1, 2, -3, 7];
+  let mut expected = [0; 4];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_rem(*y);
+  }
+  let actual = i64x4::from(a) % i64x4::from(b);
+  assert_eq!(i64x4::from(expected), actual);
+}
+
+#[test]
+fn impl_i64x4_divisor() {
+  let nums: [i64; 16] =
+    [i64::MIN, -1, 0, 1, 6, 7, 8, -8, i64::MAX, -13, 99, 100, 2, 3, 50, -50];
+  // numerators next to the extremes and at or near powers of two
+  let edges: [i64; 8] =
+    [i64::MIN + 1, i64::MAX - 1, -2, -4, 1024, -1024, 16, -16];
+  let divisors = [7, -7, 3, i64::MIN, 1, -1, 2, -2, 16, -16, 1 << 62, i64::MAX];
+  for d in divisors.iter().copied() {
+    let divisor = Divisor::<i64x4>::new(d);
+    for chunk in nums.chunks(4).chain(edges.chunks(4)) {
+      let mut a = [0; 4];
+      a.copy_from_slice(chunk);
+      let mut q = a;
+      let mut r = a;
+      for ((q, r), x) in q.iter_mut().zip(r.iter_mut()).zip(a.iter()) {
+        *q = x.wrapping_div(d);
+        *r = x.wrapping_rem(d);
+      }
+      assert_eq!(i64x4::from(a) / divisor, i64x4::from(q));
+      assert_eq!(i64x4::from(a) % divisor, i64x4::from(r));
+      assert_eq!(i64x4::from(a) / d, i64x4::from(q));
+      assert_eq!(i64x4::from(a) % d, i64x4::from(r));
+    }
+  }
+}
+
+#[test]
+fn impl_i64x4_clamp() {
+  let min = i64x4::from(-3);
+  let max = i64x4::from(5);
+  let a = i64x4::from([i64::MIN, -5, -4, -3]);
+  let expected = i64x4::from([-3, -3, -3, -3]);
+  assert_eq!(expected, a.clamp(min, max));
+  let a = i64x4::from([0, 5, 6, i64::MAX]);
+  let expected = i64x4::from([0, 5, 5, 5]);
+  assert_eq!(expected, a.clamp(min, max));
+}
+
+#[test]
+fn impl_i64x4_reductions() {
+  let a = i64x4::from([i64::MIN, 1, 100, -3]);
+  assert_eq!(a.reduce_min(), i64::MIN);
+  assert_eq!(a.reduce_max(), 100);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), -3);
+  assert_eq!(a.reduce_xor(), i64::MAX - 103);
+  let a = i64x4::from([3, 5, -7, 9]);
+  assert_eq!(a.reduce_min(), -7);
+  assert_eq!(a.reduce_max(), 9);
+  assert_eq!(a.reduce_mul(), -945);
+  assert_eq!(a.reduce_and(), 1);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), -10);
+  let a = i64x4::from([6, 6, i64::MAX, i64::MIN]);
+  assert_eq!(a.reduce_min(), i64::MIN);
+  assert_eq!(a.reduce_max(), i64::MAX);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), -1);
+}
+
+#[test]
+fn impl_i64x4_move_mask() {
+  let a = i64x4::from([-5, 5, 5, -5]);
+  assert_eq!(a.move_mask(), 0b1001);
+  assert!(a.any());
+  assert!(!a.all());
+  assert!(!a.none());
+  assert!(i64x4::splat(-1).all());
+  assert!(i64x4::splat(1).none());
+}
+
+#[test]
+fn impl_i64x4_lane_queries() {
+  let a = i64x4::from([3, 3, 100, -7]);
+  assert_eq!(a.argmin(), (-7, 3));
+  assert_eq!(a.argmax(), (100, 2));
+  let mask = a.cmp_eq(i64x4::splat(3));
+  assert_eq!(mask.first_set(), Some(0));
+  assert_eq!(mask.last_set(), Some(1));
+  assert_eq!(mask.count_set(), 2);
+  let a = i64x4::from([-3, -1, i64::MAX, i64::MIN]);
+  assert_eq!(a.argmin(), (i64::MIN, 3));
+  assert_eq!(a.argmax(), (i64::MAX, 2));
+  let mask = a.cmp_eq(i64x4::splat(3));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  let a = i64x4::from([-1, -3, 3, 3]);
+  assert_eq!(a.argmin(), (-3, 1));
+  assert_eq!(a.argmax(), (3, 2));
+  let mask = a.cmp_eq(i64x4::splat(3));
+  assert_eq!(mask.first_set(), Some(2));
+  assert_eq!(mask.last_set(), Some(3));
+  assert_eq!(mask.count_set(), 2);
+  assert_eq!(i64x4::splat(3).argmin(), (3, 0));
+  assert_eq!(i64x4::splat(3).argmax(), (3, 0));
+  assert_eq!(i64x4::splat(3).cmp_eq(i64x4::splat(3)).last_set(), Some(3));
+}
+
+#[test]
+fn impl_i64x4_shuffles() {
+  let v = i64x4::from([1, 2, 3, 4]);
+  let w = i64x4::from([5, 6, 7, 8]);
+  assert_eq!(shuffle!(i64x4, v, [1, 0, 3, 2]), i64x4::from([2, 1, 4, 3]));
+  assert_eq!(shuffle!(i64x4, v, [3, 3, 3, 3]), i64x4::splat(4));
+  assert_eq!(shuffle2!(i64x4, v, w, [2, 7, 4, 1]), i64x4::from([3, 8, 5, 2]));
+  assert_eq!(v.reverse(), i64x4::from([4, 3, 2, 1]));
+  assert_eq!(rotate_lanes_left!(i64x4, v, 0), i64x4::from([1, 2, 3, 4]));
+  assert_eq!(rotate_lanes_right!(i64x4, v, 0), i64x4::from([1, 2, 3, 4]));
+  assert_eq!(shift_lanes_left!(i64x4, v, 0), i64x4::from([1, 2, 3, 4]));
+  assert_eq!(shift_lanes_right!(i64x4, v, 0), i64x4::from([1, 2, 3, 4]));
+  assert_eq!(rotate_lanes_left!(i64x4, v, 1), i64x4::from([2, 3, 4, 1]));
+  assert_eq!(rotate_lanes_right!(i64x4, v, 1), i64x4::from([4, 1, 2, 3]));
+  assert_eq!(shift_lanes_left!(i64x4, v, 1), i64x4::from([2, 3, 4, 0]));
+  assert_eq!(shift_lanes_right!(i64x4, v, 1), i64x4::from([0, 1, 2, 3]));
+  assert_eq!(rotate_lanes_left!(i64x4, v, 3), i64x4::from([4, 1, 2, 3]));
+  assert_eq!(rotate_lanes_right!(i64x4, v, 3), i64x4::from([2, 3, 4, 1]));
+  assert_eq!(shift_lanes_left!(i64x4, v, 3), i64x4::from([4, 0, 0, 0]));
+  assert_eq!(shift_lanes_right!(i64x4, v, 3), i64x4::from([0, 0, 0, 1]));
+  assert_eq!(rotate_lanes_left!(i64x4, v, 4), i64x4::from([1, 2, 3, 4]));
+  assert_eq!(rotate_lanes_right!(i64x4, v, 4), i64x4::from([1, 2, 3, 4]));
+  assert_eq!(shift_lanes_left!(i64x4, v, 4), i64x4::from([0, 0, 0, 0]));
+  assert_eq!(shift_lanes_right!(i64x4, v, 4), i64x4::from([0, 0, 0, 0]));
+}
+
+#[test]
+fn impl_i64x4_zip_unzip() {
+  let a = i64x4::from([1, 2, 3, 4]);
+  let b = i64x4::from([5, 6, 7, 8]);
+  assert_eq!(a.zip_lo(b), i64x4::from([1, 5, 2, 6]));
+  assert_eq!(a.zip_hi(b), i64x4::from([3, 7, 4, 8]));
+  assert_eq!(a.unzip_even(b), i64x4::from([1, 3, 5, 7]));
+  assert_eq!(a.unzip_odd(b), i64x4::from([2, 4, 6, 8]));
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+}
+
+#[test]
+fn impl_i64x4_transpose4() {
+  let mut m = [[i64::default(); 4]; 4];
+  let mut mt = [[i64::default(); 4]; 4];
+  for i in 0..4 {
+    for j in 0..4 {
+      m[i][j] = (i * 4 + j) as i64;
+      mt[j][i] = m[i][j];
+    }
+  }
+  let mut rows = [i64x4::default(); 4];
+  let mut expected = [i64x4::default(); 4];
+  for i in 0..4 {
+    rows[i] = i64x4::from(m[i]);
+    expected[i] = i64x4::from(mt[i]);
+  }
+  assert_eq!(i64x4::transpose4(rows), expected);
+  assert_eq!(i64x4::transpose4(expected), rows);
+}
+
+#[test]
+fn impl_i64x4_halves() {
+  let v = i64x4::from([1, 2, 3, 4]);
+  let lo = i64x2::from([1, 2]);
+  let hi = i64x2::from([3, 4]);
+  assert_eq!(v.low(), lo);
+  assert_eq!(v.high(), hi);
+  assert_eq!(v.split(), (lo, hi));
+  assert_eq!(i64x4::from_halves(lo, hi), v);
+  assert_eq!(i64x4::from_halves(hi, lo), i64x4::from([3, 4, 1, 2]));
+}
+
+#[test]
+fn impl_i64x4_narrow() {
+  let a = i64x4::from([i64::MIN, i64::MAX, 0, 1]);
+  assert_eq!(a.narrow_wrapping(), i32x4::from([0, -1, 0, 1]));
+  assert_eq!(a.narrow_saturating(), i32x4::from([i32::MIN, i32::MAX, 0, 1]));
+  let a = i64x4::from([2, 100, i64::MAX - 1, 1 << 62]);
+  assert_eq!(a.narrow_wrapping(), i32x4::from([2, 100, -2, 0]));
+  assert_eq!(a.narrow_saturating(), i32x4::from([2, 100, i32::MAX, i32::MAX]));
+  let a = i64x4::from([-1, -2, -100, i64::MIN + 1]);
+  assert_eq!(a.narrow_wrapping(), i32x4::from([-1, -2, -100, 1]));
+  assert_eq!(a.narrow_saturating(), i32x4::from([-1, -2, -100, i32::MIN]));
+  let a = i64x4::from([-(1 << 62) - 1, 2147483647, 2147483648, 1073741824]);
+  assert_eq!(
+    a.narrow_wrapping(),
+    i32x4::from([-1, i32::MAX, i32::MIN, 1073741824])
+  );
+  assert_eq!(
+    a.narrow_saturating(),
+    i32x4::from([i32::MIN, i32::MAX, i32::MAX, 1073741824])
+  );
+  let a = i64x4::from([-2147483648, -2147483649, i64::MIN, i64::MAX]);
+  assert_eq!(a.narrow_wrapping(), i32x4::from([i32::MIN, i32::MAX, 0, -1]));
+  assert_eq!(
+    a.narrow_saturating(),
+    i32x4::from([i32::MIN, i32::MIN, i32::MIN, i32::MAX])
+  );
+}
+
+#[test]
+fn impl_i64x4_narrow_saturating_unsigned() {
+  let a = i64x4::from([i64::MIN, -1, 0, 0x1_0000_0000]);
+  assert_eq!(a.narrow_saturating_unsigned(), u32x4::from([0, 0, 0, u32::MAX]));
+  let a = i64x4::from([1, 0xFFFF_FFFE, 0xFFFF_FFFF, i64::MAX]);
+  assert_eq!(
+    a.narrow_saturating_unsigned(),
+    u32x4::from([1, 0xFFFF_FFFE, u32::MAX, u32::MAX])
+  );
+}
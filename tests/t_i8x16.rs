@@ -63,6 +63,41 @@ fn impl_sub_for_i8x16() {
   assert_eq!(expected, actual);
 }
 
+#[test]
+fn impl_mul_for_i8x16() {
+  let a = i8x16::from([
+    1,
+    2,
+    3,
+    4,
+    5,
+    6,
+    7,
+    8,
+    -9,
+    10,
+    11,
+    12,
+    13,
+    14,
+    i8::MIN,
+    i8::MAX,
+  ]);
+  let b = i8x16::from([
+    17, 18, -19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, -1, 2,
+  ]);
+  let a_arr: [i8; 16] = a.into();
+  let b_arr: [i8; 16] = b.into();
+  let mut expected = [0_i8; 16];
+  for i in 0..16 {
+    expected[i] = a_arr[i].wrapping_mul(b_arr[i]);
+  }
+  let actual = a * b;
+  assert_eq!(i8x16::from(expected), actual);
+  assert_eq!(a * 3, a * i8x16::splat(3));
+  assert_eq!(3 * a, a * i8x16::splat(3));
+}
+
 #[test]
 fn impl_bitand_for_i8x16() {
   let a = i8x16::from([0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1]);
@@ -90,6 +125,68 @@ fn impl_bitxor_for_i8x16() {
   assert_eq!(expected, actual);
 }
 
+#[test]
+fn impl_shl_for_i8x16() {
+  let a = i8x16::from([
+    1,
+    2,
+    3,
+    4,
+    -5,
+    -6,
+    7,
+    8,
+    9,
+    10,
+    11,
+    -12,
+    13,
+    14,
+    i8::MIN,
+    i8::MAX,
+  ]);
+  let a_arr: [i8; 16] = a.into();
+  for shift in 0..8 {
+    let mut expected = [0_i8; 16];
+    for i in 0..16 {
+      expected[i] = a_arr[i] << shift;
+    }
+    let actual = a << shift;
+    assert_eq!(i8x16::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_shr_for_i8x16() {
+  let a = i8x16::from([
+    1,
+    2,
+    3,
+    4,
+    -5,
+    -6,
+    7,
+    8,
+    9,
+    10,
+    11,
+    -12,
+    13,
+    14,
+    i8::MIN,
+    i8::MAX,
+  ]);
+  let a_arr: [i8; 16] = a.into();
+  for shift in 0..8 {
+    let mut expected = [0_i8; 16];
+    for i in 0..16 {
+      expected[i] = a_arr[i] >> shift;
+    }
+    let actual = a >> shift;
+    assert_eq!(i8x16::from(expected), actual);
+  }
+}
+
 #[test]
 fn impl_i8x16_cmp_eq() {
   let a = i8x16::from([1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4]);
@@ -234,3 +331,334 @@ fn impl_i8x16_min() {
   let actual = a.min(b);
   assert_eq!(expected, actual);
 }
+
+#[test]
+fn impl_i8x16_shl_each() {
+  let a: [i8; 16] =
+    [127, -128, 1, -37, 53, -2, 77, 3, -128, -37, 127, -2, 90, -1, 1, -128];
+  let counts: [u8; 16] =
+    [0, 1, 3, 4, 6, 7, 8, 9, 200, u8::MAX, 2, 5, 7, 8, 100, u8::MAX];
+  let expected: [i8; 16] =
+    [127, 0, 8, -80, 64, 0, 0, 0, 0, 0, -4, -64, 0, 0, 0, 0];
+  let actual = i8x16::from(a).shl_each(u8x16::from(counts));
+  assert_eq!(i8x16::from(expected), actual);
+}
+
+#[test]
+fn impl_i8x16_shr_each() {
+  let a: [i8; 16] =
+    [127, -128, 1, -37, 53, -2, 77, 3, -128, -37, 127, -2, 90, -1, 1, -128];
+  let counts: [u8; 16] =
+    [0, 1, 3, 4, 6, 7, 8, 9, 200, u8::MAX, 2, 5, 7, 8, 100, u8::MAX];
+  let expected: [i8; 16] =
+    [127, -64, 0, -3, 0, -1, 0, 0, -1, -1, 31, -1, 0, -1, 0, -1];
+  let actual = i8x16::from(a).shr_each(u8x16::from(counts));
+  assert_eq!(i8x16::from(expected), actual);
+}
+
+#[test]
+fn impl_i8x16_saturating_add() {
+  let a: [i8; 16] =
+    [127, -128, 100, -100, 1, -1, 0, 50, 127, -128, 100, -100, 1, -1, 0, 50];
+  let b: [i8; 16] =
+    [1, -1, 100, -100, -128, 127, 0, -60, 1, -1, 100, -100, -128, 127, 0, -60];
+  let mut expected = [0; 16];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.saturating_add(*y);
+  }
+  let actual = i8x16::from(a).saturating_add(i8x16::from(b));
+  assert_eq!(i8x16::from(expected), actual);
+}
+
+#[test]
+fn impl_i8x16_saturating_sub() {
+  let a: [i8; 16] =
+    [127, -128, 100, -100, 1, -1, 0, 50, 127, -128, 100, -100, 1, -1, 0, 50];
+  let b: [i8; 16] =
+    [1, -1, 100, -100, -128, 127, 0, -60, 1, -1, 100, -100, -128, 127, 0, -60];
+  let mut expected = [0; 16];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.saturating_sub(*y);
+  }
+  let actual = i8x16::from(a).saturating_sub(i8x16::from(b));
+  assert_eq!(i8x16::from(expected), actual);
+}
+
+#[test]
+fn impl_i8x16_clamp() {
+  let min = i8x16::from(-3);
+  let max = i8x16::from(5);
+  let a = i8x16::from([
+    -128, -5, -4, -3, 0, 5, 6, 127, 127, 6, 5, 0, -3, -4, -5, -128,
+  ]);
+  let expected =
+    i8x16::from([-3, -3, -3, -3, 0, 5, 5, 5, 5, 5, 5, 0, -3, -3, -3, -3]);
+  assert_eq!(expected, a.clamp(min, max));
+}
+
+#[test]
+fn impl_i8x16_reductions() {
+  let a = i8x16::from([
+    -128, 1, 100, -3, -7, 3, 127, 2, -1, 29, 5, -128, 1, 100, -3, -7,
+  ]);
+  assert_eq!(a.reduce_min(), -128);
+  assert_eq!(a.reduce_max(), 127);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), -103);
+  let a = i8x16::from([
+    3, 5, -7, 9, 11, -13, 15, 17, -19, 21, 23, -25, 27, 29, -31, 33,
+  ]);
+  assert_eq!(a.reduce_min(), -31);
+  assert_eq!(a.reduce_max(), 33);
+  assert_eq!(a.reduce_mul(), -65);
+  assert_eq!(a.reduce_and(), 1);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), -34);
+  let a = i8x16::from([6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 127, -128]);
+  assert_eq!(a.reduce_min(), -128);
+  assert_eq!(a.reduce_max(), 127);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), -1);
+}
+
+#[test]
+fn impl_i8x16_lane_queries() {
+  let a =
+    i8x16::from([-1, -3, 3, 3, 100, -7, 5, 1, 2, 29, -128, 127, -1, -3, 3, 3]);
+  assert_eq!(a.argmin(), (-128, 10));
+  assert_eq!(a.argmax(), (127, 11));
+  let mask = a.cmp_eq(i8x16::splat(3));
+  assert_eq!(mask.first_set(), Some(2));
+  assert_eq!(mask.last_set(), Some(15));
+  assert_eq!(mask.count_set(), 4);
+  let a = i8x16::from([
+    127, -1, -3, 3, 3, 100, -7, 5, 1, 2, 29, -128, 127, -1, -3, 3,
+  ]);
+  assert_eq!(a.argmin(), (-128, 11));
+  assert_eq!(a.argmax(), (127, 0));
+  let mask = a.cmp_eq(i8x16::splat(3));
+  assert_eq!(mask.first_set(), Some(3));
+  assert_eq!(mask.last_set(), Some(15));
+  assert_eq!(mask.count_set(), 3);
+  let a = i8x16::from([
+    2, 29, -128, 127, -1, -3, 3, 3, 100, -7, 5, 1, 2, 29, -128, 127,
+  ]);
+  assert_eq!(a.argmin(), (-128, 2));
+  assert_eq!(a.argmax(), (127, 3));
+  let mask = a.cmp_eq(i8x16::splat(3));
+  assert_eq!(mask.first_set(), Some(6));
+  assert_eq!(mask.last_set(), Some(7));
+  assert_eq!(mask.count_set(), 2);
+  assert_eq!(i8x16::splat(3).argmin(), (3, 0));
+  assert_eq!(i8x16::splat(3).argmax(), (3, 0));
+  assert_eq!(i8x16::splat(3).cmp_eq(i8x16::splat(3)).last_set(), Some(15));
+}
+
+#[test]
+fn impl_i8x16_swizzle() {
+  let table =
+    i8x16::from([-8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 7]);
+  let idx = i8x16::from([
+    15,
+    0,
+    -1,
+    16,
+    i8::MIN,
+    i8::MAX,
+    8,
+    8,
+    1,
+    2,
+    3,
+    4,
+    5,
+    6,
+    7,
+    -16,
+  ]);
+  let expected =
+    i8x16::from([7, -8, 0, 0, 0, 0, 0, 0, -7, -6, -5, -4, -3, -2, -1, 0]);
+  assert_eq!(table.swizzle(idx), expected);
+  let idx =
+    i8x16::from([8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, -1]);
+  let expected =
+    i8x16::from([0, 1, 2, 3, 4, 5, 6, 7, -8, -7, -6, -5, -4, -3, -2, 0]);
+  assert_eq!(table.swizzle(idx), expected);
+  assert_eq!(table.swizzle_relaxed(idx), expected);
+}
+
+#[test]
+fn impl_i8x16_shuffles() {
+  let v = i8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+  let w = i8x16::from([
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+  ]);
+  assert_eq!(
+    shuffle!(i8x16, v, [1, 4, 7, 10, 13, 0, 3, 6, 9, 12, 15, 2, 5, 8, 11, 14]),
+    i8x16::from([2, 5, 8, 11, 14, 1, 4, 7, 10, 13, 16, 3, 6, 9, 12, 15])
+  );
+  assert_eq!(
+    shuffle!(
+      i8x16,
+      v,
+      [15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15]
+    ),
+    i8x16::splat(16)
+  );
+  assert_eq!(
+    shuffle2!(
+      i8x16,
+      v,
+      w,
+      [2, 7, 12, 17, 22, 27, 0, 5, 10, 15, 20, 25, 30, 3, 8, 13]
+    ),
+    i8x16::from([3, 8, 13, 18, 23, 28, 1, 6, 11, 16, 21, 26, 31, 4, 9, 14])
+  );
+  assert_eq!(
+    v.reverse(),
+    i8x16::from([16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i8x16, v, 0),
+    i8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i8x16, v, 0),
+    i8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+  );
+  assert_eq!(
+    shift_lanes_left!(i8x16, v, 0),
+    i8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+  );
+  assert_eq!(
+    shift_lanes_right!(i8x16, v, 0),
+    i8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i8x16, v, 1),
+    i8x16::from([2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 1])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i8x16, v, 1),
+    i8x16::from([16, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+  );
+  assert_eq!(
+    shift_lanes_left!(i8x16, v, 1),
+    i8x16::from([2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(i8x16, v, 1),
+    i8x16::from([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i8x16, v, 9),
+    i8x16::from([10, 11, 12, 13, 14, 15, 16, 1, 2, 3, 4, 5, 6, 7, 8, 9])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i8x16, v, 9),
+    i8x16::from([8, 9, 10, 11, 12, 13, 14, 15, 16, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    shift_lanes_left!(i8x16, v, 9),
+    i8x16::from([10, 11, 12, 13, 14, 15, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(i8x16, v, 9),
+    i8x16::from([0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i8x16, v, 15),
+    i8x16::from([16, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i8x16, v, 15),
+    i8x16::from([2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 1])
+  );
+  assert_eq!(
+    shift_lanes_left!(i8x16, v, 15),
+    i8x16::from([16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(i8x16, v, 15),
+    i8x16::from([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i8x16, v, 16),
+    i8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i8x16, v, 16),
+    i8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+  );
+  assert_eq!(
+    shift_lanes_left!(i8x16, v, 16),
+    i8x16::from([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(i8x16, v, 16),
+    i8x16::from([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+  );
+}
+
+#[test]
+fn impl_i8x16_zip_unzip() {
+  let a = i8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+  let b = i8x16::from([
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+  ]);
+  assert_eq!(
+    a.zip_lo(b),
+    i8x16::from([1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23, 8, 24])
+  );
+  assert_eq!(
+    a.zip_hi(b),
+    i8x16::from([
+      9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31, 16, 32
+    ])
+  );
+  assert_eq!(
+    a.unzip_even(b),
+    i8x16::from([1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31])
+  );
+  assert_eq!(
+    a.unzip_odd(b),
+    i8x16::from([2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32])
+  );
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+  // lanes with the top bit set, which the packs must not saturate
+  let c = i8x16::from([
+    -128, -1, 127, -2, 64, -64, 1, 0, -3, 127, -128, 5, -5, -1, 7, -7,
+  ]);
+  let d = i8x16::from([
+    -1, -128, 0, 127, -2, 2, 100, -100, -127, 126, 3, -3, -50, 50, -1, 1,
+  ]);
+  assert_eq!(
+    c.unzip_even(d),
+    i8x16::from([
+      -128, 127, 64, 1, -3, -128, -5, 7, -1, 0, -2, 100, -127, 3, -50, -1
+    ])
+  );
+  assert_eq!(
+    c.unzip_odd(d),
+    i8x16::from([
+      -1, -2, -64, 0, 127, 5, -1, -7, -128, 127, 2, -100, 126, -3, 50, 1
+    ])
+  );
+}
+
+#[test]
+fn impl_i8x16_widen() {
+  let a = i8x16::from([
+    -128, 127, 0, 1, 2, 100, 126, 64, -1, -2, -100, -127, -65, -128, 127, 0,
+  ]);
+  assert_eq!(a.widen_low(), i16x8::from([-128, 127, 0, 1, 2, 100, 126, 64]));
+  assert_eq!(
+    a.widen_high(),
+    i16x8::from([-1, -2, -100, -127, -65, -128, 127, 0])
+  );
+}
@@ -80,6 +80,28 @@ fn impl_sub_for_i8x32() {
   assert_eq!(expected, actual);
 }
 
+#[test]
+fn impl_mul_for_i8x32() {
+  let mut a_arr = [0_i8; 32];
+  let mut b_arr = [0_i8; 32];
+  for i in 0..32 {
+    a_arr[i] = (i as i8).wrapping_mul(7).wrapping_sub(100);
+    b_arr[i] = (i as i8).wrapping_mul(-13).wrapping_add(3);
+  }
+  a_arr[30] = i8::MIN;
+  a_arr[31] = i8::MAX;
+  let mut expected = [0_i8; 32];
+  for i in 0..32 {
+    expected[i] = a_arr[i].wrapping_mul(b_arr[i]);
+  }
+  let a = i8x32::from(a_arr);
+  let b = i8x32::from(b_arr);
+  let actual = a * b;
+  assert_eq!(i8x32::from(expected), actual);
+  assert_eq!(a * -5, a * i8x32::splat(-5));
+  assert_eq!(-5 * a, a * i8x32::splat(-5));
+}
+
 #[test]
 fn impl_bitand_for_i8x32() {
   let a = i8x32::from([
@@ -134,6 +156,40 @@ fn impl_bitxor_for_i8x32() {
   assert_eq!(expected, actual);
 }
 
+#[test]
+fn impl_shl_for_i8x32() {
+  let mut a_arr = [0_i8; 32];
+  for i in 0..32 {
+    a_arr[i] = (i as i8).wrapping_mul(37).wrapping_sub(100);
+  }
+  let a = i8x32::from(a_arr);
+  for shift in 0..8 {
+    let mut expected = [0_i8; 32];
+    for i in 0..32 {
+      expected[i] = a_arr[i] << shift;
+    }
+    let actual = a << shift;
+    assert_eq!(i8x32::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_shr_for_i8x32() {
+  let mut a_arr = [0_i8; 32];
+  for i in 0..32 {
+    a_arr[i] = (i as i8).wrapping_mul(37).wrapping_sub(100);
+  }
+  let a = i8x32::from(a_arr);
+  for shift in 0..8 {
+    let mut expected = [0_i8; 32];
+    for i in 0..32 {
+      expected[i] = a_arr[i] >> shift;
+    }
+    let actual = a >> shift;
+    assert_eq!(i8x32::from(expected), actual);
+  }
+}
+
 #[test]
 fn impl_i8x32_cmp_eq() {
   let a = i8x32::from([
@@ -462,3 +518,495 @@ fn impl_i8x32_min() {
   let actual = a.min(b);
   assert_eq!(expected, actual);
 }
+
+#[test]
+fn impl_i8x32_shl_each() {
+  let a: [i8; 32] = [
+    127, -128, 1, -37, 53, -2, 77, 3, -128, -37, 127, -2, 90, -1, 1, -128,
+    -128, 1, -1, 90, -2, 127, -37, -128, 3, 77, -2, 53, -37, 1, -128, 127,
+  ];
+  // -56 and -1 are the counts 200 and 255 read as unsigned
+  let counts: [i8; 32] = [
+    0, 1, 3, 4, 6, 7, 8, 9, -56, -1, 2, 5, 7, 8, 100, -1, -1, 100, 8, 7, 5, 2,
+    -1, -56, 9, 8, 7, 6, 4, 3, 1, 0,
+  ];
+  let expected: [i8; 32] = [
+    127, 0, 8, -80, 64, 0, 0, 0, 0, 0, -4, -64, 0, 0, 0, 0, 0, 0, 0, 0, -64,
+    -4, 0, 0, 0, 0, 0, 64, -80, 8, 0, 127,
+  ];
+  let actual = i8x32::from(a).shl_each(i8x32::from(counts));
+  assert_eq!(i8x32::from(expected), actual);
+}
+
+#[test]
+fn impl_i8x32_shr_each() {
+  let a: [i8; 32] = [
+    127, -128, 1, -37, 53, -2, 77, 3, -128, -37, 127, -2, 90, -1, 1, -128,
+    -128, 1, -1, 90, -2, 127, -37, -128, 3, 77, -2, 53, -37, 1, -128, 127,
+  ];
+  // -56 and -1 are the counts 200 and 255 read as unsigned
+  let counts: [i8; 32] = [
+    0, 1, 3, 4, 6, 7, 8, 9, -56, -1, 2, 5, 7, 8, 100, -1, -1, 100, 8, 7, 5, 2,
+    -1, -56, 9, 8, 7, 6, 4, 3, 1, 0,
+  ];
+  let expected: [i8; 32] = [
+    127, -64, 0, -3, 0, -1, 0, 0, -1, -1, 31, -1, 0, -1, 0, -1, -1, 0, -1, 0,
+    -1, 31, -1, -1, 0, 0, -1, 0, -3, 0, -64, 127,
+  ];
+  let actual = i8x32::from(a).shr_each(i8x32::from(counts));
+  assert_eq!(i8x32::from(expected), actual);
+}
+
+#[test]
+fn impl_i8x32_saturating_add() {
+  let a: [i8; 32] = [
+    127, -128, 100, -100, 1, -1, 0, 50, 127, -128, 100, -100, 1, -1, 0, 50,
+    127, -128, 100, -100, 1, -1, 0, 50, 127, -128, 100, -100, 1, -1, 0, 50,
+  ];
+  let b: [i8; 32] = [
+    1, -1, 100, -100, -128, 127, 0, -60, 1, -1, 100, -100, -128, 127, 0, -60,
+    1, -1, 100, -100, -128, 127, 0, -60, 1, -1, 100, -100, -128, 127, 0, -60,
+  ];
+  let mut expected = [0; 32];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.saturating_add(*y);
+  }
+  let actual = i8x32::from(a).saturating_add(i8x32::from(b));
+  assert_eq!(i8x32::from(expected), actual);
+}
+
+#[test]
+fn impl_i8x32_saturating_sub() {
+  let a: [i8; 32] = [
+    127, -128, 100, -100, 1, -1, 0, 50, 127, -128, 100, -100, 1, -1, 0, 50,
+    127, -128, 100, -100, 1, -1, 0, 50, 127, -128, 100, -100, 1, -1, 0, 50,
+  ];
+  let b: [i8; 32] = [
+    1, -1, 100, -100, -128, 127, 0, -60, 1, -1, 100, -100, -128, 127, 0, -60,
+    1, -1, 100, -100, -128, 127, 0, -60, 1, -1, 100, -100, -128, 127, 0, -60,
+  ];
+  let mut expected = [0; 32];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.saturating_sub(*y);
+  }
+  let actual = i8x32::from(a).saturating_sub(i8x32::from(b));
+  assert_eq!(i8x32::from(expected), actual);
+}
+
+#[test]
+fn impl_i8x32_clamp() {
+  let min = i8x32::from(-3);
+  let max = i8x32::from(5);
+  let a = i8x32::from([
+    -128, -5, -4, -3, 0, 5, 6, 127, 127, 6, 5, 0, -3, -4, -5, -128, -4, -3, 0,
+    5, 6, 127, -128, -5, 0, -3, -4, -5, -128, 127, 6, 5,
+  ]);
+  let expected = i8x32::from([
+    -3, -3, -3, -3, 0, 5, 5, 5, 5, 5, 5, 0, -3, -3, -3, -3, -3, -3, 0, 5, 5, 5,
+    -3, -3, 0, -3, -3, -3, -3, 5, 5, 5,
+  ]);
+  assert_eq!(expected, a.clamp(min, max));
+}
+
+#[test]
+fn impl_i8x32_reductions() {
+  let a = i8x32::from([
+    -128, 1, 100, -3, -7, 3, 127, 2, -1, 29, 5, -128, 1, 100, -3, -7, 3, 127,
+    2, -1, 29, 5, -128, 1, 100, -3, -7, 3, 127, 2, -1, 29,
+  ]);
+  assert_eq!(a.reduce_min(), -128);
+  assert_eq!(a.reduce_max(), 127);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), 125);
+  let a = i8x32::from([
+    3, 5, -7, 9, 11, -13, 15, 17, -19, 21, 23, -25, 27, 29, -31, 33, 35, -37,
+    39, 41, -43, 45, 47, -49, 51, 53, -55, 57, 59, -61, 63, 65,
+  ]);
+  assert_eq!(a.reduce_min(), -61);
+  assert_eq!(a.reduce_max(), 65);
+  assert_eq!(a.reduce_mul(), -127);
+  assert_eq!(a.reduce_and(), 1);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), 64);
+  let a = i8x32::from([
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 127, -128,
+  ]);
+  assert_eq!(a.reduce_min(), -128);
+  assert_eq!(a.reduce_max(), 127);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), -1);
+  assert_eq!(a.reduce_xor(), -1);
+}
+
+#[test]
+fn impl_i8x32_move_mask() {
+  let a = i8x32::from([
+    -5, 5, 5, -5, 5, 5, -5, 5, 5, -5, 5, 5, -5, 5, 5, -5, 5, 5, -5, 5, 5, -5,
+    5, 5, -5, 5, 5, -5, 5, 5, -5, 5,
+  ]);
+  assert_eq!(a.move_mask(), 0b01001001001001001001001001001001);
+  assert!(a.any());
+  assert!(!a.all());
+  assert!(!a.none());
+  assert!(i8x32::splat(-1).all());
+  assert!(i8x32::splat(1).none());
+}
+
+#[test]
+fn impl_i8x32_lane_queries() {
+  let a = i8x32::from([
+    29, 2, 1, 5, -7, 100, 3, 3, -3, -1, 127, -128, 29, 2, 1, 5, -7, 100, 3, 3,
+    -3, -1, 127, -128, 29, 2, 1, 5, -7, 100, 3, 3,
+  ]);
+  assert_eq!(a.argmin(), (-128, 11));
+  assert_eq!(a.argmax(), (127, 10));
+  let mask = a.cmp_eq(i8x32::splat(3));
+  assert_eq!(mask.first_set(), Some(6));
+  assert_eq!(mask.last_set(), Some(31));
+  assert_eq!(mask.count_set(), 6);
+  let a = i8x32::from([
+    127, -1, -3, 3, 3, 100, -7, 5, 1, 2, 29, -128, 127, -1, -3, 3, 3, 100, -7,
+    5, 1, 2, 29, -128, 127, -1, -3, 3, 3, 100, -7, 5,
+  ]);
+  assert_eq!(a.argmin(), (-128, 11));
+  assert_eq!(a.argmax(), (127, 0));
+  let mask = a.cmp_eq(i8x32::splat(3));
+  assert_eq!(mask.first_set(), Some(3));
+  assert_eq!(mask.last_set(), Some(28));
+  assert_eq!(mask.count_set(), 6);
+  let a = i8x32::from([
+    100, -7, 5, 1, 2, 29, -128, 127, -1, -3, 3, 3, 100, -7, 5, 1, 2, 29, -128,
+    127, -1, -3, 3, 3, 100, -7, 5, 1, 2, 29, -128, 127,
+  ]);
+  assert_eq!(a.argmin(), (-128, 6));
+  assert_eq!(a.argmax(), (127, 7));
+  let mask = a.cmp_eq(i8x32::splat(3));
+  assert_eq!(mask.first_set(), Some(10));
+  assert_eq!(mask.last_set(), Some(23));
+  assert_eq!(mask.count_set(), 4);
+  assert_eq!(i8x32::splat(3).argmin(), (3, 0));
+  assert_eq!(i8x32::splat(3).argmax(), (3, 0));
+  assert_eq!(i8x32::splat(3).cmp_eq(i8x32::splat(3)).last_set(), Some(31));
+}
+
+#[test]
+fn impl_i8x32_swizzle() {
+  let pattern = [0, 15, 3, 16, -1, 9, i8::MAX, 7];
+  let mut table = [0_i8; 32];
+  let mut idx = [0_i8; 32];
+  let mut expected = [0_i8; 32];
+  let mut reversed = [0_i8; 32];
+  for (i, lane) in table.iter_mut().enumerate() {
+    *lane = i as i8 * 3 - 40;
+  }
+  for (i, lane) in idx.iter_mut().enumerate() {
+    *lane = pattern[i % 8];
+    if (0..16).contains(lane) {
+      expected[i] = table[i / 16 * 16 + *lane as usize];
+    }
+  }
+  for (i, lane) in reversed.iter_mut().enumerate() {
+    *lane = table[i / 16 * 16 + 15 - i % 16];
+  }
+  let table = i8x32::from(table);
+  assert_eq!(table.swizzle(i8x32::from(idx)), i8x32::from(expected));
+  // in range indices are the same either way, and stay in their own half
+  let mut idx = [0_i8; 32];
+  for (i, lane) in idx.iter_mut().enumerate() {
+    *lane = 15 - (i % 16) as i8;
+  }
+  let idx = i8x32::from(idx);
+  assert_eq!(table.swizzle(idx), i8x32::from(reversed));
+  assert_eq!(table.swizzle_relaxed(idx), i8x32::from(reversed));
+}
+
+#[test]
+fn impl_i8x32_shuffles() {
+  let v = i8x32::from([
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+    22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+  ]);
+  let w = i8x32::from([
+    33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51,
+    52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+  ]);
+  assert_eq!(
+    shuffle!(
+      i8x32,
+      v,
+      [
+        1, 4, 7, 10, 13, 16, 19, 22, 25, 28, 31, 2, 5, 8, 11, 14, 17, 20, 23,
+        26, 29, 0, 3, 6, 9, 12, 15, 18, 21, 24, 27, 30
+      ]
+    ),
+    i8x32::from([
+      2, 5, 8, 11, 14, 17, 20, 23, 26, 29, 32, 3, 6, 9, 12, 15, 18, 21, 24, 27,
+      30, 1, 4, 7, 10, 13, 16, 19, 22, 25, 28, 31
+    ])
+  );
+  assert_eq!(
+    shuffle!(
+      i8x32,
+      v,
+      [
+        31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31,
+        31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31
+      ]
+    ),
+    i8x32::splat(32)
+  );
+  assert_eq!(
+    shuffle2!(
+      i8x32,
+      v,
+      w,
+      [
+        2, 7, 12, 17, 22, 27, 32, 37, 42, 47, 52, 57, 62, 3, 8, 13, 18, 23, 28,
+        33, 38, 43, 48, 53, 58, 63, 4, 9, 14, 19, 24, 29
+      ]
+    ),
+    i8x32::from([
+      3, 8, 13, 18, 23, 28, 33, 38, 43, 48, 53, 58, 63, 4, 9, 14, 19, 24, 29,
+      34, 39, 44, 49, 54, 59, 64, 5, 10, 15, 20, 25, 30
+    ])
+  );
+  assert_eq!(
+    v.reverse(),
+    i8x32::from([
+      32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16, 15,
+      14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1
+    ])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i8x32, v, 0),
+    i8x32::from([
+      1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+      21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+    ])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i8x32, v, 0),
+    i8x32::from([
+      1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+      21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+    ])
+  );
+  assert_eq!(
+    shift_lanes_left!(i8x32, v, 0),
+    i8x32::from([
+      1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+      21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+    ])
+  );
+  assert_eq!(
+    shift_lanes_right!(i8x32, v, 0),
+    i8x32::from([
+      1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+      21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+    ])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i8x32, v, 1),
+    i8x32::from([
+      2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+      22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 1
+    ])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i8x32, v, 1),
+    i8x32::from([
+      32, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+      20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31
+    ])
+  );
+  assert_eq!(
+    shift_lanes_left!(i8x32, v, 1),
+    i8x32::from([
+      2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+      22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 0
+    ])
+  );
+  assert_eq!(
+    shift_lanes_right!(i8x32, v, 1),
+    i8x32::from([
+      0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+      21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31
+    ])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i8x32, v, 17),
+    i8x32::from([
+      18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 1, 2, 3, 4,
+      5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17
+    ])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i8x32, v, 17),
+    i8x32::from([
+      16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 1, 2,
+      3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15
+    ])
+  );
+  assert_eq!(
+    shift_lanes_left!(i8x32, v, 17),
+    i8x32::from([
+      18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 0, 0, 0, 0,
+      0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
+    ])
+  );
+  assert_eq!(
+    shift_lanes_right!(i8x32, v, 17),
+    i8x32::from([
+      0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7,
+      8, 9, 10, 11, 12, 13, 14, 15
+    ])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i8x32, v, 31),
+    i8x32::from([
+      32, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+      20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31
+    ])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i8x32, v, 31),
+    i8x32::from([
+      2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+      22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 1
+    ])
+  );
+  assert_eq!(
+    shift_lanes_left!(i8x32, v, 31),
+    i8x32::from([
+      32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+      0, 0, 0, 0, 0, 0, 0, 0
+    ])
+  );
+  assert_eq!(
+    shift_lanes_right!(i8x32, v, 31),
+    i8x32::from([
+      0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+      0, 0, 0, 0, 0, 0, 0, 1
+    ])
+  );
+  assert_eq!(
+    rotate_lanes_left!(i8x32, v, 32),
+    i8x32::from([
+      1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+      21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+    ])
+  );
+  assert_eq!(
+    rotate_lanes_right!(i8x32, v, 32),
+    i8x32::from([
+      1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+      21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+    ])
+  );
+  assert_eq!(
+    shift_lanes_left!(i8x32, v, 32),
+    i8x32::from([
+      0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+      0, 0, 0, 0, 0, 0, 0, 0
+    ])
+  );
+  assert_eq!(
+    shift_lanes_right!(i8x32, v, 32),
+    i8x32::from([
+      0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+      0, 0, 0, 0, 0, 0, 0, 0
+    ])
+  );
+}
+
+#[test]
+fn impl_i8x32_zip_unzip() {
+  let a = i8x32::from([
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+    22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+  ]);
+  let b = i8x32::from([
+    33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51,
+    52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+  ]);
+  assert_eq!(
+    a.zip_lo(b),
+    i8x32::from([
+      1, 33, 2, 34, 3, 35, 4, 36, 5, 37, 6, 38, 7, 39, 8, 40, 9, 41, 10, 42,
+      11, 43, 12, 44, 13, 45, 14, 46, 15, 47, 16, 48
+    ])
+  );
+  assert_eq!(
+    a.zip_hi(b),
+    i8x32::from([
+      17, 49, 18, 50, 19, 51, 20, 52, 21, 53, 22, 54, 23, 55, 24, 56, 25, 57,
+      26, 58, 27, 59, 28, 60, 29, 61, 30, 62, 31, 63, 32, 64
+    ])
+  );
+  assert_eq!(
+    a.unzip_even(b),
+    i8x32::from([
+      1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33, 35, 37,
+      39, 41, 43, 45, 47, 49, 51, 53, 55, 57, 59, 61, 63
+    ])
+  );
+  assert_eq!(
+    a.unzip_odd(b),
+    i8x32::from([
+      2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32, 34, 36, 38,
+      40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62, 64
+    ])
+  );
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+  // lanes with the top bit set, which the packs must not saturate
+  let c = i8x32::from([
+    -128, -1, 127, -2, 64, -64, 1, 0, -3, 127, -128, 5, -5, -1, 7, -7, -1,
+    -128, 0, 127, -2, 2, 100, -100, -127, 126, 3, -3, -50, 50, -1, 1,
+  ]);
+  let d = i8x32::from([
+    -1, 1, -128, 127, 0, -2, -56, 100, 1, -1, -127, 2, -6, 5, 64, -64, 0, -1,
+    -2, 1, -128, -128, 3, -3, -1, 0, 17, -16, 99, -57, 7, -8,
+  ]);
+  assert_eq!(
+    c.unzip_even(d),
+    i8x32::from([
+      -128, 127, 64, 1, -3, -128, -5, 7, -1, 0, -2, 100, -127, 3, -50, -1, -1,
+      -128, 0, -56, 1, -127, -6, 64, 0, -2, -128, 3, -1, 17, 99, 7
+    ])
+  );
+  assert_eq!(
+    c.unzip_odd(d),
+    i8x32::from([
+      -1, -2, -64, 0, 127, 5, -1, -7, -128, 127, 2, -100, 126, -3, 50, 1, 1,
+      127, -2, 100, -1, 2, 5, -64, -1, 1, -128, -3, 0, -16, -57, -8
+    ])
+  );
+}
+
+#[test]
+fn impl_i8x32_halves() {
+  let v = i8x32::from([
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+    22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+  ]);
+  let lo = i8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+  let hi = i8x16::from([
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+  ]);
+  assert_eq!(v.low(), lo);
+  assert_eq!(v.high(), hi);
+  assert_eq!(v.split(), (lo, hi));
+  assert_eq!(i8x32::from_halves(lo, hi), v);
+  assert_eq!(
+    i8x32::from_halves(hi, lo),
+    i8x32::from([
+      17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 1, 2, 3,
+      4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
+    ])
+  );
+}
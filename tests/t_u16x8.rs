@@ -124,3 +124,391 @@ fn impl_u16x8_min() {
   let actual = a.min(b);
   assert_eq!(expected, actual);
 }
+
+#[test]
+fn impl_u16x8_cmp_gt() {
+  let a = u16x8::from([1, 2, 3, 4, 60000, 0, u16::MAX, 40000]);
+  let b = u16x8::from([2, 2, 2, 2, 40000, 40000, 40000, 40000]);
+  let expected = u16x8::from([0, 0, u16::MAX, u16::MAX, u16::MAX, 0, u16::MAX, 0]);
+  let actual = a.cmp_gt(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u16x8_cmp_lt() {
+  let a = u16x8::from([1, 2, 3, 4, 60000, 0, u16::MAX, 40000]);
+  let b = u16x8::from([2, 2, 2, 2, 40000, 40000, 40000, 40000]);
+  let expected = u16x8::from([u16::MAX, 0, 0, 0, 0, u16::MAX, 0, 0]);
+  let actual = a.cmp_lt(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u16x8_cmp_ge() {
+  let a = u16x8::from([1, 2, 3, 4, 60000, 0, u16::MAX, 40000]);
+  let b = u16x8::from([2, 2, 2, 2, 40000, 40000, 40000, 40000]);
+  let expected =
+    u16x8::from([0, u16::MAX, u16::MAX, u16::MAX, u16::MAX, 0, u16::MAX, u16::MAX]);
+  let actual = a.cmp_ge(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u16x8_cmp_le() {
+  let a = u16x8::from([1, 2, 3, 4, 60000, 0, u16::MAX, 40000]);
+  let b = u16x8::from([2, 2, 2, 2, 40000, 40000, 40000, 40000]);
+  let expected = u16x8::from([u16::MAX, u16::MAX, 0, 0, 0, u16::MAX, 0, u16::MAX]);
+  let actual = a.cmp_le(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u16x8_cmp_ne() {
+  let a = u16x8::from([1, 2, 3, 4, 60000, 0, u16::MAX, 40000]);
+  let b = u16x8::from([2, 2, 2, 2, 40000, 40000, 40000, 40000]);
+  let expected =
+    u16x8::from([u16::MAX, 0, u16::MAX, u16::MAX, u16::MAX, u16::MAX, u16::MAX, 0]);
+  let actual = a.cmp_ne(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u16x8_shl_each() {
+  let a: [u16; 8] = [0x1234, 0xEDCC, u16::MAX, 0xFFFE, 0x8000, 1024, 0xFFDB, 3];
+  let counts: [u16; 8] = [0, 5, 9, 15, 16, 17, 200, u16::MAX];
+  let expected: [u16; 8] = [0x1234, 0xB980, 0xFE00, 0, 0, 0, 0, 0];
+  let actual = u16x8::from(a).shl_each(u16x8::from(counts));
+  assert_eq!(u16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_u16x8_shr_each() {
+  let a: [u16; 8] = [0x1234, 0xEDCC, u16::MAX, 0xFFFE, 0x8000, 1024, 0xFFDB, 3];
+  let counts: [u16; 8] = [0, 5, 9, 15, 16, 17, 200, u16::MAX];
+  let expected: [u16; 8] = [0x1234, 1902, 127, 1, 0, 0, 0, 0];
+  let actual = u16x8::from(a).shr_each(u16x8::from(counts));
+  assert_eq!(u16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_u16x8_saturating_add() {
+  let a: [u16; 8] = [u16::MAX, 0, 300, 1000, 255, 2, 0, 256];
+  let b: [u16; 8] = [1, 1, 300, 70, 257, u16::MAX, 7, 256];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.saturating_add(*y);
+  }
+  let actual = u16x8::from(a).saturating_add(u16x8::from(b));
+  assert_eq!(u16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_u16x8_saturating_sub() {
+  let a: [u16; 8] = [u16::MAX, 0, 300, 1000, 255, 2, 0, 256];
+  let b: [u16; 8] = [1, 1, 300, 70, 257, u16::MAX, 7, 256];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.saturating_sub(*y);
+  }
+  let actual = u16x8::from(a).saturating_sub(u16x8::from(b));
+  assert_eq!(u16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_u16x8_saturating_mul() {
+  let a: [u16; 8] = [u16::MAX, 0, 300, 1000, 255, 2, 0, 256];
+  let b: [u16; 8] = [1, 1, 300, 70, 257, u16::MAX, 7, 256];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.saturating_mul(*y);
+  }
+  let actual = u16x8::from(a).saturating_mul(u16x8::from(b));
+  assert_eq!(u16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_mul_for_u16x8() {
+  let a = u16x8::from([2, u16::MAX, 300, 7, 2, u16::MAX, 300, 7]);
+  let b = u16x8::from([3, 2, 1000, 0, 3, 2, 1000, 0]);
+  let expected = u16x8::from([
+    6,
+    u16::MAX - 1,
+    300_u16.wrapping_mul(1000),
+    0,
+    6,
+    u16::MAX - 1,
+    300_u16.wrapping_mul(1000),
+    0,
+  ]);
+  let actual = a * b;
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_div_for_u16x8() {
+  let a: [u16; 8] = [u16::MAX, 7, 6, 100, 99, 0, 1, 13];
+  let b: [u16; 8] = [1, 2, 3, 7, 9, 10, 5, 13];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_div(*y);
+  }
+  let actual = u16x8::from(a) / u16x8::from(b);
+  assert_eq!(u16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_rem_for_u16x8() {
+  let a: [u16; 8] = [u16::MAX, 7, 6, 100, 99, 0, 1, 13];
+  let b: [u16; 8] = [1, 2, 3, 7, 9, 10, 5, 13];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_rem(*y);
+  }
+  let actual = u16x8::from(a) % u16x8::from(b);
+  assert_eq!(u16x8::from(expected), actual);
+}
+
+#[test]
+fn impl_u16x8_divisor() {
+  let nums: [u16; 16] =
+    [0, 1, 0, 1, 6, 7, 8, 1000, u16::MAX, 13, 99, 100, 2, 3, 50, 51];
+  // numerators next to the extremes and at or near powers of two
+  let edges: [u16; 8] =
+    [u16::MAX - 1, u16::MAX / 2, u16::MAX / 2 + 1, 4, 16, 1024, 1023, 2];
+  let divisors = [7, 1, 3, u16::MAX, 2, 16, 1 << 15, u16::MAX - 1];
+  for d in divisors.iter().copied() {
+    let divisor = Divisor::<u16x8>::new(d);
+    for chunk in nums.chunks(8).chain(edges.chunks(8)) {
+      let mut a = [0; 8];
+      a.copy_from_slice(chunk);
+      let mut q = a;
+      let mut r = a;
+      for ((q, r), x) in q.iter_mut().zip(r.iter_mut()).zip(a.iter()) {
+        *q = x.wrapping_div(d);
+        *r = x.wrapping_rem(d);
+      }
+      assert_eq!(u16x8::from(a) / divisor, u16x8::from(q));
+      assert_eq!(u16x8::from(a) % divisor, u16x8::from(r));
+      assert_eq!(u16x8::from(a) / d, u16x8::from(q));
+      assert_eq!(u16x8::from(a) % d, u16x8::from(r));
+    }
+  }
+}
+
+#[test]
+fn impl_u16x8_clamp() {
+  let min = u16x8::from(3);
+  let max = u16x8::from(6);
+  let a = u16x8::from([0, 2, 3, 5, 6, 7, 9, u16::MAX]);
+  let expected = u16x8::from([3, 3, 3, 5, 6, 6, 6, 6]);
+  assert_eq!(expected, a.clamp(min, max));
+}
+
+#[test]
+fn impl_u16x8_reductions() {
+  let a = u16x8::from([1, 7, u16::MAX, 64, 2, 29, u16::MAX - 1, 200]);
+  assert_eq!(a.reduce_min(), 1);
+  assert_eq!(a.reduce_max(), u16::MAX);
+  assert_eq!(a.reduce_mul(), 38912);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), u16::MAX);
+  assert_eq!(a.reduce_xor(), 144);
+  let a = u16x8::from([3, 5, 7, 9, 11, 13, 15, 17]);
+  assert_eq!(a.reduce_min(), 3);
+  assert_eq!(a.reduce_max(), 17);
+  assert_eq!(a.reduce_mul(), 53025);
+  assert_eq!(a.reduce_and(), 1);
+  assert_eq!(a.reduce_or(), 31);
+  assert_eq!(a.reduce_xor(), 16);
+  let a = u16x8::from([6, 6, 6, 6, 6, 6, u16::MAX, 0]);
+  assert_eq!(a.reduce_min(), 0);
+  assert_eq!(a.reduce_max(), u16::MAX);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), u16::MAX);
+  assert_eq!(a.reduce_xor(), u16::MAX);
+}
+
+#[test]
+fn impl_u16x8_move_mask() {
+  let a = u16x8::from([u16::MAX, 1, 1, u16::MAX, 1, 1, u16::MAX, 1]);
+  assert_eq!(a.move_mask(), 0b01001001);
+  assert!(a.any());
+  assert!(!a.all());
+  assert!(!a.none());
+  assert!(u16x8::splat(u16::MAX).all());
+  assert!(u16x8::splat(1).none());
+}
+
+#[test]
+fn impl_u16x8_lane_queries() {
+  let a = u16x8::from([2, u16::MAX, 3, 29, 64, 3, u16::MAX - 1, 1]);
+  assert_eq!(a.argmin(), (1, 7));
+  assert_eq!(a.argmax(), (u16::MAX, 1));
+  let mask = a.cmp_eq(u16x8::splat(3));
+  assert_eq!(mask.first_set(), Some(2));
+  assert_eq!(mask.last_set(), Some(5));
+  assert_eq!(mask.count_set(), 2);
+  let a = u16x8::from([u16::MAX, 2, 5, 7, 200, 100, 1, u16::MAX - 1]);
+  assert_eq!(a.argmin(), (1, 6));
+  assert_eq!(a.argmax(), (u16::MAX, 0));
+  let mask = a.cmp_eq(u16x8::splat(3));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  let a = u16x8::from([u16::MAX - 1, 1, 100, 200, 7, 5, 2, u16::MAX]);
+  assert_eq!(a.argmin(), (1, 1));
+  assert_eq!(a.argmax(), (u16::MAX, 7));
+  let mask = a.cmp_eq(u16x8::splat(3));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  assert_eq!(u16x8::splat(3).argmin(), (3, 0));
+  assert_eq!(u16x8::splat(3).argmax(), (3, 0));
+  assert_eq!(u16x8::splat(3).cmp_eq(u16x8::splat(3)).last_set(), Some(7));
+}
+
+#[test]
+fn impl_u16x8_shuffles() {
+  let v = u16x8::from([1, 2, 3, 4, 5, 6, 7, 8]);
+  let w = u16x8::from([9, 10, 11, 12, 13, 14, 15, 16]);
+  assert_eq!(
+    shuffle!(u16x8, v, [1, 4, 7, 2, 5, 0, 3, 6]),
+    u16x8::from([2, 5, 8, 3, 6, 1, 4, 7])
+  );
+  assert_eq!(shuffle!(u16x8, v, [7, 7, 7, 7, 7, 7, 7, 7]), u16x8::splat(8));
+  assert_eq!(
+    shuffle2!(u16x8, v, w, [2, 7, 12, 1, 6, 11, 0, 5]),
+    u16x8::from([3, 8, 13, 2, 7, 12, 1, 6])
+  );
+  assert_eq!(v.reverse(), u16x8::from([8, 7, 6, 5, 4, 3, 2, 1]));
+  assert_eq!(
+    rotate_lanes_left!(u16x8, v, 0),
+    u16x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u16x8, v, 0),
+    u16x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    shift_lanes_left!(u16x8, v, 0),
+    u16x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    shift_lanes_right!(u16x8, v, 0),
+    u16x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    rotate_lanes_left!(u16x8, v, 1),
+    u16x8::from([2, 3, 4, 5, 6, 7, 8, 1])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u16x8, v, 1),
+    u16x8::from([8, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    shift_lanes_left!(u16x8, v, 1),
+    u16x8::from([2, 3, 4, 5, 6, 7, 8, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(u16x8, v, 1),
+    u16x8::from([0, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    rotate_lanes_left!(u16x8, v, 5),
+    u16x8::from([6, 7, 8, 1, 2, 3, 4, 5])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u16x8, v, 5),
+    u16x8::from([4, 5, 6, 7, 8, 1, 2, 3])
+  );
+  assert_eq!(
+    shift_lanes_left!(u16x8, v, 5),
+    u16x8::from([6, 7, 8, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(u16x8, v, 5),
+    u16x8::from([0, 0, 0, 0, 0, 1, 2, 3])
+  );
+  assert_eq!(
+    rotate_lanes_left!(u16x8, v, 7),
+    u16x8::from([8, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u16x8, v, 7),
+    u16x8::from([2, 3, 4, 5, 6, 7, 8, 1])
+  );
+  assert_eq!(
+    shift_lanes_left!(u16x8, v, 7),
+    u16x8::from([8, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(u16x8, v, 7),
+    u16x8::from([0, 0, 0, 0, 0, 0, 0, 1])
+  );
+  assert_eq!(
+    rotate_lanes_left!(u16x8, v, 8),
+    u16x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u16x8, v, 8),
+    u16x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    shift_lanes_left!(u16x8, v, 8),
+    u16x8::from([0, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(u16x8, v, 8),
+    u16x8::from([0, 0, 0, 0, 0, 0, 0, 0])
+  );
+}
+
+#[test]
+fn impl_u16x8_zip_unzip() {
+  let a = u16x8::from([1, 2, 3, 4, 5, 6, 7, 8]);
+  let b = u16x8::from([9, 10, 11, 12, 13, 14, 15, 16]);
+  assert_eq!(a.zip_lo(b), u16x8::from([1, 9, 2, 10, 3, 11, 4, 12]));
+  assert_eq!(a.zip_hi(b), u16x8::from([5, 13, 6, 14, 7, 15, 8, 16]));
+  assert_eq!(a.unzip_even(b), u16x8::from([1, 3, 5, 7, 9, 11, 13, 15]));
+  assert_eq!(a.unzip_odd(b), u16x8::from([2, 4, 6, 8, 10, 12, 14, 16]));
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+  // lanes with the top bit set, which the packs must not saturate
+  let c = u16x8::from([u16::MAX, 1, 32768, 32767, 256, 65280, 0, 255]);
+  let d = u16x8::from([1, u16::MAX, 65534, 0, 32769, 2, 255, 256]);
+  assert_eq!(
+    c.unzip_even(d),
+    u16x8::from([u16::MAX, 32768, 256, 0, 1, 65534, 32769, 255])
+  );
+  assert_eq!(
+    c.unzip_odd(d),
+    u16x8::from([1, 32767, 65280, 255, u16::MAX, 0, 2, 256])
+  );
+}
+
+#[test]
+fn impl_u16x8_widen() {
+  let a = u16x8::from([0, u16::MAX, 1, 2, 100, u16::MAX - 1, 32768, 0]);
+  assert_eq!(a.widen_low(), u32x4::from([0, 65535, 1, 2]));
+  assert_eq!(a.widen_high(), u32x4::from([100, 65534, 32768, 0]));
+  assert_eq!(a.widen(), u32x8::from([0, 65535, 1, 2, 100, 65534, 32768, 0]));
+}
+
+#[test]
+fn impl_u16x8_narrow() {
+  let a = u16x8::from([0, u16::MAX, 1, 2, 100, u16::MAX - 1, 32768, 255]);
+  let b = u16x8::from([256, 128, 0, u16::MAX, 1, 2, 100, u16::MAX - 1]);
+  assert_eq!(
+    a.narrow_wrapping(b),
+    u8x16::from([
+      0, 255, 1, 2, 100, 254, 0, 255, 0, 128, 0, 255, 1, 2, 100, 254
+    ])
+  );
+  assert_eq!(
+    a.narrow_saturating(b),
+    u8x16::from([
+      0, 255, 1, 2, 100, 255, 255, 255, 255, 128, 0, 255, 1, 2, 100, 255
+    ])
+  );
+}
@@ -108,3 +108,311 @@ fn impl_u32x4_min() {
   let actual = a.min(b);
   assert_eq!(expected, actual);
 }
+
+#[test]
+fn impl_u32x4_cmp_gt() {
+  let a = u32x4::from([1, 2, u32::MAX, 0]);
+  let b = u32x4::from([2, 2, 1 << 31, 1 << 31]);
+  let expected = u32x4::from([0, 0, u32::MAX, 0]);
+  let actual = a.cmp_gt(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u32x4_cmp_lt() {
+  let a = u32x4::from([1, 2, u32::MAX, 0]);
+  let b = u32x4::from([2, 2, 1 << 31, 1 << 31]);
+  let expected = u32x4::from([u32::MAX, 0, 0, u32::MAX]);
+  let actual = a.cmp_lt(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u32x4_cmp_ge() {
+  let a = u32x4::from([1, 2, u32::MAX, 0]);
+  let b = u32x4::from([2, 2, 1 << 31, 1 << 31]);
+  let expected = u32x4::from([0, u32::MAX, u32::MAX, 0]);
+  let actual = a.cmp_ge(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u32x4_cmp_le() {
+  let a = u32x4::from([1, 2, u32::MAX, 0]);
+  let b = u32x4::from([2, 2, 1 << 31, 1 << 31]);
+  let expected = u32x4::from([u32::MAX, u32::MAX, 0, u32::MAX]);
+  let actual = a.cmp_le(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u32x4_cmp_ne() {
+  let a = u32x4::from([1, 2, u32::MAX, 0]);
+  let b = u32x4::from([2, 2, 1 << 31, 1 << 31]);
+  let expected = u32x4::from([u32::MAX, 0, u32::MAX, u32::MAX]);
+  let actual = a.cmp_ne(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u32x4_shl_each() {
+  let table: [([u32; 4], [u32; 4], [u32; 4]); 2] = [
+    (
+      [0x1234_5678, 0xEDCB_A988, 0x8000_0000, u32::MAX],
+      [0, 7, 19, 31],
+      [0x1234_5678, 0xE5D4_C400, 0, 0x8000_0000],
+    ),
+    (
+      [0xFFFF_FFDB, 0x10_0000, 0xFFFF_FFFE, 3],
+      [32, 33, 200, u32::MAX],
+      [0, 0, 0, 0],
+    ),
+  ];
+  for &(a, counts, expected) in table.iter() {
+    let actual = u32x4::from(a).shl_each(u32x4::from(counts));
+    assert_eq!(u32x4::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_u32x4_shr_each() {
+  let table: [([u32; 4], [u32; 4], [u32; 4]); 2] = [
+    (
+      [0x1234_5678, 0xEDCB_A988, 0x8000_0000, u32::MAX],
+      [0, 7, 19, 31],
+      [0x1234_5678, 0x1DB_9753, 4096, 1],
+    ),
+    (
+      [0xFFFF_FFDB, 0x10_0000, 0xFFFF_FFFE, 3],
+      [32, 33, 200, u32::MAX],
+      [0, 0, 0, 0],
+    ),
+  ];
+  for &(a, counts, expected) in table.iter() {
+    let actual = u32x4::from(a).shr_each(u32x4::from(counts));
+    assert_eq!(u32x4::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_mul_for_u32x4() {
+  let a = u32x4::from([2, u32::MAX, 300, 7]);
+  let b = u32x4::from([3, 2, 1000, 0]);
+  let expected = u32x4::from([6, u32::MAX - 1, 300_u32.wrapping_mul(1000), 0]);
+  let actual = a * b;
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_div_for_u32x4() {
+  let a: [u32; 4] = [u32::MAX, 7, 6, 100];
+  let b: [u32; 4] = [1, 2, 3, 7];
+  let mut expected = [0; 4];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_div(*y);
+  }
+  let actual = u32x4::from(a) / u32x4::from(b);
+  assert_eq!(u32x4::from(expected), actual);
+}
+
+#[test]
+fn impl_rem_for_u32x4() {
+  let a: [u32; 4] = [u32::MAX, 7, 6, 100];
+  let b: [u32; 4] = [1, 2, 3, 7];
+  let mut expected = [0; 4];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_rem(*y);
+  }
+  let actual = u32x4::from(a) % u32x4::from(b);
+  assert_eq!(u32x4::from(expected), actual);
+}
+
+#[test]
+fn impl_u32x4_divisor() {
+  let nums: [u32; 16] =
+    [0, 1, 0, 1, 6, 7, 8, 1000, u32::MAX, 13, 99, 100, 2, 3, 50, 51];
+  // numerators next to the extremes and at or near powers of two
+  let edges: [u32; 8] =
+    [u32::MAX - 1, u32::MAX / 2, u32::MAX / 2 + 1, 4, 16, 1024, 1023, 2];
+  let divisors = [7, 1, 3, u32::MAX, 2, 16, 1 << 31, u32::MAX - 1];
+  for d in divisors.iter().copied() {
+    let divisor = Divisor::<u32x4>::new(d);
+    for chunk in nums.chunks(4).chain(edges.chunks(4)) {
+      let mut a = [0; 4];
+      a.copy_from_slice(chunk);
+      let mut q = a;
+      let mut r = a;
+      for ((q, r), x) in q.iter_mut().zip(r.iter_mut()).zip(a.iter()) {
+        *q = x.wrapping_div(d);
+        *r = x.wrapping_rem(d);
+      }
+      assert_eq!(u32x4::from(a) / divisor, u32x4::from(q));
+      assert_eq!(u32x4::from(a) % divisor, u32x4::from(r));
+      assert_eq!(u32x4::from(a) / d, u32x4::from(q));
+      assert_eq!(u32x4::from(a) % d, u32x4::from(r));
+    }
+  }
+}
+
+#[test]
+fn impl_u32x4_clamp() {
+  let min = u32x4::from(3);
+  let max = u32x4::from(6);
+  let a = u32x4::from([0, 2, 3, 5]);
+  let expected = u32x4::from([3, 3, 3, 5]);
+  assert_eq!(expected, a.clamp(min, max));
+  let a = u32x4::from([6, 7, 9, u32::MAX]);
+  let expected = u32x4::from([6, 6, 6, 6]);
+  assert_eq!(expected, a.clamp(min, max));
+}
+
+#[test]
+fn impl_u32x4_reductions() {
+  let a = u32x4::from([1, 7, u32::MAX, 64]);
+  assert_eq!(a.reduce_min(), 1);
+  assert_eq!(a.reduce_max(), u32::MAX);
+  assert_eq!(a.reduce_mul(), u32::MAX - 447);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), u32::MAX);
+  assert_eq!(a.reduce_xor(), u32::MAX - 70);
+  let a = u32x4::from([3, 5, 7, 9]);
+  assert_eq!(a.reduce_min(), 3);
+  assert_eq!(a.reduce_max(), 9);
+  assert_eq!(a.reduce_mul(), 945);
+  assert_eq!(a.reduce_and(), 1);
+  assert_eq!(a.reduce_or(), 15);
+  assert_eq!(a.reduce_xor(), 8);
+  let a = u32x4::from([6, 6, u32::MAX, 0]);
+  assert_eq!(a.reduce_min(), 0);
+  assert_eq!(a.reduce_max(), u32::MAX);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), u32::MAX);
+  assert_eq!(a.reduce_xor(), u32::MAX);
+}
+
+#[test]
+fn impl_u32x4_move_mask() {
+  let a = u32x4::from([u32::MAX, 1, 1, u32::MAX]);
+  assert_eq!(a.move_mask(), 0b1001);
+  assert!(a.any());
+  assert!(!a.all());
+  assert!(!a.none());
+  assert!(u32x4::splat(u32::MAX).all());
+  assert!(u32x4::splat(1).none());
+}
+
+#[test]
+fn impl_u32x4_lane_queries() {
+  let a = u32x4::from([3, 29, 64, 3]);
+  assert_eq!(a.argmin(), (3, 0));
+  assert_eq!(a.argmax(), (64, 2));
+  let mask = a.cmp_eq(u32x4::splat(3));
+  assert_eq!(mask.first_set(), Some(0));
+  assert_eq!(mask.last_set(), Some(3));
+  assert_eq!(mask.count_set(), 2);
+  let a = u32x4::from([200, 7, 5, 2]);
+  assert_eq!(a.argmin(), (2, 3));
+  assert_eq!(a.argmax(), (200, 0));
+  let mask = a.cmp_eq(u32x4::splat(3));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  assert_eq!(u32x4::splat(3).argmin(), (3, 0));
+  assert_eq!(u32x4::splat(3).argmax(), (3, 0));
+  assert_eq!(u32x4::splat(3).cmp_eq(u32x4::splat(3)).last_set(), Some(3));
+}
+
+#[test]
+fn impl_u32x4_shuffles() {
+  let v = u32x4::from([1, 2, 3, 4]);
+  let w = u32x4::from([5, 6, 7, 8]);
+  assert_eq!(shuffle!(u32x4, v, [1, 0, 3, 2]), u32x4::from([2, 1, 4, 3]));
+  assert_eq!(shuffle!(u32x4, v, [3, 3, 3, 3]), u32x4::splat(4));
+  assert_eq!(shuffle2!(u32x4, v, w, [2, 7, 4, 1]), u32x4::from([3, 8, 5, 2]));
+  assert_eq!(v.reverse(), u32x4::from([4, 3, 2, 1]));
+  assert_eq!(rotate_lanes_left!(u32x4, v, 0), u32x4::from([1, 2, 3, 4]));
+  assert_eq!(rotate_lanes_right!(u32x4, v, 0), u32x4::from([1, 2, 3, 4]));
+  assert_eq!(shift_lanes_left!(u32x4, v, 0), u32x4::from([1, 2, 3, 4]));
+  assert_eq!(shift_lanes_right!(u32x4, v, 0), u32x4::from([1, 2, 3, 4]));
+  assert_eq!(rotate_lanes_left!(u32x4, v, 1), u32x4::from([2, 3, 4, 1]));
+  assert_eq!(rotate_lanes_right!(u32x4, v, 1), u32x4::from([4, 1, 2, 3]));
+  assert_eq!(shift_lanes_left!(u32x4, v, 1), u32x4::from([2, 3, 4, 0]));
+  assert_eq!(shift_lanes_right!(u32x4, v, 1), u32x4::from([0, 1, 2, 3]));
+  assert_eq!(rotate_lanes_left!(u32x4, v, 3), u32x4::from([4, 1, 2, 3]));
+  assert_eq!(rotate_lanes_right!(u32x4, v, 3), u32x4::from([2, 3, 4, 1]));
+  assert_eq!(shift_lanes_left!(u32x4, v, 3), u32x4::from([4, 0, 0, 0]));
+  assert_eq!(shift_lanes_right!(u32x4, v, 3), u32x4::from([0, 0, 0, 1]));
+  assert_eq!(rotate_lanes_left!(u32x4, v, 4), u32x4::from([1, 2, 3, 4]));
+  assert_eq!(rotate_lanes_right!(u32x4, v, 4), u32x4::from([1, 2, 3, 4]));
+  assert_eq!(shift_lanes_left!(u32x4, v, 4), u32x4::from([0, 0, 0, 0]));
+  assert_eq!(shift_lanes_right!(u32x4, v, 4), u32x4::from([0, 0, 0, 0]));
+}
+
+#[test]
+fn impl_u32x4_zip_unzip() {
+  let a = u32x4::from([1, 2, 3, 4]);
+  let b = u32x4::from([5, 6, 7, 8]);
+  assert_eq!(a.zip_lo(b), u32x4::from([1, 5, 2, 6]));
+  assert_eq!(a.zip_hi(b), u32x4::from([3, 7, 4, 8]));
+  assert_eq!(a.unzip_even(b), u32x4::from([1, 3, 5, 7]));
+  assert_eq!(a.unzip_odd(b), u32x4::from([2, 4, 6, 8]));
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+}
+
+#[test]
+fn impl_u32x4_transpose4() {
+  let mut m = [[u32::default(); 4]; 4];
+  let mut mt = [[u32::default(); 4]; 4];
+  for i in 0..4 {
+    for j in 0..4 {
+      m[i][j] = (i * 4 + j) as u32;
+      mt[j][i] = m[i][j];
+    }
+  }
+  let mut rows = [u32x4::default(); 4];
+  let mut expected = [u32x4::default(); 4];
+  for i in 0..4 {
+    rows[i] = u32x4::from(m[i]);
+    expected[i] = u32x4::from(mt[i]);
+  }
+  assert_eq!(u32x4::transpose4(rows), expected);
+  assert_eq!(u32x4::transpose4(expected), rows);
+}
+
+#[test]
+fn impl_u32x4_widen() {
+  let a = u32x4::from([0, u32::MAX, 1, 2]);
+  assert_eq!(a.widen_low(), u64x2::from([0, 4294967295]));
+  assert_eq!(a.widen_high(), u64x2::from([1, 2]));
+  assert_eq!(a.widen(), u64x4::from([0, 4294967295, 1, 2]));
+  let a = u32x4::from([100, u32::MAX - 1, 2147483648, 0]);
+  assert_eq!(a.widen_low(), u64x2::from([100, 4294967294]));
+  assert_eq!(a.widen_high(), u64x2::from([2147483648, 0]));
+  assert_eq!(a.widen(), u64x4::from([100, 4294967294, 2147483648, 0]));
+}
+
+#[test]
+fn impl_u32x4_narrow() {
+  let a = u32x4::from([0, u32::MAX, 1, 2]);
+  let b = u32x4::from([100, u32::MAX - 1, 2147483648, 65535]);
+  assert_eq!(
+    a.narrow_wrapping(b),
+    u16x8::from([0, u16::MAX, 1, 2, 100, u16::MAX - 1, 0, u16::MAX])
+  );
+  assert_eq!(
+    a.narrow_saturating(b),
+    u16x8::from([0, u16::MAX, 1, 2, 100, u16::MAX, u16::MAX, u16::MAX])
+  );
+  let a = u32x4::from([65536, 32768, 0, u32::MAX]);
+  let b = u32x4::from([1, 2, 100, u32::MAX - 1]);
+  assert_eq!(
+    a.narrow_wrapping(b),
+    u16x8::from([0, 32768, 0, u16::MAX, 1, 2, 100, u16::MAX - 1])
+  );
+  assert_eq!(
+    a.narrow_saturating(b),
+    u16x8::from([u16::MAX, 32768, 0, u16::MAX, 1, 2, 100, u16::MAX])
+  );
+}
@@ -134,3 +134,402 @@ fn impl_u32x8_min() {
   let actual = a.min(b);
   assert_eq!(expected, actual);
 }
+
+#[test]
+fn impl_u32x8_unsigned_cmp_gt() {
+  let a = u32x8::from([1, 2, 9, u32::MAX, 0, 1 << 31, 8, 10]);
+  let b = u32x8::from([5, 5, 5, 5, 1 << 31, 5, 5, 5]);
+  let expected =
+    u32x8::from([0, 0, u32::MAX, u32::MAX, 0, u32::MAX, u32::MAX, u32::MAX]);
+  let actual = a.cmp_gt(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u32x8_cmp_lt() {
+  let a = u32x8::from([1, 2, 9, u32::MAX, 0, 1 << 31, 8, 5]);
+  let b = u32x8::from([5, 5, 5, 5, 1 << 31, 5, 5, 5]);
+  let expected = u32x8::from([u32::MAX, u32::MAX, 0, 0, u32::MAX, 0, 0, 0]);
+  let actual = a.cmp_lt(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u32x8_cmp_ge() {
+  let a = u32x8::from([1, 2, 9, u32::MAX, 0, 1 << 31, 8, 5]);
+  let b = u32x8::from([5, 5, 5, 5, 1 << 31, 5, 5, 5]);
+  let expected =
+    u32x8::from([0, 0, u32::MAX, u32::MAX, 0, u32::MAX, u32::MAX, u32::MAX]);
+  let actual = a.cmp_ge(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u32x8_cmp_le() {
+  let a = u32x8::from([1, 2, 9, u32::MAX, 0, 1 << 31, 8, 5]);
+  let b = u32x8::from([5, 5, 5, 5, 1 << 31, 5, 5, 5]);
+  let expected =
+    u32x8::from([u32::MAX, u32::MAX, 0, 0, u32::MAX, 0, 0, u32::MAX]);
+  let actual = a.cmp_le(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u32x8_cmp_ne() {
+  let a = u32x8::from([1, 2, 9, u32::MAX, 0, 1 << 31, 8, 5]);
+  let b = u32x8::from([5, 5, 5, 5, 1 << 31, 5, 5, 5]);
+  let expected = u32x8::from([
+    u32::MAX,
+    u32::MAX,
+    u32::MAX,
+    u32::MAX,
+    u32::MAX,
+    u32::MAX,
+    u32::MAX,
+    0,
+  ]);
+  let actual = a.cmp_ne(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u32x8_unsigned_max_min() {
+  let a = u32x8::from([1, 2, u32::MAX, 0, 1 << 31, 7, 8, 9]);
+  let b = u32x8::from([5, 1, 5, 5, 5, 7, 1 << 31, 10]);
+  let expected_max =
+    u32x8::from([5, 2, u32::MAX, 5, 1 << 31, 7, 1 << 31, 10]);
+  let expected_min = u32x8::from([1, 1, 5, 0, 5, 7, 8, 9]);
+  assert_eq!(expected_max, a.max(b));
+  assert_eq!(expected_min, a.min(b));
+}
+
+#[test]
+fn impl_u32x8_shl_each() {
+  let a: [u32; 8] = [
+    0x1234_5678,
+    0xEDCB_A988,
+    0x8000_0000,
+    u32::MAX,
+    0xFFFF_FFDB,
+    0x10_0000,
+    0xFFFF_FFFE,
+    3,
+  ];
+  let counts: [u32; 8] = [0, 7, 19, 31, 32, 33, 200, u32::MAX];
+  let expected: [u32; 8] =
+    [0x1234_5678, 0xE5D4_C400, 0, 0x8000_0000, 0, 0, 0, 0];
+  let actual = u32x8::from(a).shl_each(u32x8::from(counts));
+  assert_eq!(u32x8::from(expected), actual);
+}
+
+#[test]
+fn impl_u32x8_shr_each() {
+  let a: [u32; 8] = [
+    0x1234_5678,
+    0xEDCB_A988,
+    0x8000_0000,
+    u32::MAX,
+    0xFFFF_FFDB,
+    0x10_0000,
+    0xFFFF_FFFE,
+    3,
+  ];
+  let counts: [u32; 8] = [0, 7, 19, 31, 32, 33, 200, u32::MAX];
+  let expected: [u32; 8] = [0x1234_5678, 0x1DB_9753, 4096, 1, 0, 0, 0, 0];
+  let actual = u32x8::from(a).shr_each(u32x8::from(counts));
+  assert_eq!(u32x8::from(expected), actual);
+}
+
+#[test]
+fn impl_div_for_u32x8() {
+  let a: [u32; 8] = [u32::MAX, 7, 6, 100, 99, 0, 1, 13];
+  let b: [u32; 8] = [1, 2, 3, 7, 9, 10, 5, 13];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_div(*y);
+  }
+  let actual = u32x8::from(a) / u32x8::from(b);
+  assert_eq!(u32x8::from(expected), actual);
+}
+
+#[test]
+fn impl_rem_for_u32x8() {
+  let a: [u32; 8] = [u32::MAX, 7, 6, 100, 99, 0, 1, 13];
+  let b: [u32; 8] = [1, 2, 3, 7, 9, 10, 5, 13];
+  let mut expected = [0; 8];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_rem(*y);
+  }
+  let actual = u32x8::from(a) % u32x8::from(b);
+  assert_eq!(u32x8::from(expected), actual);
+}
+
+#[test]
+fn impl_u32x8_divisor() {
+  let nums: [u32; 16] =
+    [0, 1, 0, 1, 6, 7, 8, 1000, u32::MAX, 13, 99, 100, 2, 3, 50, 51];
+  // numerators next to the extremes and at or near powers of two
+  let edges: [u32; 8] =
+    [u32::MAX - 1, u32::MAX / 2, u32::MAX / 2 + 1, 4, 16, 1024, 1023, 2];
+  let divisors = [7, 1, 3, u32::MAX, 2, 16, 1 << 31, u32::MAX - 1];
+  for d in divisors.iter().copied() {
+    let divisor = Divisor::<u32x8>::new(d);
+    for chunk in nums.chunks(8).chain(edges.chunks(8)) {
+      let mut a = [0; 8];
+      a.copy_from_slice(chunk);
+      let mut q = a;
+      let mut r = a;
+      for ((q, r), x) in q.iter_mut().zip(r.iter_mut()).zip(a.iter()) {
+        *q = x.wrapping_div(d);
+        *r = x.wrapping_rem(d);
+      }
+      assert_eq!(u32x8::from(a) / divisor, u32x8::from(q));
+      assert_eq!(u32x8::from(a) % divisor, u32x8::from(r));
+      assert_eq!(u32x8::from(a) / d, u32x8::from(q));
+      assert_eq!(u32x8::from(a) % d, u32x8::from(r));
+    }
+  }
+}
+
+#[test]
+fn impl_u32x8_clamp() {
+  let min = u32x8::from(3);
+  let max = u32x8::from(6);
+  let a = u32x8::from([0, 2, 3, 5, 6, 7, 9, u32::MAX]);
+  let expected = u32x8::from([3, 3, 3, 5, 6, 6, 6, 6]);
+  assert_eq!(expected, a.clamp(min, max));
+}
+
+#[test]
+fn impl_u32x8_reductions() {
+  let a = u32x8::from([1, 7, u32::MAX, 64, 2, 29, u32::MAX - 1, 200]);
+  assert_eq!(a.reduce_min(), 1);
+  assert_eq!(a.reduce_max(), u32::MAX);
+  assert_eq!(a.reduce_mul(), 10393600);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), u32::MAX);
+  assert_eq!(a.reduce_xor(), 144);
+  let a = u32x8::from([3, 5, 7, 9, 11, 13, 15, 17]);
+  assert_eq!(a.reduce_min(), 3);
+  assert_eq!(a.reduce_max(), 17);
+  assert_eq!(a.reduce_mul(), 34459425);
+  assert_eq!(a.reduce_and(), 1);
+  assert_eq!(a.reduce_or(), 31);
+  assert_eq!(a.reduce_xor(), 16);
+  let a = u32x8::from([6, 6, 6, 6, 6, 6, u32::MAX, 0]);
+  assert_eq!(a.reduce_min(), 0);
+  assert_eq!(a.reduce_max(), u32::MAX);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), u32::MAX);
+  assert_eq!(a.reduce_xor(), u32::MAX);
+}
+
+#[test]
+fn impl_u32x8_move_mask() {
+  let a = u32x8::from([u32::MAX, 1, 1, u32::MAX, 1, 1, u32::MAX, 1]);
+  assert_eq!(a.move_mask(), 0b01001001);
+  assert!(a.any());
+  assert!(!a.all());
+  assert!(!a.none());
+  assert!(u32x8::splat(u32::MAX).all());
+  assert!(u32x8::splat(1).none());
+}
+
+#[test]
+fn impl_u32x8_lane_queries() {
+  let a = u32x8::from([2, u32::MAX, 3, 29, 64, 3, u32::MAX - 1, 1]);
+  assert_eq!(a.argmin(), (1, 7));
+  assert_eq!(a.argmax(), (u32::MAX, 1));
+  let mask = a.cmp_eq(u32x8::splat(3));
+  assert_eq!(mask.first_set(), Some(2));
+  assert_eq!(mask.last_set(), Some(5));
+  assert_eq!(mask.count_set(), 2);
+  let a = u32x8::from([u32::MAX, 2, 5, 7, 200, 100, 1, u32::MAX - 1]);
+  assert_eq!(a.argmin(), (1, 6));
+  assert_eq!(a.argmax(), (u32::MAX, 0));
+  let mask = a.cmp_eq(u32x8::splat(3));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  let a = u32x8::from([u32::MAX - 1, 1, 100, 200, 7, 5, 2, u32::MAX]);
+  assert_eq!(a.argmin(), (1, 1));
+  assert_eq!(a.argmax(), (u32::MAX, 7));
+  let mask = a.cmp_eq(u32x8::splat(3));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  assert_eq!(u32x8::splat(3).argmin(), (3, 0));
+  assert_eq!(u32x8::splat(3).argmax(), (3, 0));
+  assert_eq!(u32x8::splat(3).cmp_eq(u32x8::splat(3)).last_set(), Some(7));
+}
+
+#[test]
+fn impl_u32x8_shuffles() {
+  let v = u32x8::from([1, 2, 3, 4, 5, 6, 7, 8]);
+  let w = u32x8::from([9, 10, 11, 12, 13, 14, 15, 16]);
+  assert_eq!(
+    shuffle!(u32x8, v, [1, 4, 7, 2, 5, 0, 3, 6]),
+    u32x8::from([2, 5, 8, 3, 6, 1, 4, 7])
+  );
+  assert_eq!(shuffle!(u32x8, v, [7, 7, 7, 7, 7, 7, 7, 7]), u32x8::splat(8));
+  assert_eq!(
+    shuffle2!(u32x8, v, w, [2, 7, 12, 1, 6, 11, 0, 5]),
+    u32x8::from([3, 8, 13, 2, 7, 12, 1, 6])
+  );
+  assert_eq!(v.reverse(), u32x8::from([8, 7, 6, 5, 4, 3, 2, 1]));
+  assert_eq!(
+    rotate_lanes_left!(u32x8, v, 0),
+    u32x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u32x8, v, 0),
+    u32x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    shift_lanes_left!(u32x8, v, 0),
+    u32x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    shift_lanes_right!(u32x8, v, 0),
+    u32x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    rotate_lanes_left!(u32x8, v, 1),
+    u32x8::from([2, 3, 4, 5, 6, 7, 8, 1])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u32x8, v, 1),
+    u32x8::from([8, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    shift_lanes_left!(u32x8, v, 1),
+    u32x8::from([2, 3, 4, 5, 6, 7, 8, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(u32x8, v, 1),
+    u32x8::from([0, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    rotate_lanes_left!(u32x8, v, 5),
+    u32x8::from([6, 7, 8, 1, 2, 3, 4, 5])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u32x8, v, 5),
+    u32x8::from([4, 5, 6, 7, 8, 1, 2, 3])
+  );
+  assert_eq!(
+    shift_lanes_left!(u32x8, v, 5),
+    u32x8::from([6, 7, 8, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(u32x8, v, 5),
+    u32x8::from([0, 0, 0, 0, 0, 1, 2, 3])
+  );
+  assert_eq!(
+    rotate_lanes_left!(u32x8, v, 7),
+    u32x8::from([8, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u32x8, v, 7),
+    u32x8::from([2, 3, 4, 5, 6, 7, 8, 1])
+  );
+  assert_eq!(
+    shift_lanes_left!(u32x8, v, 7),
+    u32x8::from([8, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(u32x8, v, 7),
+    u32x8::from([0, 0, 0, 0, 0, 0, 0, 1])
+  );
+  assert_eq!(
+    rotate_lanes_left!(u32x8, v, 8),
+    u32x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u32x8, v, 8),
+    u32x8::from([1, 2, 3, 4, 5, 6, 7, 8])
+  );
+  assert_eq!(
+    shift_lanes_left!(u32x8, v, 8),
+    u32x8::from([0, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(u32x8, v, 8),
+    u32x8::from([0, 0, 0, 0, 0, 0, 0, 0])
+  );
+}
+
+#[test]
+fn impl_u32x8_zip_unzip() {
+  let a = u32x8::from([1, 2, 3, 4, 5, 6, 7, 8]);
+  let b = u32x8::from([9, 10, 11, 12, 13, 14, 15, 16]);
+  assert_eq!(a.zip_lo(b), u32x8::from([1, 9, 2, 10, 3, 11, 4, 12]));
+  assert_eq!(a.zip_hi(b), u32x8::from([5, 13, 6, 14, 7, 15, 8, 16]));
+  assert_eq!(a.unzip_even(b), u32x8::from([1, 3, 5, 7, 9, 11, 13, 15]));
+  assert_eq!(a.unzip_odd(b), u32x8::from([2, 4, 6, 8, 10, 12, 14, 16]));
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+}
+
+#[test]
+fn impl_u32x8_transpose8() {
+  let mut m = [[u32::default(); 8]; 8];
+  let mut mt = [[u32::default(); 8]; 8];
+  for i in 0..8 {
+    for j in 0..8 {
+      m[i][j] = (i * 8 + j) as u32;
+      mt[j][i] = m[i][j];
+    }
+  }
+  let mut rows = [u32x8::default(); 8];
+  let mut expected = [u32x8::default(); 8];
+  for i in 0..8 {
+    rows[i] = u32x8::from(m[i]);
+    expected[i] = u32x8::from(mt[i]);
+  }
+  assert_eq!(u32x8::transpose8(rows), expected);
+  assert_eq!(u32x8::transpose8(expected), rows);
+}
+
+#[test]
+fn impl_u32x8_halves() {
+  let v = u32x8::from([1, 2, 3, 4, 5, 6, 7, 8]);
+  let lo = u32x4::from([1, 2, 3, 4]);
+  let hi = u32x4::from([5, 6, 7, 8]);
+  assert_eq!(v.low(), lo);
+  assert_eq!(v.high(), hi);
+  assert_eq!(v.split(), (lo, hi));
+  assert_eq!(u32x8::from_halves(lo, hi), v);
+  assert_eq!(u32x8::from_halves(hi, lo), u32x8::from([5, 6, 7, 8, 1, 2, 3, 4]));
+}
+
+#[test]
+fn impl_u32x8_widen() {
+  let a = u32x8::from([0, u32::MAX, 1, 2, 100, u32::MAX - 1, 2147483648, 0]);
+  assert_eq!(a.widen_low(), u64x4::from([0, 4294967295, 1, 2]));
+  assert_eq!(a.widen_high(), u64x4::from([100, 4294967294, 2147483648, 0]));
+}
+
+#[test]
+fn impl_u32x8_narrow() {
+  let a =
+    u32x8::from([0, u32::MAX, 1, 2, 100, u32::MAX - 1, 2147483648, 65535]);
+  assert_eq!(
+    a.narrow_wrapping(),
+    u16x8::from([0, u16::MAX, 1, 2, 100, u16::MAX - 1, 0, u16::MAX])
+  );
+  assert_eq!(
+    a.narrow_saturating(),
+    u16x8::from([0, u16::MAX, 1, 2, 100, u16::MAX, u16::MAX, u16::MAX])
+  );
+  let a = u32x8::from([65536, 32768, 0, u32::MAX, 1, 2, 100, u32::MAX - 1]);
+  assert_eq!(
+    a.narrow_wrapping(),
+    u16x8::from([0, 32768, 0, u16::MAX, 1, 2, 100, u16::MAX - 1])
+  );
+  assert_eq!(
+    a.narrow_saturating(),
+    u16x8::from([u16::MAX, 32768, 0, u16::MAX, 1, 2, 100, u16::MAX])
+  );
+}
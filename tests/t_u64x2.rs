@@ -24,6 +24,18 @@ fn impl_sub_for_u64x2() {
   assert_eq!(expected, actual);
 }
 
+#[test]
+fn impl_mul_for_u64x2() {
+  let a = u64x2::from([u64::MAX, (1 << 40) + 3]);
+  let b = u64x2::from([u64::MAX, (1 << 33) + 7]);
+  let expected =
+    u64x2::from([1, ((1_u64 << 40) + 3).wrapping_mul((1 << 33) + 7)]);
+  let actual = a * b;
+  assert_eq!(expected, actual);
+  assert_eq!(a * 2, u64x2::from([u64::MAX - 1, (1 << 41) + 6]));
+  assert_eq!(2 * a, a * 2);
+}
+
 #[test]
 fn impl_bitand_for_u64x2() {
   let a = u64x2::from([1, 1]);
@@ -97,3 +109,276 @@ fn impl_u64x2_cmp_gt() {
   let actual = a.cmp_gt(b);
   assert_eq!(expected, actual);
 }
+
+#[test]
+fn impl_u64x2_cmp_gt_high_bit() {
+  let a = u64x2::from([u64::MAX, 1]);
+  let b = u64x2::from([1, u64::MAX]);
+  let expected = u64x2::from([u64::MAX, 0]);
+  let actual = a.cmp_gt(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u64x2_cmp_lt() {
+  let a = u64x2::from([u64::MAX, 1]);
+  let b = u64x2::from([1, u64::MAX]);
+  let expected = u64x2::from([0, u64::MAX]);
+  let actual = a.cmp_lt(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u64x2_cmp_ge() {
+  let a = u64x2::from([1 << 63, 1]);
+  let b = u64x2::from([1 << 63, u64::MAX]);
+  let expected = u64x2::from([u64::MAX, 0]);
+  let actual = a.cmp_ge(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u64x2_cmp_le() {
+  let a = u64x2::from([u64::MAX, 1]);
+  let b = u64x2::from([1, 1]);
+  let expected = u64x2::from([0, u64::MAX]);
+  let actual = a.cmp_le(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u64x2_cmp_ne() {
+  let a = u64x2::from([1_u64, 4]);
+  let b = u64x2::from([3_u64, 4]);
+  let expected = u64x2::from([u64::MAX, 0]);
+  let actual = a.cmp_ne(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u64x2_shl_each() {
+  let table: [([u64; 2], [u64; 2], [u64; 2]); 4] = [
+    (
+      [0x1234_5678_9ABC_DEF0, 0xEDCB_A987_6543_2110],
+      [0, 13],
+      [0x1234_5678_9ABC_DEF0, 0x7530_ECA8_6422_0000],
+    ),
+    ([0x8000_0000_0000_0000, u64::MAX], [40, 63], [0, 0x8000_0000_0000_0000]),
+    ([0xFFFF_FFFF_FFFF_FFDB, 0x100_0000_0000], [64, 200], [0, 0]),
+    ([0xFFFF_FFFF_FFFF_FFFE, 3], [u32::MAX as u64, u64::MAX], [0, 0]),
+  ];
+  for &(a, counts, expected) in table.iter() {
+    let actual = u64x2::from(a).shl_each(u64x2::from(counts));
+    assert_eq!(u64x2::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_u64x2_shr_each() {
+  let table: [([u64; 2], [u64; 2], [u64; 2]); 4] = [
+    (
+      [0x1234_5678_9ABC_DEF0, 0xEDCB_A987_6543_2110],
+      [0, 13],
+      [0x1234_5678_9ABC_DEF0, 0x7_6E5D_4C3B_2A19],
+    ),
+    ([0x8000_0000_0000_0000, u64::MAX], [40, 63], [0x80_0000, 1]),
+    ([0xFFFF_FFFF_FFFF_FFDB, 0x100_0000_0000], [64, 200], [0, 0]),
+    ([0xFFFF_FFFF_FFFF_FFFE, 3], [u32::MAX as u64, u64::MAX], [0, 0]),
+  ];
+  for &(a, counts, expected) in table.iter() {
+    let actual = u64x2::from(a).shr_each(u64x2::from(counts));
+    assert_eq!(u64x2::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_div_for_u64x2() {
+  let a: [u64; 2] = [u64::MAX, 7];
+  let b: [u64; 2] = [1, 2];
+  let mut expected = [0; 2];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_div(*y);
+  }
+  let actual = u64x2::from(a) / u64x2::from(b);
+  assert_eq!(u64x2::from(expected), actual);
+}
+
+#[test]
+fn impl_rem_for_u64x2() {
+  let a: [u64; 2] = [u64::MAX, 7];
+  let b: [u64; 2] = [1, 2];
+  let mut expected = [0; 2];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_rem(*y);
+  }
+  let actual = u64x2::from(a) % u64x2::from(b);
+  assert_eq!(u64x2::from(expected), actual);
+}
+
+#[test]
+fn impl_u64x2_divisor() {
+  let nums: [u64; 16] =
+    [0, 1, 0, 1, 6, 7, 8, 1000, u64::MAX, 13, 99, 100, 2, 3, 50, 51];
+  // numerators next to the extremes and at or near powers of two
+  let edges: [u64; 8] =
+    [u64::MAX - 1, u64::MAX / 2, u64::MAX / 2 + 1, 4, 16, 1024, 1023, 2];
+  let divisors = [7, 1, 3, u64::MAX, 2, 16, 1 << 63, u64::MAX - 1];
+  for d in divisors.iter().copied() {
+    let divisor = Divisor::<u64x2>::new(d);
+    for chunk in nums.chunks(2).chain(edges.chunks(2)) {
+      let mut a = [0; 2];
+      a.copy_from_slice(chunk);
+      let mut q = a;
+      let mut r = a;
+      for ((q, r), x) in q.iter_mut().zip(r.iter_mut()).zip(a.iter()) {
+        *q = x.wrapping_div(d);
+        *r = x.wrapping_rem(d);
+      }
+      assert_eq!(u64x2::from(a) / divisor, u64x2::from(q));
+      assert_eq!(u64x2::from(a) % divisor, u64x2::from(r));
+      assert_eq!(u64x2::from(a) / d, u64x2::from(q));
+      assert_eq!(u64x2::from(a) % d, u64x2::from(r));
+    }
+  }
+}
+
+#[test]
+fn impl_u64x2_clamp() {
+  let min = u64x2::from(3);
+  let max = u64x2::from(6);
+  let a = u64x2::from([0, 2]);
+  let expected = u64x2::from([3, 3]);
+  assert_eq!(expected, a.clamp(min, max));
+  let a = u64x2::from([3, 5]);
+  let expected = u64x2::from([3, 5]);
+  assert_eq!(expected, a.clamp(min, max));
+  let a = u64x2::from([6, 7]);
+  let expected = u64x2::from([6, 6]);
+  assert_eq!(expected, a.clamp(min, max));
+  let a = u64x2::from([9, u64::MAX]);
+  let expected = u64x2::from([6, 6]);
+  assert_eq!(expected, a.clamp(min, max));
+}
+
+#[test]
+fn impl_u64x2_reductions() {
+  let a = u64x2::from([u64::MAX - 1, 200]);
+  assert_eq!(a.reduce_min(), 200);
+  assert_eq!(a.reduce_max(), u64::MAX - 1);
+  assert_eq!(a.reduce_mul(), u64::MAX - 399);
+  assert_eq!(a.reduce_and(), 200);
+  assert_eq!(a.reduce_or(), u64::MAX - 1);
+  assert_eq!(a.reduce_xor(), u64::MAX - 201);
+  let a = u64x2::from([3, 5]);
+  assert_eq!(a.reduce_min(), 3);
+  assert_eq!(a.reduce_max(), 5);
+  assert_eq!(a.reduce_mul(), 15);
+  assert_eq!(a.reduce_and(), 1);
+  assert_eq!(a.reduce_or(), 7);
+  assert_eq!(a.reduce_xor(), 6);
+  let a = u64x2::from([u64::MAX, 0]);
+  assert_eq!(a.reduce_min(), 0);
+  assert_eq!(a.reduce_max(), u64::MAX);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), u64::MAX);
+  assert_eq!(a.reduce_xor(), u64::MAX);
+}
+
+#[test]
+fn impl_u64x2_move_mask() {
+  let a = u64x2::from([u64::MAX, 1]);
+  assert_eq!(a.move_mask(), 0b01);
+  assert!(a.any());
+  assert!(!a.all());
+  assert!(!a.none());
+  assert!(u64x2::splat(u64::MAX).all());
+  assert!(u64x2::splat(1).none());
+}
+
+#[test]
+fn impl_u64x2_lane_queries() {
+  let a = u64x2::from([u64::MAX, 3]);
+  assert_eq!(a.argmin(), (3, 1));
+  assert_eq!(a.argmax(), (u64::MAX, 0));
+  let mask = a.cmp_eq(u64x2::splat(3));
+  assert_eq!(mask.first_set(), Some(1));
+  assert_eq!(mask.last_set(), Some(1));
+  assert_eq!(mask.count_set(), 1);
+  let a = u64x2::from([7, 5]);
+  assert_eq!(a.argmin(), (5, 1));
+  assert_eq!(a.argmax(), (7, 0));
+  let mask = a.cmp_eq(u64x2::splat(3));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  let a = u64x2::from([1, 100]);
+  assert_eq!(a.argmin(), (1, 0));
+  assert_eq!(a.argmax(), (100, 1));
+  let mask = a.cmp_eq(u64x2::splat(3));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  assert_eq!(u64x2::splat(3).argmin(), (3, 0));
+  assert_eq!(u64x2::splat(3).argmax(), (3, 0));
+  assert_eq!(u64x2::splat(3).cmp_eq(u64x2::splat(3)).last_set(), Some(1));
+}
+
+#[test]
+fn impl_u64x2_shuffles() {
+  let v = u64x2::from([1, 2]);
+  let w = u64x2::from([3, 4]);
+  assert_eq!(shuffle!(u64x2, v, [1, 0]), u64x2::from([2, 1]));
+  assert_eq!(shuffle!(u64x2, v, [1, 1]), u64x2::splat(2));
+  assert_eq!(shuffle2!(u64x2, v, w, [2, 3]), u64x2::from([3, 4]));
+  assert_eq!(v.reverse(), u64x2::from([2, 1]));
+  assert_eq!(rotate_lanes_left!(u64x2, v, 0), u64x2::from([1, 2]));
+  assert_eq!(rotate_lanes_right!(u64x2, v, 0), u64x2::from([1, 2]));
+  assert_eq!(shift_lanes_left!(u64x2, v, 0), u64x2::from([1, 2]));
+  assert_eq!(shift_lanes_right!(u64x2, v, 0), u64x2::from([1, 2]));
+  assert_eq!(rotate_lanes_left!(u64x2, v, 1), u64x2::from([2, 1]));
+  assert_eq!(rotate_lanes_right!(u64x2, v, 1), u64x2::from([2, 1]));
+  assert_eq!(shift_lanes_left!(u64x2, v, 1), u64x2::from([2, 0]));
+  assert_eq!(shift_lanes_right!(u64x2, v, 1), u64x2::from([0, 1]));
+  assert_eq!(rotate_lanes_left!(u64x2, v, 2), u64x2::from([1, 2]));
+  assert_eq!(rotate_lanes_right!(u64x2, v, 2), u64x2::from([1, 2]));
+  assert_eq!(shift_lanes_left!(u64x2, v, 2), u64x2::from([0, 0]));
+  assert_eq!(shift_lanes_right!(u64x2, v, 2), u64x2::from([0, 0]));
+}
+
+#[test]
+fn impl_u64x2_zip_unzip() {
+  let a = u64x2::from([1, 2]);
+  let b = u64x2::from([3, 4]);
+  assert_eq!(a.zip_lo(b), u64x2::from([1, 3]));
+  assert_eq!(a.zip_hi(b), u64x2::from([2, 4]));
+  assert_eq!(a.unzip_even(b), u64x2::from([1, 3]));
+  assert_eq!(a.unzip_odd(b), u64x2::from([2, 4]));
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+}
+
+#[test]
+fn impl_u64x2_narrow() {
+  let a = u64x2::from([0, u64::MAX]);
+  let b = u64x2::from([1, 2]);
+  assert_eq!(a.narrow_wrapping(b), u32x4::from([0, u32::MAX, 1, 2]));
+  assert_eq!(a.narrow_saturating(b), u32x4::from([0, u32::MAX, 1, 2]));
+  let a = u64x2::from([100, u64::MAX - 1]);
+  let b = u64x2::from([1 << 63, 4294967295]);
+  assert_eq!(
+    a.narrow_wrapping(b),
+    u32x4::from([100, u32::MAX - 1, 0, u32::MAX])
+  );
+  assert_eq!(
+    a.narrow_saturating(b),
+    u32x4::from([100, u32::MAX, u32::MAX, u32::MAX])
+  );
+  let a = u64x2::from([4294967296, 2147483648]);
+  let b = u64x2::from([0, u64::MAX]);
+  assert_eq!(a.narrow_wrapping(b), u32x4::from([0, 2147483648, 0, u32::MAX]));
+  assert_eq!(
+    a.narrow_saturating(b),
+    u32x4::from([u32::MAX, 2147483648, 0, u32::MAX])
+  );
+}
@@ -24,6 +24,18 @@ fn impl_sub_for_u64x4() {
   assert_eq!(expected, actual);
 }
 
+#[test]
+fn impl_mul_for_u64x4() {
+  let a = u64x4::from([u64::MAX, (1 << 40) + 3, 12, 1 << 63]);
+  let b = u64x4::from([u64::MAX, (1 << 33) + 7, 5, 2]);
+  let expected =
+    u64x4::from([1, ((1_u64 << 40) + 3).wrapping_mul((1 << 33) + 7), 60, 0]);
+  let actual = a * b;
+  assert_eq!(expected, actual);
+  assert_eq!(a * 3, u64x4::from([u64::MAX - 2, (3 << 40) + 9, 36, 1 << 63]));
+  assert_eq!(3 * a, a * 3);
+}
+
 #[test]
 fn impl_bitand_for_u64x4() {
   let a = u64x4::from([1, 1, 0, 0]);
@@ -90,3 +102,310 @@ fn impl_u64x4_cmp_eq() {
   let actual = a.cmp_eq(b);
   assert_eq!(expected, actual);
 }
+
+#[test]
+fn impl_u64x4_cmp_gt() {
+  let a = u64x4::from([1, u64::MAX, 1 << 63, 5]);
+  let b = u64x4::from([3, 1, 1 << 63, 1]);
+  let expected = u64x4::from([0, u64::MAX, 0, u64::MAX]);
+  let actual = a.cmp_gt(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u64x4_cmp_lt() {
+  let a = u64x4::from([1, u64::MAX, 1 << 63, 5]);
+  let b = u64x4::from([3, 1, 1 << 63, 1]);
+  let expected = u64x4::from([u64::MAX, 0, 0, 0]);
+  let actual = a.cmp_lt(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u64x4_cmp_ge() {
+  let a = u64x4::from([1, u64::MAX, 1 << 63, 5]);
+  let b = u64x4::from([3, 1, 1 << 63, 1]);
+  let expected = u64x4::from([0, u64::MAX, u64::MAX, u64::MAX]);
+  let actual = a.cmp_ge(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u64x4_cmp_le() {
+  let a = u64x4::from([1, u64::MAX, 1 << 63, 5]);
+  let b = u64x4::from([3, 1, 1 << 63, 1]);
+  let expected = u64x4::from([u64::MAX, 0, u64::MAX, 0]);
+  let actual = a.cmp_le(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u64x4_cmp_ne() {
+  let a = u64x4::from([1, u64::MAX, 1 << 63, 5]);
+  let b = u64x4::from([3, 1, 1 << 63, 1]);
+  let expected = u64x4::from([u64::MAX, u64::MAX, 0, u64::MAX]);
+  let actual = a.cmp_ne(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u64x4_shl_each() {
+  let table: [([u64; 4], [u64; 4], [u64; 4]); 2] = [
+    (
+      [
+        0x1234_5678_9ABC_DEF0,
+        0xEDCB_A987_6543_2110,
+        0x8000_0000_0000_0000,
+        u64::MAX,
+      ],
+      [0, 13, 40, 63],
+      [0x1234_5678_9ABC_DEF0, 0x7530_ECA8_6422_0000, 0, 0x8000_0000_0000_0000],
+    ),
+    (
+      [0xFFFF_FFFF_FFFF_FFDB, 0x100_0000_0000, 0xFFFF_FFFF_FFFF_FFFE, 3],
+      [64, 200, u32::MAX as u64, u64::MAX],
+      [0, 0, 0, 0],
+    ),
+  ];
+  for &(a, counts, expected) in table.iter() {
+    let actual = u64x4::from(a).shl_each(u64x4::from(counts));
+    assert_eq!(u64x4::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_u64x4_shr_each() {
+  let table: [([u64; 4], [u64; 4], [u64; 4]); 2] = [
+    (
+      [
+        0x1234_5678_9ABC_DEF0,
+        0xEDCB_A987_6543_2110,
+        0x8000_0000_0000_0000,
+        u64::MAX,
+      ],
+      [0, 13, 40, 63],
+      [0x1234_5678_9ABC_DEF0, 0x7_6E5D_4C3B_2A19, 0x80_0000, 1],
+    ),
+    (
+      [0xFFFF_FFFF_FFFF_FFDB, 0x100_0000_0000, 0xFFFF_FFFF_FFFF_FFFE, 3],
+      [64, 200, u32::MAX as u64, u64::MAX],
+      [0, 0, 0, 0],
+    ),
+  ];
+  for &(a, counts, expected) in table.iter() {
+    let actual = u64x4::from(a).shr_each(u64x4::from(counts));
+    assert_eq!(u64x4::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_div_for_u64x4() {
+  let a: [u64; 4] = [u64::MAX, 7, 6, 100];
+  let b: [u64; 4] = [1, 2, 3, 7];
+  let mut expected = [0; 4];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_div(*y);
+  }
+  let actual = u64x4::from(a) / u64x4::from(b);
+  assert_eq!(u64x4::from(expected), actual);
+}
+
+#[test]
+fn impl_rem_for_u64x4() {
+  let a: [u64; 4] = [u64::MAX, 7, 6, 100];
+  let b: [u64; 4] = [1, 2, 3, 7];
+  let mut expected = [0; 4];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.wrapping_rem(*y);
+  }
+  let actual = u64x4::from(a) % u64x4::from(b);
+  assert_eq!(u64x4::from(expected), actual);
+}
+
+#[test]
+fn impl_u64x4_divisor() {
+  let nums: [u64; 16] =
+    [0, 1, 0, 1, 6, 7, 8, 1000, u64::MAX, 13, 99, 100, 2, 3, 50, 51];
+  // numerators next to the extremes and at or near powers of two
+  let edges: [u64; 8] =
+    [u64::MAX - 1, u64::MAX / 2, u64::MAX / 2 + 1, 4, 16, 1024, 1023, 2];
+  let divisors = [7, 1, 3, u64::MAX, 2, 16, 1 << 63, u64::MAX - 1];
+  for d in divisors.iter().copied() {
+    let divisor = Divisor::<u64x4>::new(d);
+    for chunk in nums.chunks(4).chain(edges.chunks(4)) {
+      let mut a = [0; 4];
+      a.copy_from_slice(chunk);
+      let mut q = a;
+      let mut r = a;
+      for ((q, r), x) in q.iter_mut().zip(r.iter_mut()).zip(a.iter()) {
+        *q = x.wrapping_div(d);
+        *r = x.wrapping_rem(d);
+      }
+      assert_eq!(u64x4::from(a) / divisor, u64x4::from(q));
+      assert_eq!(u64x4::from(a) % divisor, u64x4::from(r));
+      assert_eq!(u64x4::from(a) / d, u64x4::from(q));
+      assert_eq!(u64x4::from(a) % d, u64x4::from(r));
+    }
+  }
+}
+
+#[test]
+fn impl_u64x4_clamp() {
+  let min = u64x4::from(3);
+  let max = u64x4::from(6);
+  let a = u64x4::from([0, 2, 3, 5]);
+  let expected = u64x4::from([3, 3, 3, 5]);
+  assert_eq!(expected, a.clamp(min, max));
+  let a = u64x4::from([6, 7, 9, u64::MAX]);
+  let expected = u64x4::from([6, 6, 6, 6]);
+  assert_eq!(expected, a.clamp(min, max));
+}
+
+#[test]
+fn impl_u64x4_reductions() {
+  let a = u64x4::from([1, 7, u64::MAX, 64]);
+  assert_eq!(a.reduce_min(), 1);
+  assert_eq!(a.reduce_max(), u64::MAX);
+  assert_eq!(a.reduce_mul(), u64::MAX - 447);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), u64::MAX);
+  assert_eq!(a.reduce_xor(), u64::MAX - 70);
+  let a = u64x4::from([3, 5, 7, 9]);
+  assert_eq!(a.reduce_min(), 3);
+  assert_eq!(a.reduce_max(), 9);
+  assert_eq!(a.reduce_mul(), 945);
+  assert_eq!(a.reduce_and(), 1);
+  assert_eq!(a.reduce_or(), 15);
+  assert_eq!(a.reduce_xor(), 8);
+  let a = u64x4::from([6, 6, u64::MAX, 0]);
+  assert_eq!(a.reduce_min(), 0);
+  assert_eq!(a.reduce_max(), u64::MAX);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), u64::MAX);
+  assert_eq!(a.reduce_xor(), u64::MAX);
+}
+
+#[test]
+fn impl_u64x4_move_mask() {
+  let a = u64x4::from([u64::MAX, 1, 1, u64::MAX]);
+  assert_eq!(a.move_mask(), 0b1001);
+  assert!(a.any());
+  assert!(!a.all());
+  assert!(!a.none());
+  assert!(u64x4::splat(u64::MAX).all());
+  assert!(u64x4::splat(1).none());
+}
+
+#[test]
+fn impl_u64x4_lane_queries() {
+  let a = u64x4::from([3, 29, 64, 3]);
+  assert_eq!(a.argmin(), (3, 0));
+  assert_eq!(a.argmax(), (64, 2));
+  let mask = a.cmp_eq(u64x4::splat(3));
+  assert_eq!(mask.first_set(), Some(0));
+  assert_eq!(mask.last_set(), Some(3));
+  assert_eq!(mask.count_set(), 2);
+  let a = u64x4::from([200, 7, 5, 2]);
+  assert_eq!(a.argmin(), (2, 3));
+  assert_eq!(a.argmax(), (200, 0));
+  let mask = a.cmp_eq(u64x4::splat(3));
+  assert_eq!(mask.first_set(), None);
+  assert_eq!(mask.last_set(), None);
+  assert_eq!(mask.count_set(), 0);
+  assert_eq!(u64x4::splat(3).argmin(), (3, 0));
+  assert_eq!(u64x4::splat(3).argmax(), (3, 0));
+  assert_eq!(u64x4::splat(3).cmp_eq(u64x4::splat(3)).last_set(), Some(3));
+}
+
+#[test]
+fn impl_u64x4_shuffles() {
+  let v = u64x4::from([1, 2, 3, 4]);
+  let w = u64x4::from([5, 6, 7, 8]);
+  assert_eq!(shuffle!(u64x4, v, [1, 0, 3, 2]), u64x4::from([2, 1, 4, 3]));
+  assert_eq!(shuffle!(u64x4, v, [3, 3, 3, 3]), u64x4::splat(4));
+  assert_eq!(shuffle2!(u64x4, v, w, [2, 7, 4, 1]), u64x4::from([3, 8, 5, 2]));
+  assert_eq!(v.reverse(), u64x4::from([4, 3, 2, 1]));
+  assert_eq!(rotate_lanes_left!(u64x4, v, 0), u64x4::from([1, 2, 3, 4]));
+  assert_eq!(rotate_lanes_right!(u64x4, v, 0), u64x4::from([1, 2, 3, 4]));
+  assert_eq!(shift_lanes_left!(u64x4, v, 0), u64x4::from([1, 2, 3, 4]));
+  assert_eq!(shift_lanes_right!(u64x4, v, 0), u64x4::from([1, 2, 3, 4]));
+  assert_eq!(rotate_lanes_left!(u64x4, v, 1), u64x4::from([2, 3, 4, 1]));
+  assert_eq!(rotate_lanes_right!(u64x4, v, 1), u64x4::from([4, 1, 2, 3]));
+  assert_eq!(shift_lanes_left!(u64x4, v, 1), u64x4::from([2, 3, 4, 0]));
+  assert_eq!(shift_lanes_right!(u64x4, v, 1), u64x4::from([0, 1, 2, 3]));
+  assert_eq!(rotate_lanes_left!(u64x4, v, 3), u64x4::from([4, 1, 2, 3]));
+  assert_eq!(rotate_lanes_right!(u64x4, v, 3), u64x4::from([2, 3, 4, 1]));
+  assert_eq!(shift_lanes_left!(u64x4, v, 3), u64x4::from([4, 0, 0, 0]));
+  assert_eq!(shift_lanes_right!(u64x4, v, 3), u64x4::from([0, 0, 0, 1]));
+  assert_eq!(rotate_lanes_left!(u64x4, v, 4), u64x4::from([1, 2, 3, 4]));
+  assert_eq!(rotate_lanes_right!(u64x4, v, 4), u64x4::from([1, 2, 3, 4]));
+  assert_eq!(shift_lanes_left!(u64x4, v, 4), u64x4::from([0, 0, 0, 0]));
+  assert_eq!(shift_lanes_right!(u64x4, v, 4), u64x4::from([0, 0, 0, 0]));
+}
+
+#[test]
+fn impl_u64x4_zip_unzip() {
+  let a = u64x4::from([1, 2, 3, 4]);
+  let b = u64x4::from([5, 6, 7, 8]);
+  assert_eq!(a.zip_lo(b), u64x4::from([1, 5, 2, 6]));
+  assert_eq!(a.zip_hi(b), u64x4::from([3, 7, 4, 8]));
+  assert_eq!(a.unzip_even(b), u64x4::from([1, 3, 5, 7]));
+  assert_eq!(a.unzip_odd(b), u64x4::from([2, 4, 6, 8]));
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+}
+
+#[test]
+fn impl_u64x4_transpose4() {
+  let mut m = [[u64::default(); 4]; 4];
+  let mut mt = [[u64::default(); 4]; 4];
+  for i in 0..4 {
+    for j in 0..4 {
+      m[i][j] = (i * 4 + j) as u64;
+      mt[j][i] = m[i][j];
+    }
+  }
+  let mut rows = [u64x4::default(); 4];
+  let mut expected = [u64x4::default(); 4];
+  for i in 0..4 {
+    rows[i] = u64x4::from(m[i]);
+    expected[i] = u64x4::from(mt[i]);
+  }
+  assert_eq!(u64x4::transpose4(rows), expected);
+  assert_eq!(u64x4::transpose4(expected), rows);
+}
+
+#[test]
+fn impl_u64x4_halves() {
+  let v = u64x4::from([1, 2, 3, 4]);
+  let lo = u64x2::from([1, 2]);
+  let hi = u64x2::from([3, 4]);
+  assert_eq!(v.low(), lo);
+  assert_eq!(v.high(), hi);
+  assert_eq!(v.split(), (lo, hi));
+  assert_eq!(u64x4::from_halves(lo, hi), v);
+  assert_eq!(u64x4::from_halves(hi, lo), u64x4::from([3, 4, 1, 2]));
+}
+
+#[test]
+fn impl_u64x4_narrow() {
+  let a = u64x4::from([0, u64::MAX, 1, 2]);
+  assert_eq!(a.narrow_wrapping(), u32x4::from([0, u32::MAX, 1, 2]));
+  assert_eq!(a.narrow_saturating(), u32x4::from([0, u32::MAX, 1, 2]));
+  let a = u64x4::from([100, u64::MAX - 1, 1 << 63, 4294967295]);
+  assert_eq!(
+    a.narrow_wrapping(),
+    u32x4::from([100, u32::MAX - 1, 0, u32::MAX])
+  );
+  assert_eq!(
+    a.narrow_saturating(),
+    u32x4::from([100, u32::MAX, u32::MAX, u32::MAX])
+  );
+  let a = u64x4::from([4294967296, 2147483648, 0, u64::MAX]);
+  assert_eq!(a.narrow_wrapping(), u32x4::from([0, 2147483648, 0, u32::MAX]));
+  assert_eq!(
+    a.narrow_saturating(),
+    u32x4::from([u32::MAX, 2147483648, 0, u32::MAX])
+  );
+}
@@ -46,6 +46,25 @@ fn impl_sub_for_u8x16() {
   assert_eq!(expected, actual);
 }
 
+#[test]
+fn impl_mul_for_u8x16() {
+  let a =
+    u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 250, 255]);
+  let b = u8x16::from([
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 2, 255,
+  ]);
+  let a_arr: [u8; 16] = a.into();
+  let b_arr: [u8; 16] = b.into();
+  let mut expected = [0_u8; 16];
+  for i in 0..16 {
+    expected[i] = a_arr[i].wrapping_mul(b_arr[i]);
+  }
+  let actual = a * b;
+  assert_eq!(u8x16::from(expected), actual);
+  assert_eq!(a * 3, a * u8x16::splat(3));
+  assert_eq!(3 * a, a * u8x16::splat(3));
+}
+
 #[test]
 fn impl_bitand_for_u8x16() {
   let a = u8x16::from([0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1]);
@@ -73,6 +92,36 @@ fn impl_bitxor_for_u8x16() {
   assert_eq!(expected, actual);
 }
 
+#[test]
+fn impl_shl_for_u8x16() {
+  let a =
+    u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 128, 255]);
+  let a_arr: [u8; 16] = a.into();
+  for shift in 0..8 {
+    let mut expected = [0_u8; 16];
+    for i in 0..16 {
+      expected[i] = a_arr[i] << shift;
+    }
+    let actual = a << shift;
+    assert_eq!(u8x16::from(expected), actual);
+  }
+}
+
+#[test]
+fn impl_shr_for_u8x16() {
+  let a =
+    u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 128, 255]);
+  let a_arr: [u8; 16] = a.into();
+  for shift in 0..8 {
+    let mut expected = [0_u8; 16];
+    for i in 0..16 {
+      expected[i] = a_arr[i] >> shift;
+    }
+    let actual = a >> shift;
+    assert_eq!(u8x16::from(expected), actual);
+  }
+}
+
 #[test]
 fn impl_u8x16_cmp_eq() {
   let a = u8x16::from([1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4]);
@@ -140,3 +189,465 @@ fn impl_u8x16_min() {
   let actual = a.min(b);
   assert_eq!(expected, actual);
 }
+
+#[test]
+fn impl_u8x16_cmp_gt() {
+  let a = u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 250, 0]);
+  let b = u8x16::from([2, 2, 2, 2, 2, 2, 2, 2, 200, 200, 200, 200, 200, 200, 200, 200]);
+  let expected = u8x16::from([
+    0,
+    0,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    u8::MAX,
+    0,
+  ]);
+  let actual = a.cmp_gt(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u8x16_cmp_lt() {
+  let a = u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 250, 0]);
+  let b = u8x16::from([2, 2, 2, 2, 2, 2, 2, 2, 200, 200, 200, 200, 200, 200, 200, 200]);
+  let expected = u8x16::from([
+    u8::MAX,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    0,
+    u8::MAX,
+  ]);
+  let actual = a.cmp_lt(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u8x16_cmp_ge() {
+  let a = u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 250, 0]);
+  let b = u8x16::from([2, 2, 2, 2, 2, 2, 2, 2, 200, 200, 200, 200, 200, 200, 200, 200]);
+  let expected = u8x16::from([
+    0,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    u8::MAX,
+    0,
+  ]);
+  let actual = a.cmp_ge(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u8x16_cmp_le() {
+  let a = u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 250, 0]);
+  let b = u8x16::from([2, 2, 2, 2, 2, 2, 2, 2, 200, 200, 200, 200, 200, 200, 200, 200]);
+  let expected = u8x16::from([
+    u8::MAX,
+    u8::MAX,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    0,
+    u8::MAX,
+  ]);
+  let actual = a.cmp_le(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u8x16_cmp_ne() {
+  let a = u8x16::from([1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4]);
+  let b = u8x16::from([2_u8; 16]);
+  let expected = u8x16::from([
+    u8::MAX,
+    0,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    0,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    0,
+    u8::MAX,
+    u8::MAX,
+    u8::MAX,
+    0,
+    u8::MAX,
+    u8::MAX,
+  ]);
+  let actual = a.cmp_ne(b);
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn impl_u8x16_shl_each() {
+  let a: [u8; 16] =
+    [255, 0, 1, 165, 53, 254, 77, 3, 128, 219, 127, 254, 90, 255, 1, 128];
+  let counts: [u8; 16] =
+    [0, 1, 3, 4, 6, 7, 8, 9, 200, u8::MAX, 2, 5, 7, 8, 100, u8::MAX];
+  let expected: [u8; 16] =
+    [255, 0, 8, 80, 64, 0, 0, 0, 0, 0, 252, 192, 0, 0, 0, 0];
+  let actual = u8x16::from(a).shl_each(u8x16::from(counts));
+  assert_eq!(u8x16::from(expected), actual);
+}
+
+#[test]
+fn impl_u8x16_shr_each() {
+  let a: [u8; 16] =
+    [255, 0, 1, 165, 53, 254, 77, 3, 128, 219, 127, 254, 90, 255, 1, 128];
+  let counts: [u8; 16] =
+    [0, 1, 3, 4, 6, 7, 8, 9, 200, u8::MAX, 2, 5, 7, 8, 100, u8::MAX];
+  let expected: [u8; 16] = [255, 0, 0, 10, 0, 1, 0, 0, 0, 0, 31, 7, 0, 0, 0, 0];
+  let actual = u8x16::from(a).shr_each(u8x16::from(counts));
+  assert_eq!(u8x16::from(expected), actual);
+}
+
+#[test]
+fn impl_u8x16_saturating_add() {
+  let a: [u8; 16] =
+    [255, 0, 200, 100, 1, 7, 0, 50, 255, 0, 200, 100, 1, 7, 0, 50];
+  let b: [u8; 16] =
+    [1, 1, 100, 200, 255, 9, 0, 60, 1, 1, 100, 200, 255, 9, 0, 60];
+  let mut expected = [0; 16];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.saturating_add(*y);
+  }
+  let actual = u8x16::from(a).saturating_add(u8x16::from(b));
+  assert_eq!(u8x16::from(expected), actual);
+}
+
+#[test]
+fn impl_u8x16_saturating_sub() {
+  let a: [u8; 16] =
+    [255, 0, 200, 100, 1, 7, 0, 50, 255, 0, 200, 100, 1, 7, 0, 50];
+  let b: [u8; 16] =
+    [1, 1, 100, 200, 255, 9, 0, 60, 1, 1, 100, 200, 255, 9, 0, 60];
+  let mut expected = [0; 16];
+  for ((e, x), y) in expected.iter_mut().zip(a.iter()).zip(b.iter()) {
+    *e = x.saturating_sub(*y);
+  }
+  let actual = u8x16::from(a).saturating_sub(u8x16::from(b));
+  assert_eq!(u8x16::from(expected), actual);
+}
+
+#[test]
+fn impl_u8x16_clamp() {
+  let min = u8x16::from(3);
+  let max = u8x16::from(6);
+  let a = u8x16::from([0, 2, 3, 5, 6, 7, 9, 255, 255, 9, 7, 6, 5, 3, 2, 0]);
+  let expected = u8x16::from([3, 3, 3, 5, 6, 6, 6, 6, 6, 6, 6, 6, 5, 3, 3, 3]);
+  assert_eq!(expected, a.clamp(min, max));
+}
+
+#[test]
+fn impl_u8x16_reductions() {
+  let a =
+    u8x16::from([1, 7, 255, 64, 2, 29, 254, 200, 3, 100, 5, 1, 7, 255, 64, 2]);
+  assert_eq!(a.reduce_min(), 1);
+  assert_eq!(a.reduce_max(), 255);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), 255);
+  assert_eq!(a.reduce_xor(), 73);
+  let a =
+    u8x16::from([3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33]);
+  assert_eq!(a.reduce_min(), 3);
+  assert_eq!(a.reduce_max(), 33);
+  assert_eq!(a.reduce_mul(), 65);
+  assert_eq!(a.reduce_and(), 1);
+  assert_eq!(a.reduce_or(), 63);
+  assert_eq!(a.reduce_xor(), 32);
+  let a = u8x16::from([6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 255, 0]);
+  assert_eq!(a.reduce_min(), 0);
+  assert_eq!(a.reduce_max(), 255);
+  assert_eq!(a.reduce_mul(), 0);
+  assert_eq!(a.reduce_and(), 0);
+  assert_eq!(a.reduce_or(), 255);
+  assert_eq!(a.reduce_xor(), 255);
+}
+
+#[test]
+fn impl_u8x16_move_mask() {
+  let a = u8x16::from([
+    u8::MAX,
+    1,
+    1,
+    u8::MAX,
+    1,
+    1,
+    u8::MAX,
+    1,
+    1,
+    u8::MAX,
+    1,
+    1,
+    u8::MAX,
+    1,
+    1,
+    u8::MAX,
+  ]);
+  assert_eq!(a.move_mask(), 0b1001001001001001);
+  assert!(a.any());
+  assert!(!a.all());
+  assert!(!a.none());
+  assert!(u8x16::splat(u8::MAX).all());
+  assert!(u8x16::splat(1).none());
+}
+
+#[test]
+fn impl_u8x16_lane_queries() {
+  let a =
+    u8x16::from([3, 64, 29, 3, 255, 2, 5, 7, 200, 100, 1, 254, 3, 64, 29, 3]);
+  assert_eq!(a.argmin(), (1, 10));
+  assert_eq!(a.argmax(), (255, 4));
+  let mask = a.cmp_eq(u8x16::splat(3));
+  assert_eq!(mask.first_set(), Some(0));
+  assert_eq!(mask.last_set(), Some(15));
+  assert_eq!(mask.count_set(), 4);
+  let a =
+    u8x16::from([100, 200, 7, 5, 2, 255, 3, 29, 64, 3, 254, 1, 100, 200, 7, 5]);
+  assert_eq!(a.argmin(), (1, 11));
+  assert_eq!(a.argmax(), (255, 5));
+  let mask = a.cmp_eq(u8x16::splat(3));
+  assert_eq!(mask.first_set(), Some(6));
+  assert_eq!(mask.last_set(), Some(9));
+  assert_eq!(mask.count_set(), 2);
+  let a =
+    u8x16::from([64, 3, 254, 1, 100, 200, 7, 5, 2, 255, 3, 29, 64, 3, 254, 1]);
+  assert_eq!(a.argmin(), (1, 3));
+  assert_eq!(a.argmax(), (255, 9));
+  let mask = a.cmp_eq(u8x16::splat(3));
+  assert_eq!(mask.first_set(), Some(1));
+  assert_eq!(mask.last_set(), Some(13));
+  assert_eq!(mask.count_set(), 3);
+  assert_eq!(u8x16::splat(3).argmin(), (3, 0));
+  assert_eq!(u8x16::splat(3).argmax(), (3, 0));
+  assert_eq!(u8x16::splat(3).cmp_eq(u8x16::splat(3)).last_set(), Some(15));
+}
+
+#[test]
+fn impl_u8x16_swizzle() {
+  let table = u8x16::from(*b"0123456789abcdef");
+  let idx =
+    u8x16::from([15, 0, 1, 14, 16, 17, 127, 128, 255, 2, 3, 3, 7, 240, 9, 10]);
+  let expected = u8x16::from([
+    b'f', b'0', b'1', b'e', 0, 0, 0, 0, 0, b'2', b'3', b'3', b'7', 0, b'9',
+    b'a',
+  ]);
+  assert_eq!(table.swizzle(idx), expected);
+  // in range indices are the same either way
+  let idx = u8x16::from([15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+  let reversed = u8x16::from(*b"fedcba9876543210");
+  assert_eq!(table.swizzle(idx), reversed);
+  assert_eq!(table.swizzle_relaxed(idx), reversed);
+}
+
+#[test]
+fn impl_u8x16_shuffles() {
+  let v = u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+  let w = u8x16::from([
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+  ]);
+  assert_eq!(
+    shuffle!(u8x16, v, [1, 4, 7, 10, 13, 0, 3, 6, 9, 12, 15, 2, 5, 8, 11, 14]),
+    u8x16::from([2, 5, 8, 11, 14, 1, 4, 7, 10, 13, 16, 3, 6, 9, 12, 15])
+  );
+  assert_eq!(
+    shuffle!(
+      u8x16,
+      v,
+      [15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15]
+    ),
+    u8x16::splat(16)
+  );
+  assert_eq!(
+    shuffle2!(
+      u8x16,
+      v,
+      w,
+      [2, 7, 12, 17, 22, 27, 0, 5, 10, 15, 20, 25, 30, 3, 8, 13]
+    ),
+    u8x16::from([3, 8, 13, 18, 23, 28, 1, 6, 11, 16, 21, 26, 31, 4, 9, 14])
+  );
+  assert_eq!(
+    v.reverse(),
+    u8x16::from([16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1])
+  );
+  assert_eq!(
+    rotate_lanes_left!(u8x16, v, 0),
+    u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u8x16, v, 0),
+    u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+  );
+  assert_eq!(
+    shift_lanes_left!(u8x16, v, 0),
+    u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+  );
+  assert_eq!(
+    shift_lanes_right!(u8x16, v, 0),
+    u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+  );
+  assert_eq!(
+    rotate_lanes_left!(u8x16, v, 1),
+    u8x16::from([2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 1])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u8x16, v, 1),
+    u8x16::from([16, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+  );
+  assert_eq!(
+    shift_lanes_left!(u8x16, v, 1),
+    u8x16::from([2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(u8x16, v, 1),
+    u8x16::from([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+  );
+  assert_eq!(
+    rotate_lanes_left!(u8x16, v, 9),
+    u8x16::from([10, 11, 12, 13, 14, 15, 16, 1, 2, 3, 4, 5, 6, 7, 8, 9])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u8x16, v, 9),
+    u8x16::from([8, 9, 10, 11, 12, 13, 14, 15, 16, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    shift_lanes_left!(u8x16, v, 9),
+    u8x16::from([10, 11, 12, 13, 14, 15, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(u8x16, v, 9),
+    u8x16::from([0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7])
+  );
+  assert_eq!(
+    rotate_lanes_left!(u8x16, v, 15),
+    u8x16::from([16, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u8x16, v, 15),
+    u8x16::from([2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 1])
+  );
+  assert_eq!(
+    shift_lanes_left!(u8x16, v, 15),
+    u8x16::from([16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(u8x16, v, 15),
+    u8x16::from([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])
+  );
+  assert_eq!(
+    rotate_lanes_left!(u8x16, v, 16),
+    u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+  );
+  assert_eq!(
+    rotate_lanes_right!(u8x16, v, 16),
+    u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+  );
+  assert_eq!(
+    shift_lanes_left!(u8x16, v, 16),
+    u8x16::from([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+  );
+  assert_eq!(
+    shift_lanes_right!(u8x16, v, 16),
+    u8x16::from([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+  );
+}
+
+#[test]
+fn impl_u8x16_zip_unzip() {
+  let a = u8x16::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+  let b = u8x16::from([
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+  ]);
+  assert_eq!(
+    a.zip_lo(b),
+    u8x16::from([1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23, 8, 24])
+  );
+  assert_eq!(
+    a.zip_hi(b),
+    u8x16::from([
+      9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31, 16, 32
+    ])
+  );
+  assert_eq!(
+    a.unzip_even(b),
+    u8x16::from([1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31])
+  );
+  assert_eq!(
+    a.unzip_odd(b),
+    u8x16::from([2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32])
+  );
+  assert_eq!(a.zip_lo(b).unzip_even(a.zip_hi(b)), a);
+  assert_eq!(a.zip_lo(b).unzip_odd(a.zip_hi(b)), b);
+  // lanes with the top bit set, which the packs must not saturate
+  let c = u8x16::from([
+    255, 1, 128, 127, 0, 254, 200, 100, 1, 255, 129, 2, 250, 5, 64, 192,
+  ]);
+  let d = u8x16::from([
+    0, 255, 254, 1, 128, 128, 3, 253, 255, 0, 17, 240, 99, 199, 7, 248,
+  ]);
+  assert_eq!(
+    c.unzip_even(d),
+    u8x16::from([
+      255, 128, 0, 200, 1, 129, 250, 64, 0, 254, 128, 3, 255, 17, 99, 7
+    ])
+  );
+  assert_eq!(
+    c.unzip_odd(d),
+    u8x16::from([
+      1, 127, 254, 100, 255, 2, 5, 192, 255, 1, 128, 253, 0, 240, 199, 248
+    ])
+  );
+}
+
+#[test]
+fn impl_u8x16_widen() {
+  let a = u8x16::from([
+    0, 255, 1, 2, 100, 254, 128, 0, 255, 1, 2, 100, 254, 128, 0, 255,
+  ]);
+  assert_eq!(a.widen_low(), u16x8::from([0, 255, 1, 2, 100, 254, 128, 0]));
+  assert_eq!(a.widen_high(), u16x8::from([255, 1, 2, 100, 254, 128, 0, 255]));
+}